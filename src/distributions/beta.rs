@@ -0,0 +1,229 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::distributions::{is_probability, xlog1py, xlogy, Distribution};
+use crate::special::RealBeta;
+use num_traits::Float;
+
+/// Beta distribution.
+/// $$
+/// f(x) = \frac{x^{\alpha-1}(1-x)^{\beta-1}}{B(\alpha,\beta)}
+/// $$
+/// for $0 \leq x \leq 1$, with shape parameters $\alpha > 0$ and $\beta > 0$.
+///
+/// # Examples
+/// ```
+/// use sci_rs::distributions::{Beta, Distribution};
+/// let beta = Beta::new(2.0_f64, 3.0).unwrap();
+/// assert!((beta.pdf(0.5) - 1.5).abs() < 1e-14);
+/// assert!((beta.cdf(0.5) - 0.6875).abs() < 1e-15);
+/// assert_eq!(beta.mean(), 0.4);
+/// ```
+///
+/// # Notes
+/// The cumulative distribution and survival functions are the regularized incomplete beta functions [betainc] and [betaincc], and the quantiles are evaluated with [betaincinv].
+///
+/// [betainc]: crate::special::RealBeta::betainc
+/// [betaincc]: crate::special::RealBeta::betaincc
+/// [betaincinv]: crate::special::RealBeta::betaincinv
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Beta<T> {
+    alpha: T,
+    beta: T,
+}
+
+impl<T> Beta<T>
+where
+    T: Float,
+{
+    /// Creates a beta distribution with shape parameters $\alpha$ and $\beta$.
+    ///
+    /// Returns `None` unless both parameters are finite and positive.
+    pub fn new(alpha: T, beta: T) -> Option<Self> {
+        if alpha.is_finite() && beta.is_finite() && alpha > T::zero() && beta > T::zero() {
+            Some(Self { alpha, beta })
+        } else {
+            None
+        }
+    }
+
+    /// The first shape parameter, $\alpha$.
+    pub fn alpha(&self) -> T {
+        self.alpha
+    }
+
+    /// The second shape parameter, $\beta$.
+    pub fn beta(&self) -> T {
+        self.beta
+    }
+}
+
+impl<T> Distribution<T> for Beta<T>
+where
+    T: Float + RealBeta,
+{
+    fn pdf(&self, x: T) -> T {
+        self.logpdf(x).exp()
+    }
+
+    fn logpdf(&self, x: T) -> T {
+        if x < T::zero() || x > T::one() {
+            return T::neg_infinity();
+        }
+        xlogy(self.alpha - T::one(), x) + xlog1py(self.beta - T::one(), -x)
+            - self.alpha.lbeta(self.beta)
+    }
+
+    fn cdf(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+        if x <= T::zero() {
+            return T::zero();
+        }
+        if x >= T::one() {
+            return T::one();
+        }
+        self.alpha.betainc(self.beta, x)
+    }
+
+    fn logcdf(&self, x: T) -> T {
+        self.cdf(x).ln()
+    }
+
+    fn sf(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+        if x <= T::zero() {
+            return T::one();
+        }
+        if x >= T::one() {
+            return T::zero();
+        }
+        self.alpha.betaincc(self.beta, x)
+    }
+
+    fn logsf(&self, x: T) -> T {
+        self.sf(x).ln()
+    }
+
+    fn ppf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        self.alpha.betaincinv(self.beta, q)
+    }
+
+    fn isf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        // I_x(a, b) = 1 - I_{1-x}(b, a), which loses precision when x is small
+        if q > (T::one() + T::one()).recip() {
+            return self.ppf(T::one() - q);
+        }
+        T::one() - self.beta.betaincinv(self.alpha, q)
+    }
+
+    fn mean(&self) -> T {
+        self.alpha / (self.alpha + self.beta)
+    }
+
+    fn variance(&self) -> T {
+        let s = self.alpha + self.beta;
+        self.alpha * self.beta / (s * s * (s + T::one()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 6]; 9] = [
+        // alpha, beta, x, logpdf, cdf, sf
+        [2.0, 3.0, 0.01, -2.140364207907094, 0.00059203, 0.99940797],
+        [
+            2.0, 3.0, 0.3, 0.5675839575845996, 0.3483, 0.6517000000000001,
+        ],
+        [
+            2.0,
+            3.0,
+            0.9,
+            -2.225624051857918,
+            0.9963,
+            0.0036999999999999976,
+        ],
+        [
+            0.5, 4.0, 0.01, 2.3620462441232286, 0.21657559375, 0.78342440625,
+        ],
+        [
+            0.5, 4.0, 0.3, -0.3784262709635419, 0.8987784842061335, 0.10122151579386648,
+        ],
+        [
+            0.5,
+            4.0,
+            0.9,
+            -6.765462862463537,
+            0.9999714888513697,
+            2.8511148630298084e-05,
+        ],
+        [
+            10.0,
+            0.7,
+            0.01,
+            -40.10300070345139,
+            3.831497624341151e-21,
+            1.0,
+        ],
+        [
+            10.0,
+            0.7,
+            0.3,
+            -9.388236886066423,
+            2.483005433613525e-06,
+            0.9999975169945664,
+        ],
+        [
+            10.0, 0.7, 0.9, 1.083046756663159, 0.22825885833928833, 0.7717411416607116,
+        ],
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_QUANTILES: [[f64; 4]; 7] = [
+        // alpha, beta, q, ppf
+        [2.0, 3.0, 0.05, 0.09761146288641434],
+        [2.0, 3.0, 0.5, 0.38572756813238956],
+        [0.5, 4.0, 0.05, 0.0005229957140220069],
+        [0.5, 4.0, 0.95, 0.39929364764275604],
+        [10.0, 0.7, 0.05, 0.785241886482093],
+        [10.0, 0.7, 0.5, 0.9594917087871989],
+        [10.0, 0.7, 0.95, 0.9987661911624102],
+    ];
+
+    #[test]
+    fn test_beta() {
+        for [a, b, x, logpdf, cdf, sf] in KNOWN_VALUES {
+            let beta = Beta::new(a, b).unwrap();
+            assert_almost_eq!(beta.logpdf(x), logpdf, PRECISION * logpdf.abs().max(1.0));
+            assert_almost_eq!(beta.cdf(x), cdf, PRECISION * cdf);
+            assert_almost_eq!(beta.sf(x), sf, PRECISION * sf);
+        }
+        for [a, b, q, x] in KNOWN_QUANTILES {
+            let beta = Beta::new(a, b).unwrap();
+            assert_almost_eq!(beta.ppf(q), x, PRECISION * x);
+        }
+
+        // Values from mpmath (v 1.3.0)
+        let beta = Beta::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(beta.isf(1e-10), 0.9997075768467906, PRECISION);
+        assert_almost_eq!(beta.isf(0.2), 0.5824535745243332, PRECISION);
+        assert_eq!(beta.pdf(1.5), 0.0);
+        assert_eq!(beta.variance(), 0.04);
+    }
+}
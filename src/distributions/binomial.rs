@@ -0,0 +1,247 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::distributions::{discrete_search, is_probability, xlog1py, xlogy, Distribution};
+use crate::special::{Ndtr, RealBeta};
+use num_traits::Float;
+
+/// Binomial distribution.
+/// $$
+/// f(k) = \binom{n}{k}p^k(1-p)^{n-k}
+/// $$
+/// for integers $0 \leq k \leq n$, with $n$ trials and success probability $0 \leq p \leq 1$.
+///
+/// # Examples
+/// ```
+/// use sci_rs::distributions::{Binomial, Distribution};
+/// let binomial = Binomial::new(10, 0.3_f64).unwrap();
+/// assert!((binomial.pdf(3.0) - 0.26682793200000005).abs() < 1e-15);
+/// assert!((binomial.cdf(3.0) - 0.6496107184000002).abs() < 1e-15);
+/// assert_eq!(binomial.ppf(0.5), 3.0);
+/// ```
+///
+/// # Notes
+/// The probability mass function is evaluated as
+/// $$
+/// f(k) = \frac{p^k(1-p)^{n-k}}{(n+1)B(n-k+1, k+1)}
+/// $$
+/// and the cumulative distribution function is $F(k) = 1 - I_p(k+1, n-k)$, in terms of the regularized incomplete beta function. The quantiles are found by a search over the integers, starting from the normal approximation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial<T> {
+    n: T,
+    p: T,
+}
+
+impl<T> Binomial<T>
+where
+    T: Float,
+{
+    /// Creates a binomial distribution with `n` trials and success probability `p`.
+    ///
+    /// Returns `None` unless $0 \leq p \leq 1$ and `n` is representable.
+    pub fn new(n: u64, p: T) -> Option<Self> {
+        if !is_probability(p) {
+            return None;
+        }
+        Some(Self { n: T::from(n)?, p })
+    }
+
+    /// The number of trials, $n$.
+    pub fn n(&self) -> T {
+        self.n
+    }
+
+    /// The success probability, $p$.
+    pub fn p(&self) -> T {
+        self.p
+    }
+}
+
+impl<T> Distribution<T> for Binomial<T>
+where
+    T: Float + RealBeta + Ndtr,
+{
+    fn pdf(&self, x: T) -> T {
+        self.logpdf(x).exp()
+    }
+
+    fn logpdf(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+        if x < T::zero() || x > self.n || x != x.floor() {
+            return T::neg_infinity();
+        }
+        let n = self.n;
+        xlogy(x, self.p) + xlog1py(n - x, -self.p)
+            - n.ln_1p()
+            - (n - x + T::one()).lbeta(x + T::one())
+    }
+
+    fn cdf(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+        if x < T::zero() {
+            return T::zero();
+        }
+        let k = x.floor();
+        if k >= self.n {
+            return T::one();
+        }
+        (k + T::one()).betaincc(self.n - k, self.p)
+    }
+
+    fn logcdf(&self, x: T) -> T {
+        self.cdf(x).ln()
+    }
+
+    fn sf(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+        if x < T::zero() {
+            return T::one();
+        }
+        let k = x.floor();
+        if k >= self.n {
+            return T::zero();
+        }
+        (k + T::one()).betainc(self.n - k, self.p)
+    }
+
+    fn logsf(&self, x: T) -> T {
+        self.sf(x).ln()
+    }
+
+    fn ppf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        if q.is_zero() {
+            return -T::one();
+        }
+        let guess = self.mean() + self.variance().sqrt() * q.ndtri();
+        discrete_search(guess, T::zero(), self.n, |k| self.cdf(k) >= q)
+    }
+
+    fn isf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        if q.is_one() {
+            return -T::one();
+        }
+        let guess = self.mean() - self.variance().sqrt() * q.ndtri();
+        discrete_search(guess, T::zero(), self.n, |k| self.sf(k) <= q)
+    }
+
+    fn mean(&self) -> T {
+        self.n * self.p
+    }
+
+    fn variance(&self) -> T {
+        self.n * self.p * (T::one() - self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 6]; 10] = [
+        // n, p, k, logpmf, cdf, sf
+        [
+            10.0, 0.3, 0.0, -3.5667494393873236, 0.028247524900000005, 0.9717524751,
+        ],
+        [
+            10.0, 0.3, 3.0, -1.3211512777668886, 0.6496107184000001, 0.3503892816,
+        ],
+        [
+            1000.0,
+            0.02,
+            0.0,
+            -20.202707317519447,
+            1.682967357215955e-09,
+            0.9999999983170327,
+        ],
+        [
+            1000.0,
+            0.02,
+            3.0,
+            -12.94966434713723,
+            2.7631676295533944e-06,
+            0.9999972368323704,
+        ],
+        [
+            1000.0, 0.02, 20.0, -2.410871336695004, 0.5590956057638878, 0.44090439423611216,
+        ],
+        [
+            1000.0,
+            0.02,
+            49.0,
+            -18.183124055321244,
+            0.9999999921152831,
+            7.884716909458582e-09,
+        ],
+        [
+            50.0,
+            0.999,
+            0.0,
+            -345.3877639491068,
+            1.0000000000000444e-150,
+            1.0,
+        ],
+        [
+            50.0,
+            0.999,
+            3.0,
+            -314.78421476794256,
+            1.9542481381576815e-137,
+            1.0,
+        ],
+        [
+            50.0, 0.999, 20.0, -175.76875423394637, 4.6225380191247e-77, 1.0,
+        ],
+        [
+            50.0, 0.999, 49.0, -3.044756789899583, 0.048794371802968695, 0.9512056281970314,
+        ],
+    ];
+
+    #[test]
+    fn test_binomial() {
+        for [n, p, k, logpmf, cdf, sf] in KNOWN_VALUES {
+            let binomial = Binomial::new(n as u64, p).unwrap();
+            assert_almost_eq!(binomial.logpdf(k), logpmf, 10.0 * PRECISION * logpmf.abs());
+            assert_almost_eq!(binomial.cdf(k), cdf, 1e3 * PRECISION * cdf);
+            assert_almost_eq!(binomial.sf(k), sf, 1e3 * PRECISION * sf);
+        }
+    }
+
+    #[test]
+    fn test_binomial_quantiles() {
+        for (n, p) in [(10, 0.3), (1000, 0.02), (50, 0.999)] {
+            let binomial = Binomial::new(n, p).unwrap();
+            for q in [1e-10, 0.01, 0.3, 0.5, 0.9, 0.999] {
+                let k = binomial.ppf(q);
+                assert!(binomial.cdf(k) >= q);
+                assert!(k == 0.0 || binomial.cdf(k - 1.0) < q);
+
+                let k = binomial.isf(q);
+                assert!(binomial.sf(k) <= q);
+                assert!(k == 0.0 || binomial.sf(k - 1.0) > q);
+            }
+        }
+        let binomial = Binomial::new(10, 0.3).unwrap();
+        assert_eq!(binomial.ppf(0.0), -1.0);
+        assert_eq!(binomial.ppf(1.0), 10.0);
+        assert_eq!(binomial.pdf(11.0), 0.0);
+        assert_eq!(binomial.mean(), 3.0);
+        assert!(Binomial::new(10, 1.5).is_none());
+    }
+}
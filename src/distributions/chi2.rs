@@ -0,0 +1,127 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::distributions::{Distribution, Gamma};
+use crate::special::RealGamma;
+use num_traits::Float;
+
+/// Chi-squared distribution.
+/// $$
+/// f(x) = \frac{x^{k/2-1}e^{-x/2}}{2^{k/2}\Gamma(k/2)}
+/// $$
+/// for $x \geq 0$, with $k > 0$ degrees of freedom.
+///
+/// # Examples
+/// ```
+/// use sci_rs::distributions::{Chi2, Distribution};
+/// let chi2 = Chi2::new(3.0_f64).unwrap();
+/// assert!((chi2.sf(7.814727903251178) - 0.05).abs() < 1e-15);
+/// assert_eq!(chi2.mean(), 3.0);
+/// ```
+///
+/// # Notes
+/// The chi-squared distribution with $k$ degrees of freedom is the [Gamma] distribution with shape $k/2$ and scale $2$.
+///
+/// [Gamma]: crate::distributions::Gamma
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chi2<T> {
+    df: T,
+    gamma: Gamma<T>,
+}
+
+impl<T> Chi2<T>
+where
+    T: Float,
+{
+    /// Creates a chi-squared distribution with `df` degrees of freedom.
+    ///
+    /// Returns `None` unless `df` is finite and positive.
+    pub fn new(df: T) -> Option<Self> {
+        let two = T::one() + T::one();
+        Some(Self {
+            df,
+            gamma: Gamma::new(df / two, two)?,
+        })
+    }
+
+    /// The degrees of freedom, $k$.
+    pub fn df(&self) -> T {
+        self.df
+    }
+}
+
+impl<T> Distribution<T> for Chi2<T>
+where
+    T: Float + RealGamma,
+{
+    fn pdf(&self, x: T) -> T {
+        self.gamma.pdf(x)
+    }
+
+    fn logpdf(&self, x: T) -> T {
+        self.gamma.logpdf(x)
+    }
+
+    fn cdf(&self, x: T) -> T {
+        self.gamma.cdf(x)
+    }
+
+    fn logcdf(&self, x: T) -> T {
+        self.gamma.logcdf(x)
+    }
+
+    fn sf(&self, x: T) -> T {
+        self.gamma.sf(x)
+    }
+
+    fn logsf(&self, x: T) -> T {
+        self.gamma.logsf(x)
+    }
+
+    fn ppf(&self, q: T) -> T {
+        self.gamma.ppf(q)
+    }
+
+    fn isf(&self, q: T) -> T {
+        self.gamma.isf(q)
+    }
+
+    fn mean(&self) -> T {
+        self.gamma.mean()
+    }
+
+    fn variance(&self) -> T {
+        self.gamma.variance()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0) for a chi-squared distribution with 3 degrees of freedom
+    const KNOWN_VALUES: [[f64; 3]; 4] = [
+        // x, cdf, sf
+        [0.1, 0.008162576268123523, 0.9918374237318764],
+        [1.0, 0.1987480430987992, 0.8012519569012008],
+        [7.814727903251178, 0.95, 0.05000000000000004],
+        [30.0, 0.9999986199429687, 1.3800570312932547e-06],
+    ];
+
+    #[test]
+    fn test_chi2() {
+        let chi2 = Chi2::new(3.0).unwrap();
+        for [x, cdf, sf] in KNOWN_VALUES {
+            assert_almost_eq!(chi2.cdf(x), cdf, PRECISION * cdf);
+            assert_almost_eq!(chi2.sf(x), sf, 10.0 * PRECISION * sf);
+        }
+        assert_almost_eq!(chi2.ppf(0.95), 7.814727903251178, 10.0 * PRECISION);
+        assert_eq!(chi2.df(), 3.0);
+        assert_eq!(chi2.mean(), 3.0);
+        assert_eq!(chi2.variance(), 6.0);
+    }
+}
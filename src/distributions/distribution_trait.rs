@@ -0,0 +1,71 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+/// Common interface of univariate probability distributions.
+///
+/// The naming follows that of [scipy.stats]. For discrete distributions, the probability density function is the probability mass function, which is zero for non-integer arguments.
+///
+/// [scipy.stats]: https://docs.scipy.org/doc/scipy/reference/stats.html
+pub trait Distribution<T> {
+    /// Probability density function (or probability mass function for discrete distributions).
+    /// $$
+    /// f(x) = \frac{d}{dx}F(x)
+    /// $$
+    fn pdf(&self, x: T) -> T;
+
+    /// Natural log of the probability density function.
+    /// $$
+    /// \ln f(x)
+    /// $$
+    /// This is accurate even when the density underflows.
+    fn logpdf(&self, x: T) -> T;
+
+    /// Cumulative distribution function.
+    /// $$
+    /// F(x) = P(X \leq x)
+    /// $$
+    fn cdf(&self, x: T) -> T;
+
+    /// Natural log of the cumulative distribution function.
+    /// $$
+    /// \ln F(x)
+    /// $$
+    /// Implementations without a dedicated algorithm return the logarithm of [cdf](Self::cdf), which is $-\infty$ once the lower tail underflows.
+    fn logcdf(&self, x: T) -> T;
+
+    /// Survival function.
+    /// $$
+    /// S(x) = P(X > x) = 1 - F(x)
+    /// $$
+    /// The survival function is computed directly, such that the upper tail retains its relative precision.
+    fn sf(&self, x: T) -> T;
+
+    /// Natural log of the survival function.
+    /// $$
+    /// \ln S(x)
+    /// $$
+    /// Implementations without a dedicated algorithm return the logarithm of [sf](Self::sf), which is $-\infty$ once the upper tail underflows.
+    fn logsf(&self, x: T) -> T;
+
+    /// Percent point function, the inverse of the cumulative distribution function.
+    ///
+    /// Returns the smallest $x$ such that $F(x) \geq q$. The valid domain is $0 \leq q \leq 1$, otherwise NaN is returned. For discrete distributions, `ppf(0.0)` is one less than the lower bound of the support.
+    fn ppf(&self, q: T) -> T;
+
+    /// Inverse survival function.
+    ///
+    /// Returns the smallest $x$ such that $S(x) \leq q$. The valid domain is $0 \leq q \leq 1$, otherwise NaN is returned.
+    fn isf(&self, q: T) -> T;
+
+    /// Mean of the distribution.
+    ///
+    /// Returns infinity or NaN when the mean does not exist.
+    fn mean(&self) -> T;
+
+    /// Variance of the distribution.
+    ///
+    /// Returns infinity or NaN when the variance does not exist.
+    fn variance(&self) -> T;
+}
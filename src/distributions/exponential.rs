@@ -0,0 +1,154 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::distributions::{is_probability, Distribution};
+use num_traits::{Float, FloatConst};
+
+/// Exponential distribution.
+/// $$
+/// f(x) = \lambda e^{-\lambda x}
+/// $$
+/// for $x \geq 0$, with rate $\lambda > 0$.
+///
+/// # Examples
+/// ```
+/// use sci_rs::distributions::{Distribution, Exponential};
+/// let exponential = Exponential::new(2.0_f64).unwrap();
+/// assert_eq!(exponential.logsf(100.0), -200.0);
+/// assert!((exponential.cdf(1e-20) - 2e-20).abs() < 1e-35);
+/// assert_eq!(exponential.mean(), 0.5);
+/// ```
+///
+/// # Notes
+/// This corresponds to the `expon` distribution of scipy with scale $1/\lambda$.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exponential<T> {
+    rate: T,
+}
+
+impl<T> Exponential<T>
+where
+    T: Float,
+{
+    /// Creates an exponential distribution with rate $\lambda$.
+    ///
+    /// Returns `None` unless `rate` is finite and positive.
+    pub fn new(rate: T) -> Option<Self> {
+        if rate.is_finite() && rate > T::zero() {
+            Some(Self { rate })
+        } else {
+            None
+        }
+    }
+
+    /// The rate parameter, $\lambda$.
+    pub fn rate(&self) -> T {
+        self.rate
+    }
+}
+
+impl<T> Distribution<T> for Exponential<T>
+where
+    T: Float + FloatConst,
+{
+    fn pdf(&self, x: T) -> T {
+        if x < T::zero() {
+            return T::zero();
+        }
+        self.rate * (-self.rate * x).exp()
+    }
+
+    fn logpdf(&self, x: T) -> T {
+        if x < T::zero() {
+            return T::neg_infinity();
+        }
+        self.rate.ln() - self.rate * x
+    }
+
+    fn cdf(&self, x: T) -> T {
+        if x < T::zero() {
+            return T::zero();
+        }
+        -(-self.rate * x).exp_m1()
+    }
+
+    fn logcdf(&self, x: T) -> T {
+        if x < T::zero() {
+            return T::neg_infinity();
+        }
+        let t = self.rate * x;
+        if t > T::LN_2() {
+            (-(-t).exp()).ln_1p()
+        } else {
+            (-(-t).exp_m1()).ln()
+        }
+    }
+
+    fn sf(&self, x: T) -> T {
+        if x < T::zero() {
+            return T::one();
+        }
+        (-self.rate * x).exp()
+    }
+
+    fn logsf(&self, x: T) -> T {
+        if x < T::zero() {
+            return T::zero();
+        }
+        -self.rate * x
+    }
+
+    fn ppf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        -(-q).ln_1p() / self.rate
+    }
+
+    fn isf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        -q.ln() / self.rate
+    }
+
+    fn mean(&self) -> T {
+        self.rate.recip()
+    }
+
+    fn variance(&self) -> T {
+        (self.rate * self.rate).recip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0) for an exponential distribution with rate = 2
+    const KNOWN_VALUES: [[f64; 3]; 3] = [
+        // x, cdf, logcdf
+        [1e-10, 1.9999999998e-10, -22.332703749480512],
+        [0.3, 0.45118836390597356, -0.7958703683463196],
+        [5.0, 0.9999546000702375, -4.540096037048921e-05],
+    ];
+
+    #[test]
+    fn test_exponential() {
+        let exponential = Exponential::new(2.0).unwrap();
+        for [x, cdf, logcdf] in KNOWN_VALUES {
+            assert_almost_eq!(exponential.cdf(x), cdf, PRECISION * cdf);
+            assert_almost_eq!(exponential.logcdf(x), logcdf, PRECISION * logcdf.abs());
+            assert_almost_eq!(exponential.ppf(cdf), x, 10.0 * PRECISION * x.max(1.0));
+            assert_eq!(exponential.logsf(x), -2.0 * x);
+        }
+        assert_eq!(exponential.pdf(0.0), 2.0);
+        assert_eq!(exponential.pdf(-1.0), 0.0);
+        assert_eq!(exponential.mean(), 0.5);
+        assert_eq!(exponential.variance(), 0.25);
+    }
+}
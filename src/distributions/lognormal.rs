@@ -0,0 +1,173 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::distributions::{is_probability, Distribution};
+use crate::special::Ndtr;
+use crate::traits::FloatSciConst;
+use num_traits::Float;
+
+/// Log-normal distribution.
+/// $$
+/// f(x) = \frac{1}{x\sigma\sqrt{2\pi}}e^{-\frac{(\ln x-\mu)^2}{2\sigma^2}}
+/// $$
+/// for $x > 0$, such that $\ln X$ is normally distributed with mean $\mu$ and standard deviation $\sigma > 0$.
+///
+/// # Examples
+/// ```
+/// use sci_rs::distributions::{Distribution, LogNormal};
+/// let lognormal = LogNormal::new(0.0_f64, 1.0).unwrap();
+/// assert_eq!(lognormal.cdf(1.0), 0.5);
+/// assert_eq!(lognormal.pdf(-1.0), 0.0);
+/// assert!((lognormal.mean() - 1.6487212707001282).abs() < 1e-15);
+/// ```
+///
+/// # Notes
+/// This corresponds to the `lognorm` distribution of scipy with shape `s` $=\sigma$ and scale $e^{\mu}$.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogNormal<T> {
+    mu: T,
+    sigma: T,
+}
+
+impl<T> LogNormal<T>
+where
+    T: Float,
+{
+    /// Creates a log-normal distribution, where `mu` and `sigma` are the mean and standard deviation of $\ln X$.
+    ///
+    /// Returns `None` unless both parameters are finite and `sigma` is positive.
+    pub fn new(mu: T, sigma: T) -> Option<Self> {
+        if mu.is_finite() && sigma.is_finite() && sigma > T::zero() {
+            Some(Self { mu, sigma })
+        } else {
+            None
+        }
+    }
+
+    /// The mean of $\ln X$, $\mu$.
+    pub fn mu(&self) -> T {
+        self.mu
+    }
+
+    /// The standard deviation of $\ln X$, $\sigma$.
+    pub fn sigma(&self) -> T {
+        self.sigma
+    }
+
+    #[inline]
+    fn standardize(&self, x: T) -> T {
+        (x.ln() - self.mu) / self.sigma
+    }
+}
+
+impl<T> Distribution<T> for LogNormal<T>
+where
+    T: Float + FloatSciConst + Ndtr,
+{
+    fn pdf(&self, x: T) -> T {
+        if x <= T::zero() {
+            return T::zero();
+        }
+        let z = self.standardize(x);
+        (-z * z / (T::one() + T::one())).exp() / (self.sigma * x * T::SQRT_TAU())
+    }
+
+    fn logpdf(&self, x: T) -> T {
+        if x <= T::zero() {
+            return T::neg_infinity();
+        }
+        let z = self.standardize(x);
+        -z * z / (T::one() + T::one()) - self.sigma.ln() - x.ln() - T::LOG_SQRT_2_PI()
+    }
+
+    fn cdf(&self, x: T) -> T {
+        if x <= T::zero() {
+            return T::zero();
+        }
+        self.standardize(x).ndtr()
+    }
+
+    fn logcdf(&self, x: T) -> T {
+        if x <= T::zero() {
+            return T::neg_infinity();
+        }
+        self.standardize(x).log_ndtr()
+    }
+
+    fn sf(&self, x: T) -> T {
+        if x <= T::zero() {
+            return T::one();
+        }
+        (-self.standardize(x)).ndtr()
+    }
+
+    fn logsf(&self, x: T) -> T {
+        if x <= T::zero() {
+            return T::zero();
+        }
+        (-self.standardize(x)).log_ndtr()
+    }
+
+    fn ppf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        (self.mu + self.sigma * q.ndtri()).exp()
+    }
+
+    fn isf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        (self.mu - self.sigma * q.ndtri()).exp()
+    }
+
+    fn mean(&self) -> T {
+        (self.mu + self.sigma * self.sigma / (T::one() + T::one())).exp()
+    }
+
+    fn variance(&self) -> T {
+        let sigma2 = self.sigma * self.sigma;
+        sigma2.exp_m1() * (self.mu + self.mu + sigma2).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0) for a log-normal distribution with mu = 0.5 and sigma = 0.8
+    const KNOWN_VALUES: [[f64; 3]; 4] = [
+        // x, pdf, cdf
+        [0.1, 0.010785799279345913, 0.00022982501310415206],
+        [1.0, 0.4102012106879688, 0.26598552904870054],
+        [2.0, 0.24217677488483338, 0.595390608679215],
+        [20.0, 0.00019206375604878734, 0.9990947169259151],
+    ];
+
+    #[test]
+    fn test_lognormal() {
+        let lognormal = LogNormal::new(0.5, 0.8).unwrap();
+        for [x, pdf, cdf] in KNOWN_VALUES {
+            assert_almost_eq!(lognormal.pdf(x), pdf, PRECISION * pdf);
+            assert_almost_eq!(lognormal.cdf(x), cdf, PRECISION * cdf);
+            assert_almost_eq!(lognormal.sf(x), 1.0 - cdf, PRECISION);
+        }
+
+        // Values from mpmath (v 1.3.0)
+        assert_almost_eq!(lognormal.ppf(0.01), 0.25638416899458627, PRECISION);
+        assert_almost_eq!(lognormal.ppf(0.5), 1.6487212707001282, PRECISION);
+        assert_almost_eq!(lognormal.ppf(0.9), 4.596252292620807, 4.0 * PRECISION);
+        assert_almost_eq!(lognormal.mean(), 2.2704998375324057, PRECISION);
+        assert_almost_eq!(lognormal.variance(), 4.621510897294225, 4.0 * PRECISION);
+
+        assert_eq!(lognormal.pdf(0.0), 0.0);
+        assert_eq!(lognormal.pdf(-1.0), 0.0);
+        assert_eq!(lognormal.cdf(-1.0), 0.0);
+        assert_eq!(lognormal.sf(0.0), 1.0);
+    }
+}
@@ -0,0 +1,168 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Univariate probability distributions
+//!
+//! Every distribution implements the [Distribution] trait, which provides the density, cumulative distribution, survival and quantile functions, along with the mean and variance. The implementations are built on top of the [special] functions.
+//!
+//! # Available Distributions
+//! - Continuous: [Normal], [LogNormal], [Gamma], [Chi2], [Exponential], [Beta], [StudentT] and [F]
+//! - Discrete: [Poisson] and [Binomial]
+//!
+//! # Examples
+//! ```
+//! use sci_rs::distributions::{Distribution, Normal};
+//! let normal = Normal::new(1.0_f64, 2.0).unwrap();
+//! assert_eq!(normal.cdf(1.0), 0.5);
+//! assert!((normal.ppf(normal.cdf(3.0)) - 3.0).abs() < 1e-15);
+//! ```
+//!
+//! [special]: crate::special
+
+mod distribution_trait;
+
+pub use distribution_trait::*;
+
+mod beta;
+mod binomial;
+mod chi2;
+mod exponential;
+mod f;
+mod gamma;
+mod lognormal;
+mod normal;
+mod poisson;
+mod student_t;
+
+pub use beta::*;
+pub use binomial::*;
+pub use chi2::*;
+pub use exponential::*;
+pub use f::*;
+pub use gamma::*;
+pub use lognormal::*;
+pub use normal::*;
+pub use poisson::*;
+pub use student_t::*;
+
+use num_traits::Float;
+
+/// Computes $x\ln y$, such that the result is zero when $x = 0$ (unless $y$ is NaN).
+#[inline]
+fn xlogy<T>(x: T, y: T) -> T
+where
+    T: Float,
+{
+    if x.is_zero() && !y.is_nan() {
+        return T::zero();
+    }
+    x * y.ln()
+}
+
+/// Computes $x\ln(1+y)$, such that the result is zero when $x = 0$ (unless $y$ is NaN).
+#[inline]
+fn xlog1py<T>(x: T, y: T) -> T
+where
+    T: Float,
+{
+    if x.is_zero() && !y.is_nan() {
+        return T::zero();
+    }
+    x * y.ln_1p()
+}
+
+/// Whether `q` is a valid probability, $0 \leq q \leq 1$.
+#[inline]
+fn is_probability<T>(q: T) -> bool
+where
+    T: Float,
+{
+    q >= T::zero() && q <= T::one()
+}
+
+/// Smallest integer $k$ in $[\text{lower}, \text{upper}]$ for which `done(k)` is true, where `done` is monotone in $k$.
+///
+/// Used for the quantile functions of discrete distributions. Starting at `guess`, the step is doubled until the result is bracketed, after which the bracket is bisected. Only a few evaluations are thus required when the guess is accurate, and the search terminates for an unbounded `upper`. Returns `NaN` if `done(k)` is false for all $k$, e.g. when the distribution function is `NaN`.
+fn discrete_search<T, F>(guess: T, lower: T, upper: T, done: F) -> T
+where
+    T: Float,
+    F: Fn(T) -> bool,
+{
+    let one = T::one();
+    let two = one + one;
+    let start = if guess.is_nan() {
+        lower
+    } else {
+        guess.floor().max(lower).min(upper)
+    };
+
+    // Bracket the result in (lo, hi], such that done(hi) is true and done(lo) is false
+    let mut step = one;
+    let (mut lo, mut hi) = if done(start) {
+        let mut hi = start;
+        loop {
+            if hi <= lower {
+                return hi;
+            }
+            let lo = (hi - step).max(lower);
+            if !done(lo) {
+                break (lo, hi);
+            }
+            hi = lo;
+            step = step * two;
+        }
+    } else {
+        let mut lo = start;
+        loop {
+            if lo >= upper {
+                return T::nan();
+            }
+            let hi = (lo + step).min(upper);
+            if done(hi) {
+                break (lo, hi);
+            }
+            lo = hi;
+            step = step * two;
+        }
+    };
+
+    while hi - lo > one {
+        let mid = lo + ((hi - lo) / two).floor();
+        if mid <= lo || mid >= hi {
+            // The integers are no longer representable
+            break;
+        }
+        if done(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discrete_search() {
+        // Both far from and close to the guess
+        for guess in [0.0, 3.0, 7.0, 1e6, f64::NAN] {
+            assert_eq!(discrete_search(guess, 0.0, 1e9, |k| k >= 5.0), 5.0);
+            assert_eq!(
+                discrete_search(guess, 0.0, f64::INFINITY, |k| k >= 1e12),
+                1e12
+            );
+        }
+        assert_eq!(discrete_search(3.0, 2.0, 10.0, |k| k >= 0.0), 2.0);
+        assert_eq!(discrete_search(3.0, 0.0, 10.0, |k| k >= 10.0), 10.0);
+
+        // The search terminates when the predicate is never true
+        assert!(discrete_search(3.0, 0.0, 10.0, |_| false).is_nan());
+        let cdf = |_: f64| f64::NAN;
+        assert!(discrete_search(3.0, 0.0, f64::INFINITY, |k| cdf(k) >= 0.5).is_nan());
+    }
+}
@@ -0,0 +1,176 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::distributions::{is_probability, Distribution};
+use crate::special::Ndtr;
+use crate::traits::FloatSciConst;
+use num_traits::Float;
+
+/// Normal (Gaussian) distribution.
+/// $$
+/// f(x) = \frac{1}{\sigma\sqrt{2\pi}}e^{-\frac{(x-\mu)^2}{2\sigma^2}}
+/// $$
+/// with mean $\mu$ and standard deviation $\sigma > 0$.
+///
+/// # Examples
+/// ```
+/// use sci_rs::distributions::{Distribution, Normal};
+/// let normal = Normal::new(0.0_f64, 1.0).unwrap();
+/// assert!((normal.pdf(0.0) - 0.3989422804014327).abs() < 1e-15);
+/// assert!((normal.sf(10.0) - 7.619853024160527e-24).abs() < 1e-37);
+/// assert!((normal.ppf(0.975) - 1.959963984540054).abs() < 1e-15);
+/// ```
+///
+/// # Notes
+/// The cumulative distribution function and its inverse are evaluated with [ndtr] and [ndtri], while the logarithms use [log_ndtr], such that the tails are accurate.
+///
+/// [ndtr]: crate::special::Ndtr::ndtr
+/// [ndtri]: crate::special::Ndtr::ndtri
+/// [log_ndtr]: crate::special::Ndtr::log_ndtr
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal<T> {
+    mu: T,
+    sigma: T,
+}
+
+impl<T> Normal<T>
+where
+    T: Float,
+{
+    /// Creates a normal distribution with mean `mu` and standard deviation `sigma`.
+    ///
+    /// Returns `None` unless both parameters are finite and `sigma` is positive.
+    pub fn new(mu: T, sigma: T) -> Option<Self> {
+        if mu.is_finite() && sigma.is_finite() && sigma > T::zero() {
+            Some(Self { mu, sigma })
+        } else {
+            None
+        }
+    }
+
+    /// The mean, $\mu$.
+    pub fn mu(&self) -> T {
+        self.mu
+    }
+
+    /// The standard deviation, $\sigma$.
+    pub fn sigma(&self) -> T {
+        self.sigma
+    }
+
+    #[inline]
+    fn standardize(&self, x: T) -> T {
+        (x - self.mu) / self.sigma
+    }
+}
+
+impl<T> Distribution<T> for Normal<T>
+where
+    T: Float + FloatSciConst + Ndtr,
+{
+    fn pdf(&self, x: T) -> T {
+        let z = self.standardize(x);
+        (-z * z / (T::one() + T::one())).exp() / (self.sigma * T::SQRT_TAU())
+    }
+
+    fn logpdf(&self, x: T) -> T {
+        let z = self.standardize(x);
+        -z * z / (T::one() + T::one()) - self.sigma.ln() - T::LOG_SQRT_2_PI()
+    }
+
+    fn cdf(&self, x: T) -> T {
+        self.standardize(x).ndtr()
+    }
+
+    fn logcdf(&self, x: T) -> T {
+        self.standardize(x).log_ndtr()
+    }
+
+    fn sf(&self, x: T) -> T {
+        (-self.standardize(x)).ndtr()
+    }
+
+    fn logsf(&self, x: T) -> T {
+        (-self.standardize(x)).log_ndtr()
+    }
+
+    fn ppf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        self.mu + self.sigma * q.ndtri()
+    }
+
+    fn isf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        self.mu - self.sigma * q.ndtri()
+    }
+
+    fn mean(&self) -> T {
+        self.mu
+    }
+
+    fn variance(&self) -> T {
+        self.sigma * self.sigma
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0) for a normal distribution with mu = 1 and sigma = 2
+    const KNOWN_VALUES: [[f64; 4]; 6] = [
+        // x, pdf, cdf, logcdf
+        [
+            -40.0,
+            1.1059921901052852e-92,
+            1.0764673258790961e-93,
+            -214.0667289632638,
+        ],
+        [
+            -3.0, 0.026995483256594024, 0.02275013194817921, -3.783184333682032,
+        ],
+        [
+            0.0, 0.17603266338214973, 0.3085375387259869, -1.1759117615936185,
+        ],
+        [1.0, 0.19947114020071635, 0.5, -std::f64::consts::LN_2],
+        [
+            2.5, 0.15056871607740221, 0.7733726476231318, -0.25699426683836524,
+        ],
+        [
+            10.0,
+            7.991870553452737e-06,
+            0.9999966023268753,
+            -3.397678896834466e-06,
+        ],
+    ];
+
+    #[test]
+    fn test_normal() {
+        let normal = Normal::new(1.0, 2.0).unwrap();
+        for [x, pdf, cdf, logcdf] in KNOWN_VALUES {
+            assert_almost_eq!(normal.pdf(x), pdf, PRECISION * pdf);
+            assert_almost_eq!(normal.cdf(x), cdf, 10.0 * PRECISION * cdf);
+            assert_almost_eq!(normal.logcdf(x), logcdf, PRECISION * logcdf.abs());
+            assert_almost_eq!(normal.sf(2.0 - x), cdf, 10.0 * PRECISION * cdf);
+            assert_almost_eq!(normal.logsf(2.0 - x), logcdf, PRECISION * logcdf.abs());
+            if cdf > 1e-300 && cdf < 0.99 {
+                assert_almost_eq!(normal.ppf(cdf), x, PRECISION * x.abs().max(1.0));
+                assert_almost_eq!(normal.isf(cdf), 2.0 - x, PRECISION * x.abs().max(1.0));
+            }
+        }
+
+        assert_eq!(normal.mean(), 1.0);
+        assert_eq!(normal.variance(), 4.0);
+        assert!(normal.ppf(1.5).is_nan());
+        assert!(Normal::new(0.0, 0.0).is_none());
+        assert!(Normal::new(f64::NAN, 1.0).is_none());
+    }
+}
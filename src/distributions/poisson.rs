@@ -0,0 +1,222 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::distributions::{discrete_search, is_probability, xlogy, Distribution};
+use crate::special::{Ndtr, RealGamma};
+use num_traits::Float;
+
+/// Poisson distribution.
+/// $$
+/// f(k) = \frac{\mu^k e^{-\mu}}{k!}
+/// $$
+/// for integers $k \geq 0$, with mean $\mu \geq 0$.
+///
+/// # Examples
+/// ```
+/// use sci_rs::distributions::{Distribution, Poisson};
+/// let poisson = Poisson::new(3.0_f64).unwrap();
+/// assert!((poisson.pdf(2.0) - 0.22404180765538775).abs() < 1e-15);
+/// assert_eq!(poisson.pdf(2.5), 0.0); // Only defined for integers
+/// assert_eq!(poisson.ppf(0.5), 3.0);
+/// ```
+///
+/// # Notes
+/// The cumulative distribution function is the regularized upper incomplete gamma function, $F(k) = Q(\lfloor k \rfloor + 1, \mu)$. The quantiles are found by a search over the integers, starting from the normal approximation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson<T> {
+    mu: T,
+}
+
+impl<T> Poisson<T>
+where
+    T: Float,
+{
+    /// Creates a Poisson distribution with mean `mu`.
+    ///
+    /// Returns `None` unless `mu` is finite and non-negative.
+    pub fn new(mu: T) -> Option<Self> {
+        if mu.is_finite() && mu >= T::zero() {
+            Some(Self { mu })
+        } else {
+            None
+        }
+    }
+
+    /// The mean, $\mu$.
+    pub fn mu(&self) -> T {
+        self.mu
+    }
+}
+
+impl<T> Distribution<T> for Poisson<T>
+where
+    T: Float + RealGamma + Ndtr,
+{
+    fn pdf(&self, x: T) -> T {
+        self.logpdf(x).exp()
+    }
+
+    fn logpdf(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+        if x < T::zero() || x != x.floor() || x.is_infinite() {
+            return T::neg_infinity();
+        }
+        xlogy(x, self.mu) - self.mu - (x + T::one()).lgamma()
+    }
+
+    fn cdf(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+        if x < T::zero() {
+            return T::zero();
+        }
+        if x.is_infinite() {
+            return T::one();
+        }
+        (x.floor() + T::one()).gammaincc(self.mu)
+    }
+
+    fn logcdf(&self, x: T) -> T {
+        self.cdf(x).ln()
+    }
+
+    fn sf(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+        if x < T::zero() {
+            return T::one();
+        }
+        if x.is_infinite() {
+            return T::zero();
+        }
+        (x.floor() + T::one()).gammainc(self.mu)
+    }
+
+    fn logsf(&self, x: T) -> T {
+        self.sf(x).ln()
+    }
+
+    fn ppf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        if q.is_zero() {
+            return -T::one();
+        }
+        if q.is_one() {
+            return T::infinity();
+        }
+        let guess = self.mu + self.mu.sqrt() * q.ndtri();
+        discrete_search(guess, T::zero(), T::infinity(), |k| self.cdf(k) >= q)
+    }
+
+    fn isf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        if q.is_zero() {
+            return T::infinity();
+        }
+        if q.is_one() {
+            return -T::one();
+        }
+        let guess = self.mu - self.mu.sqrt() * q.ndtri();
+        discrete_search(guess, T::zero(), T::infinity(), |k| self.sf(k) <= q)
+    }
+
+    fn mean(&self) -> T {
+        self.mu
+    }
+
+    fn variance(&self) -> T {
+        self.mu
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 5]; 14] = [
+        // mu, k, logpmf, cdf, sf
+        [0.5, 0.0, -0.5, 0.6065306597126334, 0.3934693402873666],
+        [
+            0.5, 2.0, -2.5794415416798357, 0.9856123220330293, 0.014387677966970687,
+        ],
+        [
+            0.5,
+            7.0,
+            -13.87719162498503,
+            0.9999999378030914,
+            6.219690863728649e-08,
+        ],
+        [0.5, 200.0, -1002.3614233043945, 1.0, 0.0],
+        [3.0, 0.0, -3.0, 0.049787068367863944, 0.950212931632136],
+        [
+            3.0, 2.0, -1.4959226032237258, 0.42319008112684353, 0.5768099188731565,
+        ],
+        [
+            3.0, 7.0, -3.8348753403886464, 0.9880954961436426, 0.011904503856357388,
+        ],
+        [3.0, 150.0, -443.2282625492072, 1.0, 6.534491963506738e-195],
+        [3.0, 200.0, -646.5095294587835, 1.0, 2.540398586680665e-283],
+        [150.0, 0.0, -150.0, 7.175095973164411e-66, 1.0],
+        [150.0, 2.0, -140.67187659236743, 8.180326919004744e-62, 1.0],
+        [150.0, 7.0, -123.45071430239162, 2.5506138008293212e-54, 1.0],
+        [
+            150.0, 150.0, -3.4248117349853215, 0.5216971797074769, 0.47830282029252313,
+        ],
+        [
+            150.0,
+            200.0,
+            -11.104928373154323,
+            0.9999579411421362,
+            4.205885786379841e-05,
+        ],
+    ];
+
+    #[test]
+    fn test_poisson() {
+        for [mu, k, logpmf, cdf, sf] in KNOWN_VALUES {
+            let poisson = Poisson::new(mu).unwrap();
+            // k ln(mu) and ln(k!) cancel when k is close to mu
+            assert_almost_eq!(poisson.logpdf(k), logpmf, PRECISION * logpmf.abs().max(k));
+            assert_almost_eq!(poisson.cdf(k + 0.5), cdf, 10.0 * PRECISION * cdf);
+            assert_almost_eq!(
+                poisson.sf(k),
+                sf,
+                10.0 * PRECISION * sf.max(f64::MIN_POSITIVE)
+            );
+        }
+    }
+
+    #[test]
+    fn test_poisson_quantiles() {
+        for mu in [0.5, 3.0, 150.0, 1e6] {
+            let poisson = Poisson::new(mu).unwrap();
+            for q in [1e-10, 0.01, 0.3, 0.5, 0.9, 0.999] {
+                let k = poisson.ppf(q);
+                assert!(poisson.cdf(k) >= q);
+                assert!(k == 0.0 || poisson.cdf(k - 1.0) < q);
+
+                let k = poisson.isf(q);
+                assert!(poisson.sf(k) <= q);
+                assert!(k == 0.0 || poisson.sf(k - 1.0) > q);
+            }
+        }
+        let poisson = Poisson::new(3.0).unwrap();
+        assert_eq!(poisson.ppf(0.0), -1.0);
+        assert_eq!(poisson.ppf(1.0), f64::INFINITY);
+        assert_eq!(poisson.isf(0.0), f64::INFINITY);
+        assert_eq!(poisson.ppf(0.049787068367863944), 0.0);
+    }
+}
@@ -0,0 +1,354 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::distributions::{is_probability, Distribution};
+use crate::special::RealBeta;
+use num_traits::{Float, FloatConst};
+
+/// Student's t-distribution.
+/// $$
+/// f(t) = \frac{1}{\sqrt{\nu}B(\nu/2, 1/2)}\left(1+\frac{t^2}{\nu}\right)^{-\frac{\nu+1}{2}}
+/// $$
+/// with $\nu > 0$ degrees of freedom.
+///
+/// # Examples
+/// ```
+/// use sci_rs::distributions::{Distribution, StudentT};
+/// let t = StudentT::new(5.0_f64).unwrap();
+/// assert_eq!(t.cdf(0.0), 0.5);
+/// assert!((t.ppf(0.975) - 2.5705818356363146).abs() < 1e-14);
+/// assert!((t.variance() - 5.0 / 3.0).abs() < 1e-15);
+/// ```
+///
+/// # Notes
+/// The cumulative distribution function is evaluated with the regularized incomplete beta function,
+/// $$
+/// P(T \leq -|t|) = \frac{1}{2}I_{\nu/(\nu+t^2)}\left(\frac{\nu}{2}, \frac{1}{2}\right) = \frac{1}{2}\left[1 - I_{t^2/(\nu+t^2)}\left(\frac{1}{2}, \frac{\nu}{2}\right)\right]
+/// $$
+/// where the second form is used for $t^2 < \nu$, since $\nu/(\nu+t^2)$ is then close to one and its rounding error would dominate for large $\nu$. The complement in the brackets is evaluated directly with [betaincc], such that the tail retains its relative precision.
+///
+/// [betaincc]: crate::special::RealBeta::betaincc
+///
+/// The quantiles are evaluated with [betaincinv], where the symmetry $I_x(a,b) = 1 - I_{1-x}(b,a)$ is used close to the median. In the tails with $t^2 < \nu$, the inverse $x = \nu/(\nu+t^2)$ is close to one and $1-x$ has lost its precision, such that $t$ is refined with Newton's method on $\ln P(T \leq -|t|)$. For $\nu = 1$ and $\nu = 2$, the closed forms
+/// $$
+/// |t| = \cot(\pi p), \quad |t| = \frac{1-2p}{\sqrt{2p(1-p)}}
+/// $$
+/// are used instead, where $p$ is the tail probability. In the far tail, where the inverse $x$ of the incomplete beta function underflows, the leading term $I_x(a,b) \approx x^a/(aB(a,b))$ gives
+/// $$
+/// \ln|t| = \frac{1}{2}\ln\nu - \frac{1}{\nu}\ln\left(p\nu B\left(\frac{\nu}{2}, \frac{1}{2}\right)\right)
+/// $$
+///
+/// The logarithms of the distribution functions are the logarithms of [cdf](Distribution::cdf) and [sf](Distribution::sf), and are $-\infty$ once the tails underflow.
+///
+/// [betaincinv]: crate::special::RealBeta::betaincinv
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT<T> {
+    df: T,
+}
+
+impl<T> StudentT<T>
+where
+    T: Float,
+{
+    /// Creates a Student's t-distribution with `df` degrees of freedom.
+    ///
+    /// Returns `None` unless `df` is finite and positive.
+    pub fn new(df: T) -> Option<Self> {
+        if df.is_finite() && df > T::zero() {
+            Some(Self { df })
+        } else {
+            None
+        }
+    }
+
+    /// The degrees of freedom, $\nu$.
+    pub fn df(&self) -> T {
+        self.df
+    }
+}
+
+impl<T> StudentT<T>
+where
+    T: Float + FloatConst + RealBeta,
+{
+    /// Refines an approximation $t > 0$ to the quantile with the tail probability $p$, through Newton's method on $\ln P(T \leq -t) = \ln p$, which is close to quadratic in $t$.
+    fn refine_tail(&self, mut t: T, p: T) -> T {
+        const MAX_ITER: usize = 10;
+
+        let log_p = p.ln();
+        for _ in 0..MAX_ITER {
+            let tail = self.cdf(-t);
+            let step = (tail.ln() - log_p) * tail / self.pdf(t);
+            if !step.is_finite() {
+                break;
+            }
+            t = t + step;
+            if step.abs() <= T::epsilon() * t {
+                break;
+            }
+        }
+        t
+    }
+}
+
+impl<T> Distribution<T> for StudentT<T>
+where
+    T: Float + FloatConst + RealBeta,
+{
+    fn pdf(&self, x: T) -> T {
+        self.logpdf(x).exp()
+    }
+
+    fn logpdf(&self, x: T) -> T {
+        let half = (T::one() + T::one()).recip();
+        let nu = self.df;
+        -(nu + T::one()) * half * (x * x / nu).ln_1p() - half * nu.ln() - (nu * half).lbeta(half)
+    }
+
+    fn cdf(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+        let half = (T::one() + T::one()).recip();
+        let nu = self.df;
+        let x2 = x * x;
+        if x2 < nu {
+            let y = x2 / (nu + x2);
+            return if x < T::zero() {
+                half * half.betaincc(nu * half, y)
+            } else {
+                half + half * half.betainc(nu * half, y)
+            };
+        }
+        let tail = half * (nu * half).betainc(half, nu / (nu + x2));
+        if x < T::zero() {
+            tail
+        } else {
+            T::one() - tail
+        }
+    }
+
+    fn logcdf(&self, x: T) -> T {
+        self.cdf(x).ln()
+    }
+
+    fn sf(&self, x: T) -> T {
+        self.cdf(-x)
+    }
+
+    fn logsf(&self, x: T) -> T {
+        self.sf(x).ln()
+    }
+
+    fn ppf(&self, q: T) -> T {
+        if !is_probability(q) {
+            return T::nan();
+        }
+        let half = (T::one() + T::one()).recip();
+        if q == half {
+            return T::zero();
+        }
+        let nu = self.df;
+        let one = T::one();
+        let two = one + one;
+        let tail = q.min(one - q);
+
+        let t = if nu == one {
+            (T::PI() * tail).tan().recip()
+        } else if nu == two {
+            (one - two * tail) / (two * tail * (one - tail)).sqrt()
+        } else if tail < half * half {
+            let x = (nu * half).betaincinv(half, tail + tail);
+            if x > half {
+                // 1 - x has lost its precision, refine t on the tail instead
+                self.refine_tail((nu * (one - x) / x).sqrt(), tail)
+            } else if x.is_normal() {
+                (nu * (one - x) / x).sqrt()
+            } else {
+                let log = (tail * nu).ln() + (nu * half).lbeta(half);
+                (half * nu.ln() - log / nu).exp()
+            }
+        } else {
+            // Close to the median, solve for 1 - x instead
+            let y = half.betaincinv(nu * half, one - (tail + tail));
+            (nu * y / (one - y)).sqrt()
+        };
+        if q < half {
+            -t
+        } else {
+            t
+        }
+    }
+
+    fn isf(&self, q: T) -> T {
+        -self.ppf(q)
+    }
+
+    fn mean(&self) -> T {
+        if self.df > T::one() {
+            T::zero()
+        } else {
+            T::nan()
+        }
+    }
+
+    fn variance(&self) -> T {
+        let two = T::one() + T::one();
+        if self.df > two {
+            self.df / (self.df - two)
+        } else if self.df > T::one() {
+            T::infinity()
+        } else {
+            T::nan()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 3]; 20] = [
+        // df, t, cdf
+        [1.0, -50.0, 0.006365349100972796],
+        [1.0, -2.0, 0.14758361765043326],
+        [1.0, -0.1, 0.4682744825694464],
+        [1.0, 0.5, 0.6475836176504333],
+        [1.0, 3.0, 0.8975836176504333],
+        [2.5, -50.0, 4.0652475424793605e-05],
+        [2.5, -2.0, 0.078695747878983],
+        [2.5, -0.1, 0.46390327203124476],
+        [2.5, 0.5, 0.6711510400651427],
+        [2.5, 3.0, 0.9637119522254841],
+        [5.0, -50.0, 3.0238788133006125e-08],
+        [5.0, -2.0, 0.05096973941492918],
+        [5.0, -0.1, 0.4621150705773302],
+        [5.0, 0.5, 0.6808505641795355],
+        [5.0, 3.0, 0.9849503760512687],
+        [30.0, -50.0, 9.357708829611357e-31],
+        [30.0, -2.0, 0.02731252248149155],
+        [30.0, -0.1, 0.4605048058951356],
+        [30.0, 0.5, 0.6896384975574363],
+        [30.0, 3.0, 0.9973050179671741],
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_QUANTILES: [[f64; 3]; 20] = [
+        // df, q, ppf
+        [1.0, 1e-10, -3183098861.837907],
+        [1.0, 0.01, -31.820515953773956],
+        [1.0, 0.3, -0.7265425280053609],
+        [1.0, 0.6, 0.32491969623290623],
+        [1.0, 0.975, 12.706204736174694],
+        [2.5, 1e-10, -8765.437771364572],
+        [2.5, 0.01, -5.353111173030874],
+        [2.5, 0.3, -0.5973077382523175],
+        [2.5, 0.6, 0.2814595127485476],
+        [2.5, 0.975, 3.5746548420036817],
+        [5.0, 1e-10, -156.8255927088943],
+        [5.0, 0.01, -3.3649299989072188],
+        [5.0, 0.3, -0.5594296444693608],
+        [5.0, 0.6, 0.26718086570414507],
+        [5.0, 0.975, 2.5705818356363146],
+        [30.0, 1e-10, -9.377489780407144],
+        [30.0, 0.01, -2.4572615424005915],
+        [30.0, 0.3, -0.5300190039065045],
+        [30.0, 0.6, 0.2556053649519127],
+        [30.0, 0.975, 2.0422724563012378],
+    ];
+
+    #[test]
+    fn test_student_t_cdf() {
+        for [df, t, cdf] in KNOWN_VALUES {
+            let student_t = StudentT::new(df).unwrap();
+            assert_almost_eq!(student_t.cdf(t), cdf, PRECISION * cdf);
+            assert_almost_eq!(student_t.sf(-t), cdf, PRECISION * cdf);
+        }
+    }
+
+    #[test]
+    fn test_student_t_ppf() {
+        for [df, q, t] in KNOWN_QUANTILES {
+            let student_t = StudentT::new(df).unwrap();
+            assert_almost_eq!(student_t.ppf(q), t, PRECISION * t.abs());
+            assert_almost_eq!(student_t.isf(q), -t, PRECISION * t.abs());
+        }
+    }
+
+    #[test]
+    fn test_student_t_large_df() {
+        // Values from mpmath (v 1.3.0)
+        const LARGE_DF: [[f64; 3]; 13] = [
+            // df, t, cdf
+            [1e7, 0.5, 0.6914624557729925],
+            [1e7, -1e-3, 0.4996010577960625],
+            [1e7, -2.0, 0.022750145445921476],
+            [1e10, 1.5, 0.933192798715357],
+            [1e5, -0.3, 0.38208888959516885],
+            [3.5, -1e-4, 0.49996282276570647],
+            [1000.0, -10.0, 8.335351479300033e-23],
+            [1000.0, -8.0, 1.7133307411957373e-15],
+            [1e6, -20.0, 2.866543523695186e-89],
+            [1e7, -5.0, 2.8665640375042696e-07],
+            [1e7, -30.0, 5.0073068454089e-198],
+            [1e10, -8.0, 6.220961231067057e-16],
+            [50.0, -3.0, 0.0021008515935341237],
+        ];
+        for [df, t, cdf] in LARGE_DF {
+            let student_t = StudentT::new(df).unwrap();
+            assert_almost_eq!(student_t.cdf(t) / cdf, 1.0, 1e-13);
+            assert_almost_eq!(student_t.sf(-t) / cdf, 1.0, 1e-13);
+            if t < -1.0 {
+                assert_almost_eq!(student_t.ppf(cdf) / t, 1.0, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_student_t_deep_tail() {
+        // Values from mpmath (v 1.3.0)
+        const DEEP_QUANTILES: [[f64; 3]; 3] = [
+            // df, q, ppf
+            [1.0, 1e-300, -3.1830988618379066e+299],
+            [1.0, 1e-20, -3.1830988618379067e+19],
+            [2.0, 1e-300, -7.071067811865475e+149],
+        ];
+        for [df, q, t] in DEEP_QUANTILES {
+            let student_t = StudentT::new(df).unwrap();
+            assert_almost_eq!(student_t.ppf(q), t, PRECISION * t.abs());
+            assert_almost_eq!(student_t.isf(q), -t, PRECISION * t.abs());
+        }
+
+        // The quantile is the exponential of its logarithm, whose rounding error is proportional to ln|t|
+        const ASYMPTOTIC_QUANTILES: [[f64; 3]; 3] = [
+            // df, q, ppf
+            [1.5, 1e-300, -5.219469427344636e+199],
+            [0.5, 1e-100, -1.02849115631634e+199],
+            [3.0, 1e-300, -1.033110836044653e+100],
+        ];
+        for [df, q, t] in ASYMPTOTIC_QUANTILES {
+            let student_t = StudentT::new(df).unwrap();
+            let tolerance = 4.0 * f64::EPSILON * t.abs().ln() * t.abs();
+            assert_almost_eq!(student_t.ppf(q), t, tolerance);
+            assert_almost_eq!(student_t.isf(q), -t, tolerance);
+        }
+    }
+
+    #[test]
+    fn test_student_t_pdf() {
+        // Values from mpmath (v 1.3.0)
+        let student_t = StudentT::new(2.5).unwrap();
+        assert_almost_eq!(student_t.pdf(-3.0), 0.0250410669313931, PRECISION);
+        assert_almost_eq!(student_t.pdf(0.0), 0.36180872402956504, PRECISION);
+        assert_almost_eq!(student_t.pdf(1.5), 0.11766850421719718, PRECISION);
+
+        assert!(StudentT::new(1.0).unwrap().mean().is_nan());
+        assert_eq!(student_t.variance(), 5.0);
+        assert_eq!(StudentT::new(2.0).unwrap().variance(), f64::INFINITY);
+    }
+}
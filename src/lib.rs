@@ -26,6 +26,8 @@
 //! - Bernoulli, Tangent, and Secant numbers
 //! - Gamma Function (real and complex)
 //! - Error Function (real)
+//! - Riemann and Hurwitz Zeta Functions (real and complex)
+//! - Probability distributions (Normal, Gamma, Beta, Student's t, Poisson, ...)
 //!
 //! [SciPy]: https://scipy.org/
 
@@ -61,5 +63,7 @@ macro_rules! assert_almost_eq {
 }
 
 pub mod constants;
+pub mod distributions;
+pub mod prelude;
 pub mod special;
 pub mod traits;
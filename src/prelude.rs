@@ -0,0 +1,20 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Re-exports of the traits which provide the special functions and distributions as methods.
+//!
+//! Functions for real and complex-valued arguments are split over two traits, e.g. [Erf] and [RealErf], which are both brought into scope by
+//! ```
+//! use sci_rs::prelude::*;
+//! assert!((0.5_f64.erf_inv() - 0.4769362762044699).abs() < 1e-15);
+//! ```
+
+pub use crate::distributions::Distribution;
+pub use crate::special::{
+    Airy, Bernoulli, Bessel, Beta, CheckedComb, CheckedFactorial, Comb, ConfluentHypergeometric,
+    Erf, ExpInt, Factorial, Faddeeva, Fresnel, Gamma, GaussHypergeometric, ModifiedBessel, Ndtr,
+    OwensT, RealBeta, RealErf, RealFaddeeva, RealGamma, RealZeta, SiCi, SphericalBessel, Zeta,
+    ZigZag,
+};
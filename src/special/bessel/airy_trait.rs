@@ -0,0 +1,119 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::complex_bessel_impl::*;
+use crate::special::bessel::real_bessel_impl::*;
+
+use num_complex::Complex;
+
+/// Airy functions and their derivatives for both real and complex-valued arguments.
+pub trait Airy {
+    /// Airy functions of the first and second kind and their derivatives.
+    ///
+    /// Returns the tuple $(\mathrm{Ai}(z), \mathrm{Ai}'(z), \mathrm{Bi}(z), \mathrm{Bi}'(z))$, where $\mathrm{Ai}$ and $\mathrm{Bi}$ are the solutions of the Airy equation
+    /// $$
+    /// \frac{d^2w}{dz^2} = zw
+    /// $$
+    /// with $\mathrm{Ai}(z) \to 0$ as $z \to \infty$ along the real axis. Both are entire functions. For real $x$, they oscillate for $x < 0$ and $\mathrm{Ai}$ decays while $\mathrm{Bi}$ grows exponentially for $x > 0$. See the [DLMF] or [wiki] page for more details.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::Airy;
+    /// let (ai, aip, bi, bip) = 1.0_f64.airy();
+    /// assert!((ai - 0.13529241631288141).abs() < 1e-15);
+    /// assert!((aip + 0.1591474412967932).abs() < 1e-15);
+    /// assert!((bi - 1.2074235949528713).abs() < 1e-15);
+    /// assert!((bip - 0.9324359333927756).abs() < 1e-15);
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::Airy;
+    /// use num_complex::Complex64;
+    /// let (ai, _, bi, _) = Complex64::new(1.0, 1.0).airy();
+    /// println!("{} {}", ai, bi); // 0.0605 - 0.1519j, 0.7167 + 0.6199j
+    /// ```
+    /// # Notes
+    /// For $|z| \leq 1$, the Maclaurin series is summed, with the values at the origin
+    /// $$
+    /// \mathrm{Ai}(0) = \frac{1}{3^{2/3}\Gamma(2/3)}, \quad \mathrm{Ai}'(0) = -\frac{1}{3^{1/3}\Gamma(1/3)}
+    /// $$
+    /// and $\mathrm{Bi}(0) = \sqrt{3}\mathrm{Ai}(0)$, $\mathrm{Bi}'(0) = -\sqrt{3}\mathrm{Ai}'(0)$.
+    ///
+    /// For real arguments with $|x| \geq 10$, the asymptotic expansions in $\zeta = \frac{2}{3}|x|^{3/2}$ are used, see [DLMF 9.7](https://dlmf.nist.gov/9.7). Otherwise, the Airy functions are related to the Bessel functions of order $1/3$ and $2/3$, as in [Amos]. For $|\arg z| \leq \pi/3$, this uses the modified Bessel functions $I_\nu(\zeta)$ and $K_\nu(\zeta)$, and the Bessel functions $J_{\pm\nu}(\xi)$ with $\xi = \frac{2}{3}(-z)^{3/2}$ otherwise, see [DLMF 9.6](https://dlmf.nist.gov/9.6).
+    ///
+    /// # References
+    /// - [Amos]
+    /// - [DLMF]
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [DLMF]: https://dlmf.nist.gov/9.2
+    /// [wiki]: https://en.wikipedia.org/wiki/Airy_function
+    /// [Amos]: https://doi.org/10.1145/7921.214331
+    fn airy(self) -> (Self, Self, Self, Self)
+    where
+        Self: Sized;
+
+    /// Exponentially scaled Airy functions and their derivatives.
+    ///
+    /// Returns the tuple
+    /// $$
+    /// \left(e^{\zeta}\mathrm{Ai}(z), e^{\zeta}\mathrm{Ai}'(z), e^{-|\Re\zeta|}\mathrm{Bi}(z), e^{-|\Re\zeta|}\mathrm{Bi}'(z)\right)
+    /// $$
+    /// with $\zeta = \frac{2}{3}z^{3/2}$, which removes the exponential behavior for large $|z|$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Airy;
+    /// let (eai, _, ebi, _) = 200.0_f64.airye();
+    /// assert!((eai - 0.07501041684381093).abs() < 1e-16);
+    /// assert!((ebi - 0.15003188417418148).abs() < 1e-16);
+    /// assert_eq!(200.0_f64.airy().2, f64::INFINITY); // See airye
+    /// ```
+    /// # Notes
+    /// For real $x < 0$, the scaling factor $e^{\zeta}$ is complex, such that the scaled $\mathrm{Ai}$ and $\mathrm{Ai}'$ are NaN, while $\mathrm{Bi}$ and $\mathrm{Bi}'$ are not scaled. See [airy] for the implementation.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [airy]: crate::special::Airy::airy
+    fn airye(self) -> (Self, Self, Self, Self)
+    where
+        Self: Sized;
+}
+
+macro_rules! float_airy_impl {
+    ($($T: ty)*) => ($(
+        impl Airy for $T {
+            #[inline(always)]
+            fn airy(self) -> (Self, Self, Self, Self) {
+                r_airy(self)
+            }
+
+            #[inline(always)]
+            fn airye(self) -> (Self, Self, Self, Self) {
+                r_airye(self)
+            }
+        }
+    )*)
+}
+
+float_airy_impl! {f32 f64}
+
+macro_rules! complex_airy_impl {
+    ($($T: ty)*) => ($(
+        impl Airy for Complex<$T> {
+            #[inline(always)]
+            fn airy(self) -> (Self, Self, Self, Self) {
+                c_airy(self)
+            }
+
+            #[inline(always)]
+            fn airye(self) -> (Self, Self, Self, Self) {
+                c_airye(self)
+            }
+        }
+    )*)
+}
+
+complex_airy_impl! {f32 f64}
@@ -0,0 +1,335 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::complex_bessel_impl::*;
+use crate::special::bessel::real_bessel_impl::*;
+
+use num_complex::Complex;
+use num_traits::cast;
+
+/// Bessel functions of the first and second kind for both real and complex-valued arguments.
+pub trait Bessel {
+    /// Real type of the order $\nu$.
+    type Real;
+
+    /// Bessel function of the first kind of order 0.
+    /// $$
+    /// J_0(z) = \frac{1}{\pi}\int_0^\pi\cos(z\sin\theta)d\theta
+    /// $$
+    /// See [jv] for the general order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((1.0_f64.j0() - 0.7651976865579666).abs() < 1e-15);
+    /// assert!((10.0_f64.j0() + 0.2459357644513483).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.j0(), 1.0);
+    /// ```
+    /// # Notes
+    /// For real arguments, the implementation follows the structure of [cephes]. For $|x| \leq 5$, the first zero $j_{0,1}$ is factored out and the remainder is a Chebyshev expansion in $x^2$. Otherwise, the modulus-phase form
+    /// $$
+    /// J_0(x) = \sqrt{\frac{2}{\pi x}}\left(P_0(x)\cos\xi - Q_0(x)\sin\xi\right)
+    /// $$
+    /// with $\xi = x - \pi/4$ is used, where $P_0$ and $xQ_0$ are Chebyshev expansions in $1/x^2$. Complex arguments are evaluated with [jv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [jv]: crate::special::Bessel::jv
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/j0.c
+    fn j0(self) -> Self;
+
+    /// Bessel function of the first kind of order 1.
+    /// $$
+    /// J_1(z) = \frac{1}{\pi}\int_0^\pi\cos(\theta - z\sin\theta)d\theta
+    /// $$
+    /// See [jv] for the general order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((1.0_f64.j1() - 0.44005058574493355).abs() < 1e-15);
+    /// assert!(((-1.0_f64).j1() + 0.44005058574493355).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.j1(), 0.0);
+    /// ```
+    /// # Notes
+    /// For real arguments, the implementation follows the structure of [cephes], see [j0]. Complex arguments are evaluated with [jv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [jv]: crate::special::Bessel::jv
+    /// [j0]: crate::special::Bessel::j0
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/j1.c
+    fn j1(self) -> Self;
+
+    /// Bessel function of the first kind of integer order $n$.
+    ///
+    /// For negative orders, $J_{-n}(z) = (-1)^nJ_n(z)$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((2.5_f64.jn(3) - 0.21660039103911352).abs() < 1e-15);
+    /// assert!((2.5_f64.jn(-3) + 0.21660039103911352).abs() < 1e-15);
+    /// ```
+    /// # Notes
+    /// For real arguments, the forward recurrence
+    /// $$
+    /// J_{n+1}(x) = \frac{2n}{x}J_n(x) - J_{n-1}(x)
+    /// $$
+    /// from [j0] and [j1] is used for $n \leq x$, where it is stable. Otherwise, the ratio $J_n(x)/J_{n-1}(x)$ is found from its continued fraction and the recurrence is run backwards, which is normalized by $J_0(x)$ or $J_1(x)$. Complex arguments are evaluated with [jv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [jv]: crate::special::Bessel::jv
+    /// [j0]: crate::special::Bessel::j0
+    /// [j1]: crate::special::Bessel::j1
+    fn jn(self, n: i32) -> Self;
+
+    /// Bessel function of the first kind of real order $\nu$.
+    /// $$
+    /// J_\nu(z) = \left(\frac{z}{2}\right)^\nu\sum_{k=0}^{\infty}\frac{(-z^2/4)^k}{k!\Gamma(\nu+k+1)}
+    /// $$
+    /// It is a solution of Bessel's differential equation
+    /// $$
+    /// z^2\frac{d^2w}{dz^2} + z\frac{dw}{dz} + (z^2 - \nu^2)w = 0
+    /// $$
+    /// which is bounded at the origin for $\nu \geq 0$. For non-integer $\nu$, there is a branch cut along the negative real axis and NaN is returned for negative real arguments. See the [DLMF] or [wiki] pages for more details.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((1.5_f64.jv(2.5) - 0.1244463597983876).abs() < 1e-15);
+    /// assert!((3.0_f64.jv(0.5) - 0.06500818287737578).abs() < 1e-15);
+    /// assert!((2.5_f64.jv(3.0) - 2.5_f64.jn(3)).abs() < 1e-15);
+    /// assert!((-1.0_f64).jv(0.5).is_nan());
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(1.0, 1.0);
+    /// let expected = Complex64::new(0.9679012828901306, 0.0602046062142817);
+    /// assert!((z.jv(0.5) - expected).norm() < 1e-15);
+    /// ```
+    /// # Notes
+    /// For real arguments, Hankel's asymptotic expansion is used for $x \geq \max(25, \nu^2/2)$. Otherwise, we follow the algorithm `bessjy` of [Numerical Recipes], which combines the continued fraction for $J_\nu'/J_\nu$ with Temme's series for $x < 2$ and Steed's method for $x \geq 2$. Integer orders are evaluated with [jn].
+    ///
+    /// For complex arguments, the power series is used for $|z|^2 \leq \nu + 1$, normalized with the [Gamma] function. Otherwise, we use $J_\nu(z) = e^{\pm i\nu\pi/2}I_\nu(\mp iz)$ to reduce to the modified Bessel function $I_\nu$ in the right half-plane. It is computed together with $K_\nu$ by the complex extension of the algorithm `bessik` of [Numerical Recipes], or with their asymptotic expansions for large $|z|$.
+    ///
+    /// Negative orders use the reflection formula
+    /// $$
+    /// J_{-\nu}(z) = \cos(\nu\pi)J_\nu(z) - \sin(\nu\pi)Y_\nu(z)
+    /// $$
+    ///
+    /// # References
+    /// - [Numerical Recipes]
+    /// - N. M. Temme, "On the numerical evaluation of the ordinary Bessel function of the second kind", J. Comput. Phys. 21, 343 (1976).
+    /// - [DLMF]
+    /// - [Wikipedia][wiki]
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [jn]: crate::special::Bessel::jn
+    /// [Gamma]: crate::special::Gamma::gamma
+    /// [Numerical Recipes]: https://numerical.recipes/
+    /// [DLMF]: https://dlmf.nist.gov/10.2
+    /// [wiki]: https://en.wikipedia.org/wiki/Bessel_function
+    fn jv(self, nu: Self::Real) -> Self;
+
+    /// Bessel function of the second kind of order 0.
+    ///
+    /// See [yv] for the general order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((1.0_f64.y0() - 0.08825696421567697).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.y0(), f64::NEG_INFINITY);
+    /// assert!((-1.0_f64).y0().is_nan());
+    /// ```
+    /// # Notes
+    /// For real arguments, the implementation follows the structure of [cephes]. For $x \leq 5$, we use
+    /// $$
+    /// Y_0(x) = \frac{2}{\pi}J_0(x)\ln x + R(x^2)
+    /// $$
+    /// where $R$ is a Chebyshev expansion. Otherwise, the modulus-phase form is used, see [j0]. Complex arguments are evaluated with [yv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [yv]: crate::special::Bessel::yv
+    /// [j0]: crate::special::Bessel::j0
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/j0.c
+    fn y0(self) -> Self;
+
+    /// Bessel function of the second kind of order 1.
+    ///
+    /// See [yv] for the general order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((2.5_f64.y1() - 0.1459181379667858).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.y1(), f64::NEG_INFINITY);
+    /// ```
+    /// # Notes
+    /// For real arguments, the implementation follows the structure of [cephes], see [y0]. Complex arguments are evaluated with [yv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [yv]: crate::special::Bessel::yv
+    /// [y0]: crate::special::Bessel::y0
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/j1.c
+    fn y1(self) -> Self;
+
+    /// Bessel function of the second kind of integer order $n$.
+    ///
+    /// For negative orders, $Y_{-n}(z) = (-1)^nY_n(z)$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((2.5_f64.yn(3) + 0.756055496753671).abs() < 1e-15);
+    /// assert!((2.5_f64.yn(-3) - 0.756055496753671).abs() < 1e-15);
+    /// ```
+    /// # Notes
+    /// For real arguments, the forward recurrence from [y0] and [y1] is used, which is stable for all orders. Complex arguments are evaluated with [yv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [yv]: crate::special::Bessel::yv
+    /// [y0]: crate::special::Bessel::y0
+    /// [y1]: crate::special::Bessel::y1
+    fn yn(self, n: i32) -> Self;
+
+    /// Bessel function of the second kind of real order $\nu$.
+    /// $$
+    /// Y_\nu(z) = \frac{J_\nu(z)\cos(\nu\pi) - J_{-\nu}(z)}{\sin(\nu\pi)}
+    /// $$
+    /// which is understood as its limit for integer $\nu$. It is the second solution of Bessel's differential equation, see [jv], and is singular at the origin. There is a branch cut along the negative real axis, and NaN is returned for negative real arguments. See the [DLMF] page for more details.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// assert!((1.5_f64.yv(2.5) + 1.3150372048051937).abs() < 1e-15);
+    /// assert!((3.0_f64.yv(-0.5) - 0.06500818287737578).abs() < 1e-15);
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::Bessel;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(1.0, 1.0);
+    /// let expected = Complex64::new(-0.2628946385649067, 0.8270501820405616);
+    /// assert!((z.yv(0.5) - expected).norm() < 1e-15);
+    /// ```
+    /// # Notes
+    /// Real arguments follow [jv]. For complex arguments with $\Im z \geq 0$, we use the Hankel function
+    /// $$
+    /// Y_\nu(z) = i\left(J_\nu(z) - H^{(1)}_\nu(z)\right), \quad H^{(1)}_\nu(z) = -\frac{2i}{\pi}e^{-i\nu\pi/2}K_\nu(-iz)
+    /// $$
+    /// where $K_\nu$ is computed together with $I_\nu$ in the right half-plane. The lower half-plane follows from $Y_\nu(\bar{z}) = \overline{Y_\nu(z)}$. Negative orders use the reflection formula
+    /// $$
+    /// Y_{-\nu}(z) = \sin(\nu\pi)J_\nu(z) + \cos(\nu\pi)Y_\nu(z)
+    /// $$
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [jv]: crate::special::Bessel::jv
+    /// [DLMF]: https://dlmf.nist.gov/10.2
+    fn yv(self, nu: Self::Real) -> Self;
+}
+
+macro_rules! float_bessel_impl {
+    ($($T: ty)*) => ($(
+        impl Bessel for $T {
+            type Real = $T;
+
+            #[inline(always)]
+            fn j0(self) -> Self {
+                r_j0(self)
+            }
+
+            #[inline(always)]
+            fn j1(self) -> Self {
+                r_j1(self)
+            }
+
+            #[inline(always)]
+            fn jn(self, n: i32) -> Self {
+                r_jn(n, self)
+            }
+
+            #[inline(always)]
+            fn jv(self, nu: Self) -> Self {
+                r_jv(nu, self)
+            }
+
+            #[inline(always)]
+            fn y0(self) -> Self {
+                r_y0(self)
+            }
+
+            #[inline(always)]
+            fn y1(self) -> Self {
+                r_y1(self)
+            }
+
+            #[inline(always)]
+            fn yn(self, n: i32) -> Self {
+                r_yn(n, self)
+            }
+
+            #[inline(always)]
+            fn yv(self, nu: Self) -> Self {
+                r_yv(nu, self)
+            }
+        }
+    )*)
+}
+
+float_bessel_impl! {f32 f64}
+
+macro_rules! complex_bessel_impl {
+    ($($T: ty)*) => ($(
+        impl Bessel for Complex<$T> {
+            type Real = $T;
+
+            #[inline(always)]
+            fn j0(self) -> Self {
+                c_jv(0.0, self)
+            }
+
+            #[inline(always)]
+            fn j1(self) -> Self {
+                c_jv(1.0, self)
+            }
+
+            #[inline(always)]
+            fn jn(self, n: i32) -> Self {
+                c_jv(cast::<i32, $T>(n).unwrap(), self)
+            }
+
+            #[inline(always)]
+            fn jv(self, nu: $T) -> Self {
+                c_jv(nu, self)
+            }
+
+            #[inline(always)]
+            fn y0(self) -> Self {
+                c_yv(0.0, self)
+            }
+
+            #[inline(always)]
+            fn y1(self) -> Self {
+                c_yv(1.0, self)
+            }
+
+            #[inline(always)]
+            fn yn(self, n: i32) -> Self {
+                c_yv(cast::<i32, $T>(n).unwrap(), self)
+            }
+
+            #[inline(always)]
+            fn yv(self, nu: $T) -> Self {
+                c_yv(nu, self)
+            }
+        }
+    )*)
+}
+
+complex_bessel_impl! {f32 f64}
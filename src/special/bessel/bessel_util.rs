@@ -0,0 +1,107 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::eval_poly;
+use num_traits::{cast, Float, FloatConst};
+
+/// Largest term allowed in the asymptotic expansions, which bounds the loss of precision to about one digit.
+pub(crate) const MAX_TERM: f64 = 10.0;
+
+/// Taylor coefficients for the Temme gamma functions $\Gamma_1(\mu)$ and $\Gamma_2(\mu)$ in $\mu^2$, computed with mpmath.
+pub(crate) trait TemmeConsts: Sized {
+    const GAM1: [Self; 11];
+    const GAM2: [Self; 11];
+}
+
+macro_rules! impl_temmeconsts {
+    ($($T: ty)*) => ($(
+        impl TemmeConsts for $T {
+            const GAM1: [Self; 11] = [
+                -5.100370287454476e-13,
+                -7.782263439905071e-12,
+                1.18127457048702e-09,
+                -6.116095104481416e-09,
+                -1.133027231981696e-06,
+                2.013485478078824e-05,
+                0.00021524167411495098,
+                -0.0072189432466631,
+                0.04219773455554433,
+                0.04200263503409524,
+                -0.5772156649015329,
+            ];
+            const GAM2: [Self; 11] = [
+                -3.696805618642206e-12,
+                1.0434267116911005e-10,
+                5.002007644469223e-09,
+                -2.056338416977607e-07,
+                -1.2504934821426706e-06,
+                0.0001280502823881162,
+                -0.0011651675918590652,
+                -0.009621971527876973,
+                0.16653861138229148,
+                -0.6558780715202539,
+                1.0,
+            ];
+        }
+    )*)
+}
+
+impl_temmeconsts! {f32 f64}
+
+/// Computes $\sin(\pi x)$, which is exactly $0$ for integer $x$.
+pub(crate) fn sin_pi<T>(x: T) -> T
+where
+    T: Float + FloatConst,
+{
+    let one = T::one();
+    let half = cast::<f64, T>(0.5).unwrap();
+
+    let mut sign = x.signum();
+    let mut r = x.abs() % (one + one);
+    if r > one {
+        r = r - one;
+        sign = -sign;
+    }
+    if r > half {
+        r = one - r;
+    }
+    sign * (T::PI() * r).sin()
+}
+
+/// Computes $\cos(\pi x)$, which is exactly $0$ for half-integer $x$.
+pub(crate) fn cos_pi<T>(x: T) -> T
+where
+    T: Float + FloatConst,
+{
+    let one = T::one();
+    let half = cast::<f64, T>(0.5).unwrap();
+
+    let mut sign = one;
+    let mut r = x.abs() % (one + one);
+    if r > one {
+        r = r - one;
+        sign = -sign;
+    }
+    if r < half * half {
+        sign * (T::PI() * r).cos()
+    } else {
+        sign * sin_pi(half - r)
+    }
+}
+
+/// Computes the functions of Temme's method for $|\mu| \leq 1/2$,
+/// $$
+/// \Gamma_1(\mu) = \frac{1}{2\mu}\left(\frac{1}{\Gamma(1-\mu)} - \frac{1}{\Gamma(1+\mu)}\right), \quad \Gamma_2(\mu) = \frac{1}{2}\left(\frac{1}{\Gamma(1-\mu)} + \frac{1}{\Gamma(1+\mu)}\right)
+/// $$
+/// together with $1/\Gamma(1+\mu)$ and $1/\Gamma(1-\mu)$. Both functions are even in $\mu$ and are evaluated from the Taylor series of $1/\Gamma(1+\mu)$.
+pub(crate) fn temme_gamma<T>(mu: T) -> (T, T, T, T)
+where
+    T: Float + TemmeConsts,
+{
+    let mu2 = mu * mu;
+    let gam1 = eval_poly(mu2, &T::GAM1);
+    let gam2 = eval_poly(mu2, &T::GAM2);
+    (gam1, gam2, gam2 - mu * gam1, gam2 + mu * gam1)
+}
@@ -0,0 +1,364 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::bessel_util::TemmeConsts;
+use crate::special::bessel::c_ik::c_ik_scaled;
+use crate::special::bessel::c_iv::iv_series;
+use crate::special::bessel::r_airy::{airy_series, r_airy, r_airye};
+use crate::special::bessel::r_ik0::BesselIK0Consts;
+use crate::special::bessel::r_ik1::BesselIK1Consts;
+use crate::special::Gamma;
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// Computes $\zeta = \frac{2}{3}z^{3/2}$ with the principal branch.
+fn airy_zeta<T: Float>(z: Complex<T>) -> Complex<T> {
+    z * z.sqrt() * cast::<f64, T>(2.0 / 3.0).unwrap()
+}
+
+/// Computes $e^{-\Re w}I_\nu(w)$ and $e^wK_\nu(w)$ for $\nu > 0$ and $\Re w \geq 0$, $w \neq 0$, replacing $I_\nu$ by its power series for $|w|^2 \leq \nu + 1$, as in [c_ive](crate::special::bessel::c_iv::c_ive).
+fn ik_scaled<T>(nu: T, w: Complex<T>) -> (Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + Gamma + TemmeConsts,
+{
+    let (ive, kve) = c_ik_scaled(nu, w);
+    if w.norm_sqr() <= nu + T::one() {
+        return (iv_series(nu, w), kve);
+    }
+    (ive, kve)
+}
+
+/// Computes the scaled Airy functions for $|z| > 1$, see [c_airye].
+///
+/// For $|\arg z| \leq \pi/3$, we have $\Re\zeta \geq 0$ and use the modified Bessel functions, [DLMF 9.6.1](https://dlmf.nist.gov/9.6#E1) to [DLMF 9.6.4](https://dlmf.nist.gov/9.6#E4),
+/// $$
+/// \mathrm{Ai}(z) = \frac{1}{\pi}\sqrt{\frac{z}{3}}K_{1/3}(\zeta), \quad \mathrm{Bi}(z) = \sqrt{\frac{z}{3}}\left(2I_{1/3}(\zeta) + \frac{\sqrt{3}}{\pi}K_{1/3}(\zeta)\right)
+/// $$
+/// Otherwise, with $w = -z$ and $\xi = \frac{2}{3}w^{3/2}$, we use [DLMF 9.6.6](https://dlmf.nist.gov/9.6#E6) to [DLMF 9.6.9](https://dlmf.nist.gov/9.6#E9),
+/// $$
+/// \mathrm{Ai}(z) = \frac{\sqrt{w}}{3}\left(J_{1/3}(\xi) + J_{-1/3}(\xi)\right), \quad \mathrm{Bi}(z) = \sqrt{\frac{w}{3}}\left(J_{-1/3}(\xi) - J_{1/3}(\xi)\right)
+/// $$
+/// where $J_{\pm\nu}(\xi) = e^{\pm i\nu\pi/2}I_{\pm\nu}(-i\xi)$ for $\Im\xi \geq 0$, and by conjugation otherwise. The Bessel functions are scaled by $e^{-|\Im\xi|}$. In this sector, $\Re\zeta = -|\Im\xi|$, such that the scaling factors reduce to $e^{i\Im\zeta}$ for $\mathrm{Ai}$ and $1$ for $\mathrm{Bi}$. The derivatives follow in the same way from the order $2/3$.
+fn airy_scaled<T>(z: Complex<T>) -> (Complex<T>, Complex<T>, Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + Gamma + TemmeConsts,
+{
+    let three = cast::<f64, T>(3.0).unwrap();
+    let third = three.recip();
+    let sqrt3 = three.sqrt();
+    let two = T::one() + T::one();
+    // 2 sin(nu pi) / pi for nu = 1/3 and 2/3
+    let reflection = sqrt3 * T::FRAC_1_PI();
+
+    let zeta = airy_zeta(z);
+    if z.re >= T::zero() && z.im.abs() <= sqrt3 * z.re {
+        let (i1, k1) = ik_scaled(third, zeta);
+        let (i2, k2) = ik_scaled(third + third, zeta);
+        // e^{-Re zeta} K = e^{-Re zeta - zeta} (e^zeta K)
+        let scale = (-zeta - zeta.re).exp() * reflection;
+        let root = (z / three).sqrt();
+        return (
+            root * k1 * T::FRAC_1_PI(),
+            -z * k2 * (T::FRAC_1_PI() / sqrt3),
+            root * (i1 * two + scale * k1),
+            z / sqrt3 * (i2 * two + scale * k2),
+        );
+    }
+
+    let w = -z;
+    let xi = airy_zeta(w);
+    let conjugate = xi.im < T::zero();
+    let u = if conjugate {
+        Complex::new(-xi.im, -xi.re)
+    } else {
+        Complex::new(xi.im, -xi.re)
+    };
+    let jv = |nu: T| {
+        let (ive, kve) = ik_scaled(nu, u);
+        let rotation = Complex::new(T::zero(), nu * T::FRAC_PI_2()).exp();
+        let plus = rotation * ive;
+        let minus = rotation.conj() * (ive + (-u - u.re).exp() * kve * reflection);
+        if conjugate {
+            (plus.conj(), minus.conj())
+        } else {
+            (plus, minus)
+        }
+    };
+    let (j1, jm1) = jv(third);
+    let (j2, jm2) = jv(third + third);
+    let root = w.sqrt();
+    let phase = Complex::new(T::zero(), zeta.im).exp();
+    (
+        phase * root / three * (j1 + jm1),
+        phase * w / three * (j2 - jm2),
+        root / sqrt3 * (jm1 - j1),
+        w / sqrt3 * (jm2 + j2),
+    )
+}
+
+/// Exponentially scaled Airy functions for complex arguments implementation.
+///
+/// Returns $(e^{\zeta}\mathrm{Ai}(z), e^{\zeta}\mathrm{Ai}'(z), e^{-|\Re\zeta|}\mathrm{Bi}(z), e^{-|\Re\zeta|}\mathrm{Bi}'(z))$ with $\zeta = \frac{2}{3}z^{3/2}$. The real axis is delegated to [r_airye] and [r_airy], and the power series is used for $|z| \leq 1$. Otherwise, see [airy_scaled].
+pub(crate) fn c_airye<T>(z: Complex<T>) -> (Complex<T>, Complex<T>, Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if !z.re.is_finite() || !z.im.is_finite() {
+        let nan = Complex::new(T::nan(), T::nan());
+        return (nan, nan, nan, nan);
+    }
+    let zeta = airy_zeta(z);
+    if z.im.is_zero() {
+        if z.re >= T::zero() {
+            let (ai, aip, bi, bip) = r_airye(z.re);
+            return (
+                Complex::new(ai, z.im),
+                Complex::new(aip, z.im),
+                Complex::new(bi, z.im),
+                Complex::new(bip, z.im),
+            );
+        }
+        // zeta is imaginary on the negative real axis
+        let (ai, aip, bi, bip) = r_airy(z.re);
+        let phase = Complex::new(T::zero(), zeta.im).exp();
+        return (
+            phase * ai,
+            phase * aip,
+            Complex::new(bi, z.im),
+            Complex::new(bip, z.im),
+        );
+    }
+    if z.norm_sqr() > T::one() {
+        return airy_scaled(z);
+    }
+
+    let (ai, aip, bi, bip) = airy_series(z);
+    let scale_ai = zeta.exp();
+    let scale_bi = (-zeta.re.abs()).exp();
+    (ai * scale_ai, aip * scale_ai, bi * scale_bi, bip * scale_bi)
+}
+
+/// Airy functions for complex arguments implementation.
+///
+/// The real axis is delegated to [r_airy], and the power series is used for $|z| \leq 1$. Otherwise, the Airy functions are evaluated from their scaled counterparts, see [c_airye].
+pub(crate) fn c_airy<T>(z: Complex<T>) -> (Complex<T>, Complex<T>, Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if !z.re.is_finite() || !z.im.is_finite() {
+        let nan = Complex::new(T::nan(), T::nan());
+        return (nan, nan, nan, nan);
+    }
+    if z.im.is_zero() {
+        let (ai, aip, bi, bip) = r_airy(z.re);
+        return (
+            Complex::new(ai, z.im),
+            Complex::new(aip, z.im),
+            Complex::new(bi, z.im),
+            Complex::new(bip, z.im),
+        );
+    }
+    if z.norm_sqr() <= T::one() {
+        return airy_series(z);
+    }
+
+    let (ai, aip, bi, bip) = airy_scaled(z);
+    let zeta = airy_zeta(z);
+    let scale_ai = (-zeta).exp();
+    let scale_bi = zeta.re.abs().exp();
+    (ai * scale_ai, aip * scale_ai, bi * scale_bi, bip * scale_bi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    const fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [(Complex64, [Complex64; 4]); 10] = [
+        // z, [Ai(z), Ai'(z), Bi(z), Bi'(z)]
+        (
+            c(0.5, 0.5),
+            [
+                c(0.21618634477812598, -0.11483063987764813),
+                c(-0.23871680908176862, 0.06615704122109356),
+                c(0.8041665904962326, 0.2492852888831791),
+                c(0.4083984976406324, 0.18775170288237833),
+            ],
+        ),
+        (
+            c(1.0, 1.0),
+            [
+                c(0.060458308371838146, -0.15188956587718141),
+                c(-0.1306279534996475, 0.16306759644932392),
+                c(0.7166580733827684, 0.6198892904008447),
+                c(0.07566284417496599, 0.7837009987854553),
+            ],
+        ),
+        (
+            c(-1.0, 1.0),
+            [
+                c(0.8221174265552726, -0.11996634266442434),
+                c(-0.3790604792268335, -0.6045001308622461),
+                c(0.21429040153487358, 0.6739169237227052),
+                c(0.8344734885227827, -0.34652606326682855),
+            ],
+        ),
+        (
+            c(0.0, 2.0),
+            [
+                c(-0.10961462643277392, -0.911583600113861),
+                c(-0.6778858159258347, 1.0346546678889406),
+                c(0.9853719221586964, -0.01242055530982517),
+                c(-1.0677739995872133, -0.4978725217882102),
+            ],
+        ),
+        (
+            c(-2.0, -0.5),
+            [
+                c(0.29003094106266103, -0.3303078762239586),
+                c(0.7458883289066516, 0.2743194885816866),
+                c(-0.5181812413053978, -0.16077529192850537),
+                c(0.41183709959167575, -0.4345556675967243),
+            ],
+        ),
+        (
+            c(3.0, -4.0),
+            [
+                c(0.014554546690944635, 0.047435251515492834),
+                c(-0.07520996119590304, -0.0823640771555378),
+                c(1.0363977946545908, -1.0513762825317121),
+                c(0.7878892378963575, -2.999866887258376),
+            ],
+        ),
+        (
+            c(-5.0, 5.0),
+            [
+                c(-9034.906596717632, -16279.459048837543),
+                c(-30622.676872538847, 38106.25410599294),
+                c(16279.459050838817, -9034.90659420064),
+                c(-38106.25411015377, -30622.676864951387),
+            ],
+        ),
+        (
+            c(20.0, 1.0),
+            [
+                c(-4.0315035511181836e-28, 1.7423356972781363e-27),
+                c(2.002169134656758e-27, -7.771159557787688e-27),
+                c(-4.965871955624375e+24, -1.9257777246305656e+25),
+                c(-1.998800060234419e+25, -8.646665600248428e+25),
+            ],
+        ),
+        (
+            c(-20.0, 1.0),
+            [
+                c(-7.336948029720429, 9.088186907177752),
+                c(41.3905107317888, 31.90721020953761),
+                c(-9.090603318797939, -7.335095469397503),
+                c(-31.91579919147685, 41.37992925604426),
+            ],
+        ),
+        (
+            c(0.0, -15.0),
+            [
+                c(-98766941460.11487, 53546754462.93246),
+                c(124752802292.9506, -415476654953.02783),
+                c(53546754462.93246, 98766941460.11487),
+                c(-415476654953.02783, -124752802292.9506),
+            ],
+        ),
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_SCALED: [(Complex64, [Complex64; 4]); 4] = [
+        // z, [eAi(z), eAi'(z), eBi(z), eBi'(z)]
+        (
+            c(1.0, 1.0),
+            [
+                c(0.24802117436254437, -0.03904138911379079),
+                c(-0.3177297469379948, -0.044918884901963836),
+                c(0.46662969858644715, 0.40362170396177083),
+                c(0.04926551654529456, 0.5102826221143183),
+            ],
+        ),
+        (
+            c(-5.0, 5.0),
+            [
+                c(0.1443763230796517, -0.09694929963552666),
+                c(-0.3788493707386126, -0.2549030350949497),
+                c(0.1520587964519432, -0.08439082763619754),
+                c(-0.35593265840017785, -0.28603207107065054),
+            ],
+        ),
+        (
+            c(30.0, 10.0),
+            [
+                c(0.11851498391958187, -0.009521490675730276),
+                c(-0.6672927077755004, -0.05353778758996375),
+                c(-0.009001998917358301, -0.23787031315539744),
+                c(0.16496554614678746, -1.3267134979394946),
+            ],
+        ),
+        (
+            c(-30.0, -10.0),
+            [
+                c(0.0905874539536329, 0.07715352893908911),
+                c(-0.508989815911296, 0.43369073255077595),
+                c(0.07037497780015473, 0.09594850880125314),
+                c(-0.5952155675519906, 0.3047568085303517),
+            ],
+        ),
+    ];
+
+    #[test]
+    fn test_c_airy() {
+        for (z, [ai, aip, bi, bip]) in KNOWN_VALUES {
+            let (result_ai, result_aip, result_bi, result_bip) = c_airy(z);
+            assert_almost_eq!(result_ai, ai, PRECISION * ai.norm());
+            assert_almost_eq!(result_aip, aip, PRECISION * aip.norm());
+            assert_almost_eq!(result_bi, bi, PRECISION * bi.norm());
+            assert_almost_eq!(result_bip, bip, PRECISION * bip.norm());
+
+            // Ai and Bi are real on the real axis
+            let (conj_ai, _, conj_bi, _) = c_airy(z.conj());
+            assert_almost_eq!(conj_ai, ai.conj(), PRECISION * ai.norm());
+            assert_almost_eq!(conj_bi, bi.conj(), PRECISION * bi.norm());
+        }
+
+        // Agrees with the real-valued implementation on the real axis
+        let (ai, _, bi, _) = c_airy(c(-3.0, 0.0));
+        let (real_ai, _, real_bi, _) = r_airy(-3.0);
+        assert_eq!((ai, bi), (c(real_ai, 0.0), c(real_bi, 0.0)));
+
+        assert!(c_airy(c(f64::NAN, 1.0)).0.is_nan());
+        assert!(c_airy(c(1.0, f64::INFINITY)).3.is_nan());
+    }
+
+    #[test]
+    fn test_c_airye() {
+        for (z, [ai, aip, bi, bip]) in KNOWN_SCALED {
+            let (result_ai, result_aip, result_bi, result_bip) = c_airye(z);
+            assert_almost_eq!(result_ai, ai, PRECISION * ai.norm());
+            assert_almost_eq!(result_aip, aip, PRECISION * aip.norm());
+            assert_almost_eq!(result_bi, bi, PRECISION * bi.norm());
+            assert_almost_eq!(result_bip, bip, PRECISION * bip.norm());
+        }
+
+        // The scaling factor of Ai has unit modulus on the negative real axis
+        let (ai, _, bi, _) = c_airye(c(-3.0, 0.0));
+        let (real_ai, _, real_bi, _) = r_airy(-3.0);
+        assert_almost_eq!(ai.norm(), real_ai.abs(), PRECISION);
+        assert_eq!(bi, c(real_bi, 0.0));
+        assert_eq!(c_airye(c(2.0, 0.0)).0, c(r_airye(2.0).0, 0.0));
+
+        assert!(c_airye(c(f64::NAN, 1.0)).0.is_nan());
+    }
+}
@@ -0,0 +1,241 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::bessel_util::{temme_gamma, TemmeConsts, MAX_TERM};
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// Maximum number of terms for the series and continued fractions.
+const MAX_ITER: usize = 1000000;
+
+/// Asymptotic expansion of the scaled modified Bessel functions for large $|z|$, see [DLMF 10.40](https://dlmf.nist.gov/10.40#i).
+/// $$
+/// I_\nu(z) \sim \frac{e^z}{\sqrt{2\pi z}}\sum_{k=0}^{\infty}(-1)^k\frac{a_k(\nu)}{z^k} \pm ie^{\pm i\nu\pi}\frac{e^{-z}}{\sqrt{2\pi z}}\sum_{k=0}^{\infty}\frac{a_k(\nu)}{z^k}, \quad K_\nu(z) \sim \sqrt{\frac{\pi}{2z}}e^{-z}\sum_{k=0}^{\infty}\frac{a_k(\nu)}{z^k}
+/// $$
+/// where the upper sign is taken for $\Im z \geq 0$. The second term of $I_\nu(z)$ is only kept when it is not negligible. Returns `None` if the terms start to increase again before the series has converged, or if its terms grow large enough to cause cancellation.
+fn ik_asymptotic<T>(nu: T, z: Complex<T>) -> Option<(Complex<T>, Complex<T>)>
+where
+    T: Float + FloatConst,
+{
+    let one = T::one();
+    let mu = cast::<f64, T>(4.0).unwrap() * nu * nu;
+    let eight_z = z * cast::<f64, T>(8.0).unwrap();
+    let max_term = cast::<f64, T>(MAX_TERM).unwrap();
+
+    let mut plus = Complex::new(one, T::zero());
+    let mut minus = plus;
+    let mut term = plus;
+    let mut converged = false;
+    let mut decreasing = false;
+    for k in 1..MAX_ITER {
+        let previous = term.norm();
+        let odd = cast::<usize, T>(2 * k - 1).unwrap();
+        term = term * (mu - odd * odd) / (eight_z * cast::<usize, T>(k).unwrap());
+        if (decreasing && term.norm() > previous) || term.norm() > max_term {
+            break;
+        }
+        decreasing = term.norm() < previous;
+        plus = plus + term;
+        minus = if k % 2 == 0 {
+            minus + term
+        } else {
+            minus - term
+        };
+        if term.norm() <= T::epsilon() * plus.norm().min(minus.norm()) {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return None;
+    }
+
+    let sqrt_z = z.sqrt();
+    let kve = plus * T::FRAC_PI_2().sqrt() / sqrt_z;
+    let mut ive = minus * Complex::new(T::zero(), z.im).exp() / (sqrt_z * T::TAU().sqrt());
+    if z.re + z.re < -T::epsilon().ln() {
+        // i e^{i nu pi} e^{-z} for Im z >= 0, and its conjugate otherwise
+        let sign = if z.im < T::zero() { -one } else { one };
+        let phase = Complex::new(-z.re - z.re, sign * nu * T::PI() - z.im);
+        let second =
+            Complex::new(T::zero(), sign) * phase.exp() * plus / (sqrt_z * T::TAU().sqrt());
+        ive = ive + second;
+    }
+    Some((ive, kve))
+}
+
+/// Computes $e^{-\Re z}I_\nu(z)$ and $e^zK_\nu(z)$ for $\nu \geq 0$ and $\Re z \geq 0$, $z \neq 0$ with the complex extension of the algorithm `bessik` of [Numerical Recipes] (section 6.7).
+///
+/// The ratio $I_\nu'/I_\nu$ is found from its continued fraction and the recurrence is run downwards to $\mu = \nu - n$ with $|\mu| \leq 1/2$. There, $K_\mu$ and $K_{\mu+1}$ are computed with Temme's series for $|z| \leq 2$ and Steed's method for the second continued fraction otherwise. The Wronskian
+/// $$
+/// I_\mu(z)K_\mu'(z) - I_\mu'(z)K_\mu(z) = -\frac{1}{z}
+/// $$
+/// then fixes $I_\mu$, and $K_\nu$ follows from the forward recurrence.
+///
+/// [Numerical Recipes]: https://numerical.recipes/
+fn ik_temme_steed<T>(nu: T, z: Complex<T>) -> (Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + TemmeConsts,
+{
+    let one = T::one();
+    let two = one + one;
+    let half = cast::<f64, T>(0.5).unwrap();
+    let eps = T::epsilon();
+    // The complex arithmetic squares the moduli, so keep well clear of under- and overflow
+    let tiny = T::min_positive_value().sqrt() / eps;
+    let big = T::max_value().sqrt().sqrt();
+    let c_one = Complex::new(one, T::zero());
+    let c_tiny = Complex::new(tiny, T::zero());
+
+    let nl = (nu + half).floor().to_usize().unwrap();
+    let mu = nu - cast::<usize, T>(nl).unwrap();
+    let mu2 = mu * mu;
+    let zi = z.inv();
+    let zi2 = zi * two;
+
+    // First continued fraction, I'_nu / I_nu
+    let mut h = zi * nu;
+    if h.norm() < tiny {
+        h = c_tiny;
+    }
+    let mut b = zi2 * nu;
+    let mut d = Complex::new(T::zero(), T::zero());
+    let mut c = h;
+    for _ in 0..MAX_ITER {
+        b = b + zi2;
+        d = b + d;
+        if d.norm() < tiny {
+            d = c_tiny;
+        }
+        d = d.inv();
+        c = b + c.inv();
+        if c.norm() < tiny {
+            c = c_tiny;
+        }
+        let delta = c * d;
+        h = delta * h;
+        if (delta - one).norm() < eps {
+            break;
+        }
+    }
+
+    // Downward recurrence from nu to mu
+    let mut ril = c_one;
+    let mut ripl = h;
+    let mut ril1 = ril;
+    let mut fact = zi * nu;
+    for _ in 0..nl {
+        let ritemp = fact * ril + ripl;
+        fact = fact - zi;
+        ripl = fact * ritemp + ril;
+        ril = ritemp;
+        if ril.norm() > big {
+            ril = ril / big;
+            ripl = ripl / big;
+            ril1 = ril1 / big;
+        }
+    }
+    if ril.norm() < tiny {
+        ril = Complex::new(eps, T::zero());
+    }
+    let f = ripl / ril;
+
+    let (mut rkmu, mut rk1) = if z.norm() <= two {
+        // Temme's series
+        let z2 = z * half;
+        let pimu = T::PI() * mu;
+        let fact = if pimu.abs() < eps {
+            one
+        } else {
+            pimu / pimu.sin()
+        };
+        let d = -z2.ln();
+        let e = d * mu;
+        let fact2 = if e.norm() < eps { c_one } else { e.sinh() / e };
+        let (gam1, gam2, gampl, gammi) = temme_gamma(mu);
+        let mut ff = (e.cosh() * gam1 + fact2 * d * gam2) * fact;
+        let mut sum = ff;
+        let e = e.exp();
+        let mut p = e * half / gampl;
+        let mut q = (e * gammi).inv() * half;
+        let mut c = c_one;
+        let d = z2 * z2;
+        let mut sum1 = p;
+        for i in 1..MAX_ITER {
+            let i = cast::<usize, T>(i).unwrap();
+            ff = (ff * i + p + q) / (i * i - mu2);
+            c = c * d / i;
+            p = p / (i - mu);
+            q = q / (i + mu);
+            let delta = c * ff;
+            sum = sum + delta;
+            sum1 = sum1 + c * (p - ff * i);
+            if delta.norm() < sum.norm() * eps {
+                break;
+            }
+        }
+        let scale = z.exp();
+        (sum * scale, sum1 * zi2 * scale)
+    } else {
+        // Steed's method for the second continued fraction
+        let mut b = (z + one) * two;
+        let mut d = b.inv();
+        let mut h = d;
+        let mut delh = d;
+        let mut q1 = Complex::new(T::zero(), T::zero());
+        let mut q2 = c_one;
+        let a1 = cast::<f64, T>(0.25).unwrap() - mu2;
+        let mut q = Complex::new(a1, T::zero());
+        let mut c = a1;
+        let mut a = -a1;
+        let mut s = q * delh + one;
+        for i in 1..MAX_ITER {
+            a = a - cast::<usize, T>(2 * i).unwrap();
+            c = -a * c / cast::<usize, T>(i + 1).unwrap();
+            let qnew = (q1 - b * q2) / a;
+            q1 = q2;
+            q2 = qnew;
+            q = q + qnew * c;
+            b = b + two;
+            d = (b + d * a).inv();
+            delh = (b * d - one) * delh;
+            h = h + delh;
+            let dels = q * delh;
+            s = s + dels;
+            if dels.norm() < s.norm() * eps {
+                break;
+            }
+        }
+        let rkmu = (z * two).inv().scale(T::PI()).sqrt() / s;
+        let rk1 = rkmu * (z + mu + half - h * a1) * zi;
+        (rkmu, rk1)
+    };
+
+    let rkmup = rkmu * mu * zi - rk1;
+    let rimu = Complex::new(T::zero(), z.im).exp() / (z * (f * rkmu - rkmup));
+    let ri = rimu * ril1 / ril;
+    for i in 1..=nl {
+        let rktemp = rk1 * (mu + cast::<usize, T>(i).unwrap()) * zi2 + rkmu;
+        rkmu = rk1;
+        rk1 = rktemp;
+    }
+    (ri, rkmu)
+}
+
+/// Computes the scaled modified Bessel functions $e^{-\Re z}I_\nu(z)$ and $e^zK_\nu(z)$ for $\nu \geq 0$ and $\Re z \geq 0$, $z \neq 0$.
+///
+/// The asymptotic expansion is attempted for $|z| \geq \max(25, \nu)$, and the Temme-Steed algorithm is used otherwise or if it fails. These are the building blocks for the Bessel functions of complex arguments, which are all related to $I_\nu$ and $K_\nu$ in the right half-plane.
+pub(crate) fn c_ik_scaled<T>(nu: T, z: Complex<T>) -> (Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + TemmeConsts,
+{
+    let norm = z.norm();
+    if norm >= cast::<f64, T>(25.0).unwrap() && norm >= nu {
+        if let Some(result) = ik_asymptotic(nu, z) {
+            return result;
+        }
+    }
+    ik_temme_steed(nu, z)
+}
@@ -0,0 +1,271 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::bessel_util::{cos_pi, sin_pi, TemmeConsts};
+use crate::special::bessel::c_ik::c_ik_scaled;
+use crate::special::bessel::r_ik0::BesselIK0Consts;
+use crate::special::bessel::r_ik1::BesselIK1Consts;
+use crate::special::bessel::r_iv::{r_ive, r_kve};
+use crate::special::Gamma;
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// Maximum number of terms in the power series.
+const MAX_ITER: usize = 1000;
+
+/// Power series of $e^{-|\Re z|}I_\nu(z)$ for $\nu \geq 0$, see [DLMF 10.25.2](https://dlmf.nist.gov/10.25#E2),
+/// $$
+/// I_\nu(z) = \frac{(z/2)^\nu}{\Gamma(\nu+1)}\sum_{k=0}^{\infty}\frac{(z^2/4)^k}{k!(\nu+1)_k}
+/// $$
+/// which is only used for $|z|^2 \leq \nu + 1$, where the terms decrease from the start.
+pub(crate) fn iv_series<T>(nu: T, z: Complex<T>) -> Complex<T>
+where
+    T: Float + Gamma,
+{
+    let quarter = cast::<f64, T>(0.25).unwrap();
+    let w = z * z * quarter;
+    let mut term = Complex::new(T::one(), T::zero());
+    let mut sum = term;
+    for k in 1..MAX_ITER {
+        let k = cast::<usize, T>(k).unwrap();
+        term = term * w / (k * (nu + k));
+        sum = sum + term;
+        if term.norm() <= T::epsilon() * sum.norm() {
+            break;
+        }
+    }
+    let half_z = z * quarter * (T::one() + T::one());
+    let gamma = (nu + T::one()).gamma();
+    let prefactor = if gamma.is_finite() {
+        half_z.powf(nu) / gamma * (-z.re.abs()).exp()
+    } else {
+        (half_z.ln() * nu - z.re.abs() - (nu + T::one()).lgamma()).exp()
+    };
+    prefactor * sum
+}
+
+/// Computes $e^{-|\Re z|}I_\nu(z)$ and $e^zK_\nu(z)$ for $\nu \geq 0$ and $z \neq 0$.
+///
+/// The right half-plane is evaluated directly. Otherwise, with $z = z'e^{\pm i\pi}$ and $\Re z' > 0$, we use the analytic continuation formulas, [DLMF 10.34.1](https://dlmf.nist.gov/10.34#E1) and [DLMF 10.34.2](https://dlmf.nist.gov/10.34#E2),
+/// $$
+/// I_\nu(z) = e^{\pm i\nu\pi}I_\nu(z'), \quad K_\nu(z) = e^{\mp i\nu\pi}K_\nu(z') \mp i\pi I_\nu(z')
+/// $$
+/// where the upper sign is taken for $\Im z \geq 0$.
+fn ik_scaled<T>(nu: T, z: Complex<T>) -> (Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + TemmeConsts,
+{
+    if z.re >= T::zero() {
+        return c_ik_scaled(nu, z);
+    }
+    let w = -z;
+    let (ive, kve) = c_ik_scaled(nu, w);
+    let sign = if z.im.is_sign_negative() {
+        -T::one()
+    } else {
+        T::one()
+    };
+    let rotation = Complex::new(cos_pi(nu), sign * sin_pi(nu));
+    let kve = rotation.conj() * (-w - w).exp() * kve
+        - Complex::new(T::zero(), sign * T::PI()) * Complex::new(T::zero(), -w.im).exp() * ive;
+    (rotation * ive, kve)
+}
+
+/// Exponentially scaled modified Bessel function of the first kind of real order for complex arguments implementation.
+///
+/// Evaluates $e^{-|\Re z|}I_\nu(z)$. The real axis with $z > 0$ is delegated to [r_ive], and the power series is used for small $|z|$. Negative orders use the reflection formula, [DLMF 10.27.2](https://dlmf.nist.gov/10.27#E2),
+/// $$
+/// I_{-\nu}(z) = I_\nu(z) + \frac{2}{\pi}\sin(\nu\pi)K_\nu(z)
+/// $$
+pub(crate) fn c_ive<T>(nu: T, z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if z.im.is_zero() && z.re >= T::zero() {
+        return Complex::new(r_ive(nu, z.re), z.im);
+    }
+    if nu.is_nan() || !z.re.is_finite() || !z.im.is_finite() {
+        return Complex::new(T::nan(), T::nan());
+    }
+
+    let order = nu.abs();
+    let series = z.norm_sqr() <= order + T::one();
+    let reflect = nu < T::zero() && nu != nu.floor();
+    if series && !reflect {
+        return iv_series(order, z);
+    }
+
+    let (ive, kve) = ik_scaled(order, z);
+    let ive = if series { iv_series(order, z) } else { ive };
+    if reflect {
+        // e^{-|Re z|} K = e^{-|Re z| - z} (e^z K)
+        let scale = Complex::new(-z.re.abs() - z.re, -z.im).exp();
+        return ive + scale * kve * (T::FRAC_2_PI() * sin_pi(-nu));
+    }
+    ive
+}
+
+/// Modified Bessel function of the first kind of real order for complex arguments implementation.
+///
+/// Evaluated as $I_\nu(z) = e^{|\Re z|}\left(e^{-|\Re z|}I_\nu(z)\right)$, see [c_ive].
+pub(crate) fn c_iv<T>(nu: T, z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    let ive = c_ive(nu, z);
+    if (ive.re.is_zero() && ive.im.is_zero()) || ive.re.is_infinite() || ive.im.is_infinite() {
+        return ive;
+    }
+    ive * z.re.abs().exp()
+}
+
+/// Exponentially scaled modified Bessel function of the second kind of real order for complex arguments implementation.
+///
+/// Evaluates $e^zK_\nu(z)$ with $K_{-\nu}(z) = K_\nu(z)$. The real axis with $z > 0$ is delegated to [r_kve].
+pub(crate) fn c_kve<T>(nu: T, z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if z.im.is_zero() && z.re >= T::zero() {
+        return Complex::new(r_kve(nu, z.re), z.im);
+    }
+    if nu.is_nan() || !z.re.is_finite() || !z.im.is_finite() {
+        return Complex::new(T::nan(), T::nan());
+    }
+    ik_scaled(nu.abs(), z).1
+}
+
+/// Modified Bessel function of the second kind of real order for complex arguments implementation.
+///
+/// Evaluated as $K_\nu(z) = e^{-z}\left(e^zK_\nu(z)\right)$, see [c_kve].
+pub(crate) fn c_kv<T>(nu: T, z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    let kve = c_kve(nu, z);
+    if kve.re.is_infinite() || kve.im.is_infinite() {
+        return kve;
+    }
+    kve * (-z).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    const fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [(f64, Complex64, Complex64, Complex64); 12] = [
+        // nu, z, Iv(z), Kv(z)
+        (
+            0.0,
+            c(1.0, 1.0),
+            c(0.9376084768060293, 0.4965299476091221),
+            c(0.08019772694651782, -0.3572774592853302),
+        ),
+        (
+            0.5,
+            c(1.0, 1.0),
+            c(0.7269806459635546, 0.6418384753379859),
+            c(0.06868578341999641, -0.38157825981268306),
+        ),
+        (
+            1.0,
+            c(-2.0, 3.0),
+            c(1.2609820602388484, 0.7801488485792538),
+            c(-2.364409914921838, 4.000553410760102),
+        ),
+        (
+            2.5,
+            c(0.1, 0.05),
+            c(8.886434341919206e-05, 0.0002039217306434769),
+            c(358.14786001841776, -823.9942821026419),
+        ),
+        (
+            0.3,
+            c(0.0, 3.0),
+            c(-0.059924637085766765, -0.03053312764691583),
+            c(-0.5873258816461443, 0.4178243992175944),
+        ),
+        (
+            0.3,
+            c(-4.0, 0.0),
+            c(6.5531691137763195, 9.019663489991828),
+            c(0.006626202343889882, -35.03447658062191),
+        ),
+        (
+            -0.5,
+            c(1.5, 0.5),
+            c(1.3967973972899115, 0.4295567160010221),
+            c(0.17557422018541116, -0.13651042350627185),
+        ),
+        (
+            -2.5,
+            c(-0.5, 0.3),
+            c(8.823868673105967, -1.5360039629453133),
+            c(13.838987817959957, -2.4170738024051355),
+        ),
+        (
+            7.7,
+            c(5.0, -20.0),
+            c(-3.100888109651074, -8.889891125575195),
+            c(-0.0027418720110778958, -0.00014733351167807608),
+        ),
+        (
+            1.5,
+            c(-30.0, 1.0),
+            c(-626608215072.1343, -416207402306.2897),
+            c(-1968547765149.6304, -1307554117455.131),
+        ),
+        (
+            0.0,
+            c(40.0, 40.0),
+            c(-4120796510498191.0, 1.1806423176186602e+16),
+            c(-6.366013124737062e-19, -3.071591801938371e-19),
+        ),
+        (
+            -3.0,
+            c(4.0, -4.0),
+            c(-2.4694861463598405, 4.617662808678474),
+            c(0.0025891613753841933, -0.016461076562092093),
+        ),
+    ];
+
+    #[test]
+    fn test_c_iv() {
+        for (nu, z, iv, _) in KNOWN_VALUES {
+            assert_almost_eq!(c_iv(nu, z), iv, PRECISION * iv.norm());
+        }
+
+        // Agrees with the real-valued implementation on the positive real axis
+        assert_eq!(
+            c_iv(2.5, c(3.0, 0.0)),
+            c(r_ive(2.5, 3.0) * 3.0_f64.exp(), 0.0)
+        );
+        // The sign of a zero imaginary part selects the side of the branch cut
+        assert_eq!(c_iv(0.3, c(-4.0, -0.0)), c_iv(0.3, c(-4.0, 0.0)).conj());
+
+        assert!(c_iv(f64::NAN, c(1.0, 1.0)).is_nan());
+        assert!(c_iv(1.0, c(f64::NAN, 1.0)).is_nan());
+    }
+
+    #[test]
+    fn test_c_kv() {
+        for (nu, z, _, kv) in KNOWN_VALUES {
+            assert_almost_eq!(c_kv(nu, z), kv, PRECISION * kv.norm());
+        }
+
+        assert_eq!(c_kve(2.5, c(3.0, 0.0)), c(r_kve(2.5, 3.0), 0.0));
+        assert_eq!(c_kv(0.3, c(-4.0, -0.0)), c_kv(0.3, c(-4.0, 0.0)).conj());
+
+        assert!(c_kv(f64::NAN, c(1.0, 1.0)).is_nan());
+    }
+}
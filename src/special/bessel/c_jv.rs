@@ -0,0 +1,273 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::bessel_util::{cos_pi, sin_pi, TemmeConsts};
+use crate::special::bessel::c_ik::c_ik_scaled;
+use crate::special::bessel::r_j0::BesselJY0Consts;
+use crate::special::bessel::r_j1::BesselJY1Consts;
+use crate::special::bessel::r_jv::{r_jv, r_yv};
+use crate::special::Gamma;
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// Maximum number of terms in the power series.
+const MAX_ITER: usize = 1000;
+
+/// Power series of $J_\nu(z)$ for $\nu \geq 0$, see [DLMF 10.2.2](https://dlmf.nist.gov/10.2#E2),
+/// $$
+/// J_\nu(z) = \frac{(z/2)^\nu}{\Gamma(\nu+1)}\sum_{k=0}^{\infty}\frac{(-z^2/4)^k}{k!(\nu+1)_k}
+/// $$
+/// which is only used for $|z|^2 \leq \nu + 1$, where the terms decrease from the start.
+fn jv_series<T>(nu: T, z: Complex<T>) -> Complex<T>
+where
+    T: Float + Gamma,
+{
+    let quarter = cast::<f64, T>(0.25).unwrap();
+    let w = -z * z * quarter;
+    let mut term = Complex::new(T::one(), T::zero());
+    let mut sum = term;
+    for k in 1..MAX_ITER {
+        let k = cast::<usize, T>(k).unwrap();
+        term = term * w / (k * (nu + k));
+        sum = sum + term;
+        if term.norm() <= T::epsilon() * sum.norm() {
+            break;
+        }
+    }
+    let half_z = z * quarter * (T::one() + T::one());
+    let gamma = (nu + T::one()).gamma();
+    let prefactor = if gamma.is_finite() {
+        half_z.powf(nu) / gamma
+    } else {
+        (half_z.ln() * nu - (nu + T::one()).lgamma()).exp()
+    };
+    prefactor * sum
+}
+
+/// Computes $J_\nu(z)$ and $Y_\nu(z)$ for $\nu \geq 0$ and $\Im z \geq 0$, $z \neq 0$.
+///
+/// With $w = -iz$ in the right half-plane, we use [DLMF 10.27.6](https://dlmf.nist.gov/10.27#E6) and [DLMF 10.27.8](https://dlmf.nist.gov/10.27#E8),
+/// $$
+/// J_\nu(z) = e^{i\nu\pi/2}I_\nu(w), \quad H^{(1)}_\nu(z) = -\frac{2i}{\pi}e^{-i\nu\pi/2}K_\nu(w)
+/// $$
+/// and $Y_\nu(z) = i(J_\nu(z) - H^{(1)}_\nu(z))$.
+fn jy_upper<T>(nu: T, z: Complex<T>) -> (Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + TemmeConsts,
+{
+    let w = Complex::new(z.im, -z.re);
+    let (ive, kve) = c_ik_scaled(nu, w);
+
+    let half_nu = nu / (T::one() + T::one());
+    let rotation = Complex::new(cos_pi(half_nu), sin_pi(half_nu));
+    let j = rotation * ive * z.im.exp();
+    let h1 = Complex::new(T::zero(), -T::FRAC_2_PI()) * rotation.conj() * kve * (-w).exp();
+    let y = Complex::new(-(j - h1).im, (j - h1).re);
+    (j, y)
+}
+
+/// Computes $J_\nu(z)$ and $Y_\nu(z)$ for $\nu \geq 0$ and $z \neq 0$, using $J_\nu(\bar{z}) = \overline{J_\nu(z)}$ and $Y_\nu(\bar{z}) = \overline{Y_\nu(z)}$ in the lower half-plane. The sign of a zero imaginary part selects the side of the branch cut.
+fn jy_positive<T>(nu: T, z: Complex<T>) -> (Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + TemmeConsts,
+{
+    if z.im.is_sign_negative() {
+        let (j, y) = jy_upper(nu, z.conj());
+        return (j.conj(), y.conj());
+    }
+    jy_upper(nu, z)
+}
+
+/// Bessel function of the first kind of real order for complex arguments implementation.
+///
+/// The positive real axis is delegated to [r_jv]. Otherwise, the power series is used for small $|z|$ and $J_\nu$ is related to the modified Bessel function $I_\nu$ in the right half-plane, which is evaluated with the Temme-Steed algorithm or its asymptotic expansion. Negative orders use the reflection formula, [DLMF 10.4.7](https://dlmf.nist.gov/10.4#E7),
+/// $$
+/// J_{-\nu}(z) = \cos(\nu\pi)J_\nu(z) - \sin(\nu\pi)Y_\nu(z)
+/// $$
+pub(crate) fn c_jv<T>(nu: T, z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselJY0Consts + BesselJY1Consts,
+{
+    if nu.is_nan() || z.re.is_nan() || z.im.is_nan() {
+        return Complex::new(T::nan(), T::nan());
+    }
+    if z.im.is_zero() && z.re >= T::zero() {
+        return Complex::new(r_jv(nu, z.re), z.im);
+    }
+    if z.im.is_infinite() {
+        return Complex::new(T::infinity(), T::infinity());
+    }
+    if z.re.is_infinite() {
+        return Complex::new(T::zero(), T::zero());
+    }
+
+    if nu < T::zero() {
+        let (j, y) = jy_positive(-nu, z);
+        return j * cos_pi(nu) + y * sin_pi(nu);
+    }
+    if z.norm_sqr() <= nu + T::one() {
+        return jv_series(nu, z);
+    }
+    jy_positive(nu, z).0
+}
+
+/// Bessel function of the second kind of real order for complex arguments implementation.
+///
+/// The positive real axis is delegated to [r_yv]. Otherwise, $Y_\nu$ is evaluated from the modified Bessel functions $I_\nu$ and $K_\nu$ in the right half-plane. Negative orders use the reflection formula, [DLMF 10.4.8](https://dlmf.nist.gov/10.4#E8),
+/// $$
+/// Y_{-\nu}(z) = \sin(\nu\pi)J_\nu(z) + \cos(\nu\pi)Y_\nu(z)
+/// $$
+pub(crate) fn c_yv<T>(nu: T, z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + TemmeConsts + BesselJY0Consts + BesselJY1Consts,
+{
+    if nu.is_nan() || z.re.is_nan() || z.im.is_nan() {
+        return Complex::new(T::nan(), T::nan());
+    }
+    if z.im.is_zero() && z.re >= T::zero() {
+        return Complex::new(r_yv(nu, z.re), z.im);
+    }
+    if z.im.is_infinite() {
+        return Complex::new(T::infinity(), T::infinity());
+    }
+    if z.re.is_infinite() {
+        return Complex::new(T::zero(), T::zero());
+    }
+
+    let (j, y) = jy_positive(nu.abs(), z);
+    if nu < T::zero() {
+        return j * sin_pi(-nu) + y * cos_pi(nu);
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    const fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [(f64, Complex64, Complex64, Complex64); 14] = [
+        // nu, z, Jv(z), Yv(z)
+        (
+            0.0,
+            c(1.0, 1.0),
+            c(0.9376084768060293, -0.4965299476091221),
+            c(0.44547448893603253, 0.7101585820037345),
+        ),
+        (
+            0.5,
+            c(1.0, 1.0),
+            c(0.9679012828901307, 0.060204606214281704),
+            c(-0.2628946385649067, 0.8270501820405617),
+        ),
+        (
+            1.0,
+            c(-1.0, 2.0),
+            c(-1.2918475192394565, 1.0104883650794736),
+            c(-0.9315068749546663, -1.2687435739466395),
+        ),
+        (
+            2.0,
+            c(3.0, -2.0),
+            c(1.2213090988782014, -0.12594627238464973),
+            c(-0.1842323456510938, -1.1704485441955228),
+        ),
+        (
+            0.3,
+            c(0.0, 5.0),
+            c(24.023401463257816, 12.240534428951557),
+            c(-12.242645480267589, 24.024477097628523),
+        ),
+        (
+            0.3,
+            c(-5.0, 0.0),
+            c(-0.17447177338302058, -0.24013979451693324),
+            c(-0.11582712740725064, -0.18952118270259025),
+        ),
+        (
+            2.5,
+            c(10.0, 3.0),
+            c(1.9530367817227556, 1.2353156983588962),
+            c(-1.2456727966643413, 1.9443665345523957),
+        ),
+        (
+            0.0,
+            c(30.0, 1.0),
+            c(-0.1309349050389251, 0.1400335416651824),
+            c(-0.18263149122685388, -0.09846133114794556),
+        ),
+        (
+            1.5,
+            c(-30.0, 20.0),
+            c(28271219.94595657, 14442866.670831537),
+            c(-14442866.670831537, 28271219.94595657),
+        ),
+        (
+            7.7,
+            c(0.5, 0.2),
+            c(-1.863749375433404e-09, 4.1177194352308674e-10),
+            c(21178256.56322856, 4717338.579108451),
+        ),
+        (
+            20.0,
+            c(2.0, 1.99),
+            c(-3.96066891698993e-16, 5.77903104438377e-17),
+            c(39284970000472.91, 6132878344831.734),
+        ),
+        (
+            -0.5,
+            c(1.5, 0.5),
+            c(-0.002871892969545898, -0.3336730632123684),
+            c(0.7082554724086624, -0.09123861809547557),
+        ),
+        (
+            -2.5,
+            c(-0.5, 0.3),
+            c(9.107560692664467, -2.5445661800747494),
+            c(0.013258345911159859, 0.003261835705861291),
+        ),
+        (
+            -3.0,
+            c(4.0, -4.0),
+            c(-4.617662808678474, 2.4694861463598405),
+            c(2.479965593173725, 4.616014497353054),
+        ),
+    ];
+
+    #[test]
+    fn test_c_jv() {
+        for (nu, z, jv, _) in KNOWN_VALUES {
+            assert_almost_eq!(c_jv(nu, z), jv, PRECISION * jv.norm());
+        }
+
+        // Agrees with the real-valued implementation on the positive real axis
+        assert_eq!(c_jv(2.5, c(3.0, 0.0)), c(r_jv(2.5, 3.0), 0.0));
+        // The sign of a zero imaginary part selects the side of the branch cut
+        assert_eq!(c_jv(0.3, c(-5.0, -0.0)), c_jv(0.3, c(-5.0, 0.0)).conj());
+
+        assert!(c_jv(f64::NAN, c(1.0, 1.0)).is_nan());
+        assert!(c_jv(1.0, c(f64::NAN, 1.0)).is_nan());
+    }
+
+    #[test]
+    fn test_c_yv() {
+        for (nu, z, _, yv) in KNOWN_VALUES {
+            assert_almost_eq!(c_yv(nu, z), yv, PRECISION * yv.norm());
+        }
+
+        // Agrees with the real-valued implementation on the positive real axis
+        assert_eq!(c_yv(2.5, c(3.0, 0.0)), c(r_yv(2.5, 3.0), 0.0));
+        assert_eq!(c_yv(0.3, c(-5.0, -0.0)), c_yv(0.3, c(-5.0, 0.0)).conj());
+
+        assert!(c_yv(f64::NAN, c(1.0, 1.0)).is_nan());
+    }
+}
@@ -0,0 +1,405 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::r_spherical::{
+    r_spherical_in_all_orders, r_spherical_jn_all_orders, r_spherical_kn_all_orders,
+    r_spherical_yn_all_orders,
+};
+use crate::special::bessel::spherical_util::{
+    sph_in_orders, sph_jn_orders, sph_kn_orders, sph_orders, sph_yn_orders,
+};
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// Spherical Bessel functions of the second kind $y_0(z), \ldots, y_{n_{max}}(z)$ for $z \neq 0$.
+///
+/// Away from the real axis, $y_n(z)$ is not the dominant solution of the recurrence for all orders and its forward recurrence loses accuracy. Instead, we use the spherical Hankel function that is exponentially small at $n = 0$, which is
+/// $$
+/// h^{(2)}_n(z) = j_n(z) - iy_n(z), \quad h^{(2)}_0(z) = \frac{ie^{-iz}}{z}, \quad h^{(2)}_1(z) = \frac{h^{(2)}_0(z) - e^{-iz}}{z}
+/// $$
+/// for $\Im z < 0$, and $h^{(1)}_n(z) = \overline{h^{(2)}_n(\bar{z})}$ for $\Im z > 0$. Its forward recurrence is stable, and $y_n(z)$ follows from $j_n(z)$, see [sph_jn_orders].
+fn yn_orders<T>(n_max: usize, z: Complex<T>) -> Vec<Complex<T>>
+where
+    T: Float + FloatConst,
+{
+    if z.im.is_zero() {
+        return sph_yn_orders(n_max, z);
+    }
+    if z.im > T::zero() {
+        return yn_orders(n_max, z.conj())
+            .into_iter()
+            .map(|y| y.conj())
+            .collect();
+    }
+
+    let i = Complex::<T>::i();
+    let exp = (-i * z).exp();
+    let mut h2 = Vec::with_capacity(n_max + 1);
+    h2.push(i * exp / z);
+    if n_max > 0 {
+        h2.push((h2[0] - exp) / z);
+    }
+    for k in 1..n_max {
+        h2.push(h2[k] * cast::<usize, T>(2 * k + 1).unwrap() / z - h2[k - 1]);
+    }
+    sph_jn_orders(n_max, z)
+        .into_iter()
+        .zip(h2)
+        .map(|(j, h)| i * (h - j))
+        .collect()
+}
+
+/// Evaluates the spherical Bessel functions, or their derivatives, for complex arguments.
+///
+/// Non-finite arguments give NaN. At $z = 0$, the values are delegated to the real-valued implementation `real`. Otherwise, the orders are evaluated with `orders`, see [sph_orders].
+fn c_spherical_all_orders<T, R, F>(
+    n_max: u32,
+    z: Complex<T>,
+    derivative: bool,
+    real: R,
+    orders: F,
+    signs: (T, T),
+) -> Vec<Complex<T>>
+where
+    T: Float + FloatConst,
+    R: Fn(u32, T, bool) -> Vec<T>,
+    F: Fn(usize, Complex<T>) -> Vec<Complex<T>>,
+{
+    if !z.re.is_finite() || !z.im.is_finite() {
+        return vec![Complex::new(T::nan(), T::nan()); n_max as usize + 1];
+    }
+    if z.re.is_zero() && z.im.is_zero() {
+        return real(n_max, T::zero(), derivative)
+            .into_iter()
+            .map(|value| Complex::new(value, T::zero()))
+            .collect();
+    }
+    let (sigma, tau) = signs;
+    sph_orders(
+        n_max,
+        z,
+        derivative,
+        orders,
+        Complex::new(sigma, T::zero()),
+        Complex::new(tau, T::zero()),
+    )
+}
+
+/// Spherical Bessel functions of the first kind for complex arguments implementation.
+pub(crate) fn c_spherical_jn_all_orders<T>(
+    n_max: u32,
+    z: Complex<T>,
+    derivative: bool,
+) -> Vec<Complex<T>>
+where
+    T: Float + FloatConst,
+{
+    let signs = (T::one(), -T::one());
+    c_spherical_all_orders(
+        n_max,
+        z,
+        derivative,
+        r_spherical_jn_all_orders,
+        sph_jn_orders,
+        signs,
+    )
+}
+
+/// Spherical Bessel function of the first kind for complex arguments implementation, see [c_spherical_jn_all_orders].
+pub(crate) fn c_spherical_jn<T>(n: u32, z: Complex<T>, derivative: bool) -> Complex<T>
+where
+    T: Float + FloatConst,
+{
+    c_spherical_jn_all_orders(n, z, derivative)[n as usize]
+}
+
+/// Spherical Bessel functions of the second kind for complex arguments implementation.
+pub(crate) fn c_spherical_yn_all_orders<T>(
+    n_max: u32,
+    z: Complex<T>,
+    derivative: bool,
+) -> Vec<Complex<T>>
+where
+    T: Float + FloatConst,
+{
+    let signs = (T::one(), -T::one());
+    c_spherical_all_orders(
+        n_max,
+        z,
+        derivative,
+        r_spherical_yn_all_orders,
+        yn_orders,
+        signs,
+    )
+}
+
+/// Spherical Bessel function of the second kind for complex arguments implementation, see [c_spherical_yn_all_orders].
+pub(crate) fn c_spherical_yn<T>(n: u32, z: Complex<T>, derivative: bool) -> Complex<T>
+where
+    T: Float + FloatConst,
+{
+    c_spherical_yn_all_orders(n, z, derivative)[n as usize]
+}
+
+/// Modified spherical Bessel functions of the first kind for complex arguments implementation.
+pub(crate) fn c_spherical_in_all_orders<T>(
+    n_max: u32,
+    z: Complex<T>,
+    derivative: bool,
+) -> Vec<Complex<T>>
+where
+    T: Float + FloatConst,
+{
+    let signs = (T::one(), T::one());
+    c_spherical_all_orders(
+        n_max,
+        z,
+        derivative,
+        r_spherical_in_all_orders,
+        sph_in_orders,
+        signs,
+    )
+}
+
+/// Modified spherical Bessel function of the first kind for complex arguments implementation, see [c_spherical_in_all_orders].
+pub(crate) fn c_spherical_in<T>(n: u32, z: Complex<T>, derivative: bool) -> Complex<T>
+where
+    T: Float + FloatConst,
+{
+    c_spherical_in_all_orders(n, z, derivative)[n as usize]
+}
+
+/// Modified spherical Bessel functions of the second kind for complex arguments implementation.
+pub(crate) fn c_spherical_kn_all_orders<T>(
+    n_max: u32,
+    z: Complex<T>,
+    derivative: bool,
+) -> Vec<Complex<T>>
+where
+    T: Float + FloatConst,
+{
+    let signs = (-T::one(), -T::one());
+    c_spherical_all_orders(
+        n_max,
+        z,
+        derivative,
+        r_spherical_kn_all_orders,
+        sph_kn_orders,
+        signs,
+    )
+}
+
+/// Modified spherical Bessel function of the second kind for complex arguments implementation, see [c_spherical_kn_all_orders].
+pub(crate) fn c_spherical_kn<T>(n: u32, z: Complex<T>, derivative: bool) -> Complex<T>
+where
+    T: Float + FloatConst,
+{
+    c_spherical_kn_all_orders(n, z, derivative)[n as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::special::bessel::r_spherical::{
+        r_spherical_in, r_spherical_jn, r_spherical_kn, r_spherical_yn,
+    };
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    const fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [(u32, Complex64, [Complex64; 4]); 8] = [
+        // n, z, [jn(z), yn(z), in(z), kn(z)]
+        (
+            0,
+            c(1.0, 1.0),
+            [
+                c(0.9667107481003567, -0.3317468333156206),
+                c(0.07758384031585802, 0.9113138654470071),
+                c(0.9667107481003567, 0.3317468333156206),
+                c(-0.08701721978776952, -0.39923829581122405),
+            ],
+        ),
+        (
+            3,
+            c(1.0, 1.0),
+            [
+                c(-0.016837524127428158, 0.021065395989229227),
+                c(3.6146064749207247, 0.7090273054328634),
+                c(-0.021065395989229227, 0.016837524127428158),
+                c(-5.710900020256886, 1.1401858080229097),
+            ],
+        ),
+        (
+            2,
+            c(-3.0, 2.0),
+            [
+                c(0.637423939077392, -0.17308321035470164),
+                c(0.23414528781400798, 0.646439240792597),
+                c(-0.4959833370016798, -1.20232819335725),
+                c(1.5987317556079055, 3.7693609893341424),
+            ],
+        ),
+        (
+            10,
+            c(0.5, -0.5),
+            [
+                c(2.470441476519817e-14, -2.272717709828292e-12),
+                c(20673639290.1434, -21224967568.135574),
+                c(-2.470441476519817e-14, -2.272717709828292e-12),
+                c(-33340101092.368168, 32474076658.439903),
+            ],
+        ),
+        (
+            5,
+            c(5.0, -20.0),
+            [
+                c(-5591435.541525075, -1277841.068717863),
+                c(-1277841.068717863, 5591435.541525075),
+                c(2.620186035076388, -1.577308277412429),
+                c(-0.0006233575919013447, -2.13948515323556e-05),
+            ],
+        ),
+        (
+            15,
+            c(5.0, -20.0),
+            [
+                c(9794.228953137572, 43830.51480828356),
+                c(43830.514808262684, -9794.228953133725),
+                c(0.7147181267471765, 0.37434497507964654),
+                c(-0.0010745478257398278, 0.002854966022115795),
+            ],
+        ),
+        (
+            4,
+            c(-1.0, 50.0),
+            [
+                c(2.3453708160674415e+19, 3.5278404517016936e+19),
+                c(-3.5278404517016936e+19, 2.3453708160674415e+19),
+                c(-0.0015468953436446513, 0.023408463496988663),
+                c(0.003866475583012387, -0.08512194489081543),
+            ],
+        ),
+        (
+            8,
+            c(0.0, 3.0),
+            [
+                c(0.0002406533501876331, 0.0),
+                c(0.0, 76.7876762258323),
+                c(0.00014983375626892928, 0.0),
+                c(-0.00023535831397711592, -220.00590534347683),
+            ],
+        ),
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_DERIVATIVES: [(u32, Complex64, [Complex64; 4]); 2] = [
+        // n, z, [jn'(z), yn'(z), in'(z), kn'(z)]
+        (
+            3,
+            c(1.0, 1.0),
+            [
+                c(0.010559816846907917, 0.05646990862849434),
+                c(-8.310326475029628, 6.731155085178894),
+                c(-0.010559816846907917, 0.05646990862849434),
+                c(10.556686361371048, -12.965127576394838),
+            ],
+        ),
+        (
+            2,
+            c(-3.0, 2.0),
+            [
+                c(-0.17783077268083564, -0.42445353400393515),
+                c(0.44015439782709825, -0.10534799065463622),
+                c(0.30364781257939666, 1.1185306370329107),
+                c(-0.8977691596539357, -3.5118914945292725),
+            ],
+        ),
+    ];
+
+    #[test]
+    fn test_c_spherical_jn() {
+        for (n, z, [jn, _, _, _]) in KNOWN_VALUES {
+            assert_almost_eq!(c_spherical_jn(n, z, false), jn, PRECISION * jn.norm());
+            let orders = c_spherical_jn_all_orders(20, z, false);
+            assert_almost_eq!(orders[n as usize], jn, PRECISION * jn.norm());
+        }
+        for (n, z, [djn, _, _, _]) in KNOWN_DERIVATIVES {
+            assert_almost_eq!(c_spherical_jn(n, z, true), djn, PRECISION * djn.norm());
+        }
+
+        // Agrees with the real-valued implementation on the real axis
+        assert_almost_eq!(
+            c_spherical_jn(7, c(10.0, 0.0), false),
+            c(r_spherical_jn(7, 10.0, false), 0.0),
+            PRECISION
+        );
+        assert_eq!(c_spherical_jn(2, c(0.0, 0.0), true), c(0.0, 0.0));
+        assert!(c_spherical_jn(2, c(f64::NAN, 1.0), false).is_nan());
+    }
+
+    #[test]
+    fn test_c_spherical_yn() {
+        for (n, z, [_, yn, _, _]) in KNOWN_VALUES {
+            assert_almost_eq!(c_spherical_yn(n, z, false), yn, PRECISION * yn.norm());
+            let orders = c_spherical_yn_all_orders(20, z, false);
+            assert_almost_eq!(orders[n as usize], yn, PRECISION * yn.norm());
+        }
+        for (n, z, [_, dyn_, _, _]) in KNOWN_DERIVATIVES {
+            assert_almost_eq!(c_spherical_yn(n, z, true), dyn_, PRECISION * dyn_.norm());
+        }
+
+        assert_almost_eq!(
+            c_spherical_yn(7, c(10.0, 0.0), false),
+            c(r_spherical_yn(7, 10.0, false), 0.0),
+            PRECISION
+        );
+        assert!(c_spherical_yn(2, c(1.0, f64::INFINITY), false).is_nan());
+    }
+
+    #[test]
+    fn test_c_spherical_in() {
+        for (n, z, [_, _, i_n, _]) in KNOWN_VALUES {
+            assert_almost_eq!(c_spherical_in(n, z, false), i_n, PRECISION * i_n.norm());
+            let orders = c_spherical_in_all_orders(20, z, false);
+            assert_almost_eq!(orders[n as usize], i_n, PRECISION * i_n.norm());
+        }
+        for (n, z, [_, _, di_n, _]) in KNOWN_DERIVATIVES {
+            assert_almost_eq!(c_spherical_in(n, z, true), di_n, PRECISION * di_n.norm());
+        }
+
+        let expected = r_spherical_in(7, 10.0, false);
+        assert_almost_eq!(
+            c_spherical_in(7, c(10.0, 0.0), false),
+            c(expected, 0.0),
+            PRECISION * expected
+        );
+        assert!(c_spherical_in(2, c(f64::NAN, 1.0), false).is_nan());
+    }
+
+    #[test]
+    fn test_c_spherical_kn() {
+        for (n, z, [_, _, _, kn]) in KNOWN_VALUES {
+            assert_almost_eq!(c_spherical_kn(n, z, false), kn, PRECISION * kn.norm());
+            let orders = c_spherical_kn_all_orders(20, z, false);
+            assert_almost_eq!(orders[n as usize], kn, PRECISION * kn.norm());
+        }
+        for (n, z, [_, _, _, dkn]) in KNOWN_DERIVATIVES {
+            assert_almost_eq!(c_spherical_kn(n, z, true), dkn, PRECISION * dkn.norm());
+        }
+
+        let expected = r_spherical_kn(7, 10.0, false);
+        assert_almost_eq!(
+            c_spherical_kn(7, c(10.0, 0.0), false),
+            c(expected, 0.0),
+            PRECISION * expected
+        );
+        assert!(c_spherical_kn(2, c(f64::NAN, 1.0), false).is_nan());
+    }
+}
@@ -0,0 +1,51 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Bessel functions, modified Bessel functions, spherical Bessel functions and Airy functions.
+
+mod airy_trait;
+mod bessel_trait;
+mod modified_bessel_trait;
+mod spherical_bessel_trait;
+
+pub use airy_trait::*;
+pub use bessel_trait::*;
+pub use modified_bessel_trait::*;
+pub use spherical_bessel_trait::*;
+
+pub(crate) mod bessel_util;
+mod c_airy;
+mod c_ik;
+mod c_iv;
+mod c_jv;
+mod c_spherical;
+mod r_airy;
+mod r_ik0;
+mod r_ik1;
+mod r_iv;
+mod r_j0;
+mod r_j1;
+mod r_jn;
+mod r_jv;
+mod r_spherical;
+mod spherical_util;
+
+pub(crate) mod real_bessel_impl {
+    pub(crate) use super::r_airy::*;
+    pub(crate) use super::r_ik0::*;
+    pub(crate) use super::r_ik1::*;
+    pub(crate) use super::r_iv::*;
+    pub(crate) use super::r_j0::*;
+    pub(crate) use super::r_j1::*;
+    pub(crate) use super::r_jn::*;
+    pub(crate) use super::r_jv::*;
+    pub(crate) use super::r_spherical::*;
+}
+pub(crate) mod complex_bessel_impl {
+    pub(crate) use super::c_airy::*;
+    pub(crate) use super::c_iv::*;
+    pub(crate) use super::c_jv::*;
+    pub(crate) use super::c_spherical::*;
+}
@@ -0,0 +1,433 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::complex_bessel_impl::*;
+use crate::special::bessel::real_bessel_impl::*;
+
+use num_complex::Complex;
+
+/// Modified Bessel functions of the first and second kind for both real and complex-valued arguments.
+pub trait ModifiedBessel {
+    /// Real type of the order $\nu$.
+    type Real;
+
+    /// Modified Bessel function of the first kind of order 0.
+    /// $$
+    /// I_0(z) = \frac{1}{\pi}\int_0^\pi e^{z\cos\theta}d\theta
+    /// $$
+    /// See [iv] for the general order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1.0_f64.i0() - 1.2660658777520084).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.i0(), 1.0);
+    /// assert_eq!(800.0_f64.i0(), f64::INFINITY); // See i0e
+    /// ```
+    /// # Notes
+    /// For real arguments, the implementation follows the structure of [cephes]. For $|x| \leq 8$, $e^{-|x|}I_0(x)$ is a Chebyshev expansion in $x$, and otherwise $\sqrt{|x|}e^{-|x|}I_0(x)$ is a Chebyshev expansion in $1/x$. Complex arguments are evaluated with [iv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [iv]: crate::special::ModifiedBessel::iv
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/i0.c
+    fn i0(self) -> Self;
+
+    /// Exponentially scaled modified Bessel function of the first kind of order 0.
+    /// $$
+    /// \mathrm{i0e}(z) = e^{-|\Re z|}I_0(z)
+    /// $$
+    /// which does not overflow for large real arguments.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((800.0_f64.i0e() - 0.014106945005869184).abs() < 1e-16);
+    /// assert!(((-1.0_f64).i0e() - 1.0_f64.i0() / 1.0_f64.exp()).abs() < 1e-15);
+    /// ```
+    /// # Notes
+    /// See [i0].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [i0]: crate::special::ModifiedBessel::i0
+    fn i0e(self) -> Self;
+
+    /// Modified Bessel function of the first kind of order 1.
+    /// $$
+    /// I_1(z) = \frac{1}{\pi}\int_0^\pi e^{z\cos\theta}\cos\theta\,d\theta
+    /// $$
+    /// See [iv] for the general order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1.0_f64.i1() - 0.565159103992485).abs() < 1e-15);
+    /// assert!(((-1.0_f64).i1() + 0.565159103992485).abs() < 1e-15);
+    /// ```
+    /// # Notes
+    /// For real arguments, the implementation follows the structure of [cephes], see [i0]. Complex arguments are evaluated with [iv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [iv]: crate::special::ModifiedBessel::iv
+    /// [i0]: crate::special::ModifiedBessel::i0
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/i1.c
+    fn i1(self) -> Self;
+
+    /// Exponentially scaled modified Bessel function of the first kind of order 1.
+    /// $$
+    /// \mathrm{i1e}(z) = e^{-|\Re z|}I_1(z)
+    /// $$
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1.0_f64.i1e() - 1.0_f64.i1() / 1.0_f64.exp()).abs() < 1e-15);
+    /// ```
+    /// # Notes
+    /// See [i1].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [i1]: crate::special::ModifiedBessel::i1
+    fn i1e(self) -> Self;
+
+    /// Modified Bessel function of the first kind of real order $\nu$.
+    /// $$
+    /// I_\nu(z) = \left(\frac{z}{2}\right)^\nu\sum_{k=0}^{\infty}\frac{(z^2/4)^k}{k!\Gamma(\nu+k+1)}
+    /// $$
+    /// It is a solution of the modified Bessel equation
+    /// $$
+    /// z^2\frac{d^2w}{dz^2} + z\frac{dw}{dz} - (z^2 + \nu^2)w = 0
+    /// $$
+    /// and is related to the [Bessel function] through $I_\nu(z) = e^{\mp i\nu\pi/2}J_\nu(ze^{\pm i\pi/2})$. For non-integer $\nu$, there is a branch cut along the negative real axis and NaN is returned for negative real arguments. See the [DLMF] or [wiki] pages for more details.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1.5_f64.iv(2.5) - 0.17166202218829632).abs() < 1e-15);
+    /// assert!((1.5_f64.iv(-2.5) - 0.8015666610717216).abs() < 1e-15);
+    /// assert!((-1.0_f64).iv(0.5).is_nan());
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(1.0, 1.0);
+    /// let expected = Complex64::new(0.7269806459635546, 0.6418384753379859);
+    /// assert!((z.iv(0.5) - expected).norm() < 1e-15);
+    /// ```
+    /// # Notes
+    /// Orders 0 and 1 of real arguments are evaluated with [i0] and [i1]. Otherwise, the asymptotic expansion
+    /// $$
+    /// I_\nu(z) \sim \frac{e^z}{\sqrt{2\pi z}}\sum_{k=0}^{\infty}(-1)^k\frac{a_k(\nu)}{z^k}
+    /// $$
+    /// is used for $|z| \geq \max(25, \nu)$, as long as its terms stay small. For complex arguments, it also includes the exponentially small contribution $\pm ie^{\pm i\nu\pi}e^{-z}$. Elsewhere, we follow the algorithm `bessik` of [Numerical Recipes], extended to complex arguments. It combines the continued fraction for $I_\nu'/I_\nu$ with Temme's series for $|z| \leq 2$ and Steed's method for $|z| > 2$, which give $K_\nu$, and fixes $I_\nu$ from their Wronskian. The left half-plane follows from $I_\nu(ze^{\pm i\pi}) = e^{\pm i\nu\pi}I_\nu(z)$.
+    ///
+    /// Negative orders use the reflection formula
+    /// $$
+    /// I_{-\nu}(z) = I_\nu(z) + \frac{2}{\pi}\sin(\nu\pi)K_\nu(z)
+    /// $$
+    ///
+    /// # References
+    /// - [Numerical Recipes]
+    /// - N. M. Temme, "On the numerical evaluation of the modified Bessel function of the third kind", J. Comput. Phys. 19, 324 (1975).
+    /// - [DLMF]
+    /// - [Wikipedia][wiki]
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [i0]: crate::special::ModifiedBessel::i0
+    /// [i1]: crate::special::ModifiedBessel::i1
+    /// [Bessel function]: crate::special::Bessel::jv
+    /// [Numerical Recipes]: https://numerical.recipes/
+    /// [DLMF]: https://dlmf.nist.gov/10.25
+    /// [wiki]: https://en.wikipedia.org/wiki/Bessel_function#Modified_Bessel_functions:_I%CE%B1,_K%CE%B1
+    fn iv(self, nu: Self::Real) -> Self;
+
+    /// Exponentially scaled modified Bessel function of the first kind of real order $\nu$.
+    /// $$
+    /// \mathrm{ive}(\nu, z) = e^{-|\Re z|}I_\nu(z)
+    /// $$
+    /// which does not overflow for large real arguments.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1000.0_f64.ive(2.5) - 0.012577853469258328).abs() < 1e-16);
+    /// assert_eq!(1000.0_f64.iv(2.5), f64::INFINITY);
+    /// ```
+    /// # Notes
+    /// See [iv], which is evaluated through this function.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [iv]: crate::special::ModifiedBessel::iv
+    fn ive(self, nu: Self::Real) -> Self;
+
+    /// Modified Bessel function of the second kind of order 0.
+    ///
+    /// See [kv] for the general order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1.0_f64.k0() - 0.42102443824070834).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.k0(), f64::INFINITY);
+    /// assert!((-1.0_f64).k0().is_nan());
+    /// ```
+    /// # Notes
+    /// For real arguments, the implementation follows the structure of [cephes]. For $x \leq 2$, we use
+    /// $$
+    /// K_0(x) = R(x^2) - \ln\left(\frac{x}{2}\right)I_0(x)
+    /// $$
+    /// where $R$ is a Chebyshev expansion. Otherwise, $\sqrt{x}e^xK_0(x)$ is a Chebyshev expansion in $1/x$. Complex arguments are evaluated with [kv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [kv]: crate::special::ModifiedBessel::kv
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/k0.c
+    fn k0(self) -> Self;
+
+    /// Exponentially scaled modified Bessel function of the second kind of order 0.
+    /// $$
+    /// \mathrm{k0e}(z) = e^zK_0(z)
+    /// $$
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1.0_f64.k0e() - 1.144463079806895).abs() < 1e-15);
+    /// ```
+    /// # Notes
+    /// See [k0].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [k0]: crate::special::ModifiedBessel::k0
+    fn k0e(self) -> Self;
+
+    /// Modified Bessel function of the second kind of order 1.
+    ///
+    /// See [kv] for the general order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((2.5_f64.k1() - 0.07389081634774707).abs() < 1e-16);
+    /// assert_eq!(0.0_f64.k1(), f64::INFINITY);
+    /// ```
+    /// # Notes
+    /// For real arguments, the implementation follows the structure of [cephes], see [k0]. Complex arguments are evaluated with [kv].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [kv]: crate::special::ModifiedBessel::kv
+    /// [k0]: crate::special::ModifiedBessel::k0
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/k1.c
+    fn k1(self) -> Self;
+
+    /// Exponentially scaled modified Bessel function of the second kind of order 1.
+    /// $$
+    /// \mathrm{k1e}(z) = e^zK_1(z)
+    /// $$
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1.0_f64.k1e() - 1.636153486263258).abs() < 1e-15);
+    /// ```
+    /// # Notes
+    /// See [k1].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [k1]: crate::special::ModifiedBessel::k1
+    fn k1e(self) -> Self;
+
+    /// Modified Bessel function of the second kind of real order $\nu$.
+    /// $$
+    /// K_\nu(z) = \frac{\pi}{2}\frac{I_{-\nu}(z) - I_\nu(z)}{\sin(\nu\pi)}
+    /// $$
+    /// which is understood as its limit for integer $\nu$. It is the second solution of the modified Bessel equation, see [iv], which decays exponentially for large positive arguments. It is even in $\nu$. There is a branch cut along the negative real axis, and NaN is returned for negative real arguments. See the [DLMF] page for more details.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1.5_f64.kv(2.5) - 0.9894518929891503).abs() < 1e-15);
+    /// assert_eq!(1.5_f64.kv(-2.5), 1.5_f64.kv(2.5));
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(1.0, 1.0);
+    /// let expected = Complex64::new(0.06868578341999642, -0.3815782598126831);
+    /// assert!((z.kv(0.5) - expected).norm() < 1e-15);
+    /// ```
+    /// # Notes
+    /// Evaluated together with $I_\nu$, see [iv]. For large $|z|$, the asymptotic expansion
+    /// $$
+    /// K_\nu(z) \sim \sqrt{\frac{\pi}{2z}}e^{-z}\sum_{k=0}^{\infty}\frac{a_k(\nu)}{z^k}
+    /// $$
+    /// is used. The left half-plane follows from $K_\nu(ze^{\pm i\pi}) = e^{\mp i\nu\pi}K_\nu(z) \mp i\pi I_\nu(z)$.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [iv]: crate::special::ModifiedBessel::iv
+    /// [DLMF]: https://dlmf.nist.gov/10.25
+    fn kv(self, nu: Self::Real) -> Self;
+
+    /// Exponentially scaled modified Bessel function of the second kind of real order $\nu$.
+    /// $$
+    /// \mathrm{kve}(\nu, z) = e^zK_\nu(z)
+    /// $$
+    /// which does not underflow for large real arguments.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ModifiedBessel;
+    /// assert!((1000.0_f64.kve(2.5) - 0.03975229169480722).abs() < 1e-16);
+    /// assert_eq!(1000.0_f64.kv(2.5), 0.0);
+    /// ```
+    /// # Notes
+    /// See [kv], which is evaluated through this function.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [kv]: crate::special::ModifiedBessel::kv
+    fn kve(self, nu: Self::Real) -> Self;
+}
+
+macro_rules! float_modifiedbessel_impl {
+    ($($T: ty)*) => ($(
+        impl ModifiedBessel for $T {
+            type Real = $T;
+
+            #[inline(always)]
+            fn i0(self) -> Self {
+                r_i0(self)
+            }
+
+            #[inline(always)]
+            fn i0e(self) -> Self {
+                r_i0e(self)
+            }
+
+            #[inline(always)]
+            fn i1(self) -> Self {
+                r_i1(self)
+            }
+
+            #[inline(always)]
+            fn i1e(self) -> Self {
+                r_i1e(self)
+            }
+
+            #[inline(always)]
+            fn iv(self, nu: Self) -> Self {
+                r_iv(nu, self)
+            }
+
+            #[inline(always)]
+            fn ive(self, nu: Self) -> Self {
+                r_ive(nu, self)
+            }
+
+            #[inline(always)]
+            fn k0(self) -> Self {
+                r_k0(self)
+            }
+
+            #[inline(always)]
+            fn k0e(self) -> Self {
+                r_k0e(self)
+            }
+
+            #[inline(always)]
+            fn k1(self) -> Self {
+                r_k1(self)
+            }
+
+            #[inline(always)]
+            fn k1e(self) -> Self {
+                r_k1e(self)
+            }
+
+            #[inline(always)]
+            fn kv(self, nu: Self) -> Self {
+                r_kv(nu, self)
+            }
+
+            #[inline(always)]
+            fn kve(self, nu: Self) -> Self {
+                r_kve(nu, self)
+            }
+        }
+    )*)
+}
+
+float_modifiedbessel_impl! {f32 f64}
+
+macro_rules! complex_modifiedbessel_impl {
+    ($($T: ty)*) => ($(
+        impl ModifiedBessel for Complex<$T> {
+            type Real = $T;
+
+            #[inline(always)]
+            fn i0(self) -> Self {
+                c_iv(0.0, self)
+            }
+
+            #[inline(always)]
+            fn i0e(self) -> Self {
+                c_ive(0.0, self)
+            }
+
+            #[inline(always)]
+            fn i1(self) -> Self {
+                c_iv(1.0, self)
+            }
+
+            #[inline(always)]
+            fn i1e(self) -> Self {
+                c_ive(1.0, self)
+            }
+
+            #[inline(always)]
+            fn iv(self, nu: $T) -> Self {
+                c_iv(nu, self)
+            }
+
+            #[inline(always)]
+            fn ive(self, nu: $T) -> Self {
+                c_ive(nu, self)
+            }
+
+            #[inline(always)]
+            fn k0(self) -> Self {
+                c_kv(0.0, self)
+            }
+
+            #[inline(always)]
+            fn k0e(self) -> Self {
+                c_kve(0.0, self)
+            }
+
+            #[inline(always)]
+            fn k1(self) -> Self {
+                c_kv(1.0, self)
+            }
+
+            #[inline(always)]
+            fn k1e(self) -> Self {
+                c_kve(1.0, self)
+            }
+
+            #[inline(always)]
+            fn kv(self, nu: $T) -> Self {
+                c_kv(nu, self)
+            }
+
+            #[inline(always)]
+            fn kve(self, nu: $T) -> Self {
+                c_kve(nu, self)
+            }
+        }
+    )*)
+}
+
+complex_modifiedbessel_impl! {f32 f64}
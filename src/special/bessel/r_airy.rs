@@ -0,0 +1,409 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::bessel_util::TemmeConsts;
+use crate::special::bessel::r_ik0::BesselIK0Consts;
+use crate::special::bessel::r_ik1::BesselIK1Consts;
+use crate::special::bessel::r_iv::ik_positive;
+use crate::special::bessel::r_jv::jy_positive;
+use crate::special::Gamma;
+use num_traits::{cast, Float, FloatConst};
+
+/// Maximum number of terms in the power series and the asymptotic expansions.
+const MAX_ITER: usize = 100;
+
+/// Smallest $|x|$ for which the asymptotic expansions are attempted. There, the smallest term is about $e^{-2\zeta} < 10^{-18}$.
+const ASYMPTOTIC: f64 = 10.0;
+
+/// Maclaurin series of the Airy functions and their derivatives, see [DLMF 9.4](https://dlmf.nist.gov/9.4),
+/// $$
+/// \mathrm{Ai}(z) = c_1f(z) - c_2g(z), \quad \mathrm{Bi}(z) = \sqrt{3}\left(c_1f(z) + c_2g(z)\right)
+/// $$
+/// with
+/// $$
+/// f(z) = \sum_{k=0}^{\infty}3^k\left(\frac{1}{3}\right)_k\frac{z^{3k}}{(3k)!}, \quad g(z) = \sum_{k=0}^{\infty}3^k\left(\frac{2}{3}\right)_k\frac{z^{3k+1}}{(3k+1)!}
+/// $$
+/// and the values at the origin $c_1 = \mathrm{Ai}(0) = 3^{-2/3}/\Gamma(2/3)$ and $c_2 = -\mathrm{Ai}'(0) = 3^{-1/3}/\Gamma(1/3)$. This is only used for $|z| \leq 1$, where the cancellation in $\mathrm{Ai}$ is mild.
+pub(crate) fn airy_series<T>(z: T) -> (T, T, T, T)
+where
+    T: num_complex::ComplexFloat,
+    T::Real: Gamma,
+{
+    let three = cast::<f64, T::Real>(3.0).unwrap();
+    let third = Float::recip(three);
+    let two_thirds = third + third;
+    let c1 = T::from(Float::powf(three, -two_thirds) * two_thirds.rgamma()).unwrap();
+    let c2 = T::from(Float::powf(three, -third) * third.rgamma()).unwrap();
+    let sqrt3 = T::from(Float::sqrt(three)).unwrap();
+
+    let z3 = z * z * z;
+    let mut f_term = T::one();
+    let mut g_term = z;
+    let mut df_term = z * z / T::from(2.0).unwrap();
+    let mut dg_term = T::one();
+    let (mut f, mut g, mut df, mut dg) = (f_term, g_term, df_term, dg_term);
+    let eps = T::Real::epsilon();
+    for k in 1..MAX_ITER {
+        let k3 = T::from(3 * k).unwrap();
+        f_term = f_term * z3 / ((k3 - T::one()) * k3);
+        g_term = g_term * z3 / (k3 * (k3 + T::one()));
+        df_term = df_term * z3 / ((k3 + T::from(2.0).unwrap()) * k3);
+        dg_term = dg_term * z3 / (k3 * (k3 - T::from(2.0).unwrap()));
+        f = f + f_term;
+        g = g + g_term;
+        df = df + df_term;
+        dg = dg + dg_term;
+        if f_term.abs() <= eps * f.abs()
+            && g_term.abs() <= eps * g.abs()
+            && df_term.abs() <= eps * df.abs()
+            && dg_term.abs() <= eps * dg.abs()
+        {
+            break;
+        }
+    }
+    (
+        c1 * f - c2 * g,
+        c1 * df - c2 * dg,
+        sqrt3 * (c1 * f + c2 * g),
+        sqrt3 * (c1 * df + c2 * dg),
+    )
+}
+
+/// Computes $\zeta = \frac{2}{3}|x|^{3/2}$.
+fn airy_zeta<T: Float>(x: T) -> T {
+    let t = x.abs();
+    cast::<f64, T>(2.0 / 3.0).unwrap() * t * t.sqrt()
+}
+
+/// Asymptotic expansions of the Airy functions for large $|x|$, see [DLMF 9.7](https://dlmf.nist.gov/9.7).
+///
+/// For $x > 0$, this computes the scaled functions
+/// $$
+/// e^{\zeta}\mathrm{Ai}(x) \sim \frac{1}{2\sqrt{\pi}x^{1/4}}\sum_{k=0}^{\infty}(-1)^k\frac{u_k}{\zeta^k}, \quad e^{-\zeta}\mathrm{Bi}(x) \sim \frac{1}{\sqrt{\pi}x^{1/4}}\sum_{k=0}^{\infty}\frac{u_k}{\zeta^k}
+/// $$
+/// and likewise for the derivatives with the prefactors $-x^{1/4}/(2\sqrt{\pi})$ and $x^{1/4}/\sqrt{\pi}$, and the coefficients $v_k$. For $x = -t < 0$, the unscaled functions are
+/// $$
+/// \mathrm{Ai}(-t) \sim \frac{\cos(\zeta - \pi/4)P_u + \sin(\zeta - \pi/4)Q_u}{\sqrt{\pi}t^{1/4}}, \quad \mathrm{Bi}(-t) \sim \frac{-\sin(\zeta - \pi/4)P_u + \cos(\zeta - \pi/4)Q_u}{\sqrt{\pi}t^{1/4}}
+/// $$
+/// where $P_u$ and $Q_u$ are the sums over the even and odd terms with alternating signs. The coefficients are
+/// $$
+/// u_k = \frac{\Gamma(3k+1/2)}{54^kk!\Gamma(k+1/2)}, \quad v_k = -\frac{6k+1}{6k-1}u_k
+/// $$
+/// which are evaluated by their ratios. Returns `None` if the terms start to increase again before the expansions have converged.
+fn airy_asymptotic<T>(x: T) -> Option<(T, T, T, T)>
+where
+    T: Float + FloatConst,
+{
+    let zeta = airy_zeta(x);
+    let positive = x > T::zero();
+
+    // For x > 0, the alternating and the plain sums, otherwise P and Q
+    let (mut su, mut sv) = (T::one(), T::one());
+    let (mut tu, mut tv) = if positive {
+        (T::one(), T::one())
+    } else {
+        (T::zero(), T::zero())
+    };
+    let mut u = T::one();
+    let mut converged = false;
+    let mut decreasing = false;
+    for k in 1..MAX_ITER {
+        let previous = u.abs();
+        let k6 = cast::<usize, T>(6 * k).unwrap();
+        let kf = cast::<usize, T>(k).unwrap();
+        let five = cast::<f64, T>(5.0).unwrap();
+        let three = cast::<f64, T>(3.0).unwrap();
+        u = u * (k6 - five) * (k6 - three) * (k6 - T::one())
+            / (cast::<f64, T>(216.0).unwrap() * kf * (kf + kf - T::one()) * zeta);
+        let v = -(k6 + T::one()) / (k6 - T::one()) * u;
+        if decreasing && u.abs() > previous {
+            break;
+        }
+        decreasing = u.abs() < previous;
+        if positive {
+            if k % 2 == 0 {
+                su = su + u;
+                sv = sv + v;
+            } else {
+                su = su - u;
+                sv = sv - v;
+            }
+            tu = tu + u;
+            tv = tv + v;
+        } else {
+            match k % 4 {
+                0 => (su, sv) = (su + u, sv + v),
+                1 => (tu, tv) = (tu + u, tv + v),
+                2 => (su, sv) = (su - u, sv - v),
+                _ => (tu, tv) = (tu - u, tv - v),
+            }
+        }
+        if u.abs().max(v.abs()) <= T::epsilon() {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return None;
+    }
+
+    let half = cast::<f64, T>(0.5).unwrap();
+    let rsqrt_pi = half * T::FRAC_2_SQRT_PI();
+    let quarter = x.abs().sqrt().sqrt();
+    if positive {
+        let amplitude = rsqrt_pi / quarter;
+        let amplitude_d = rsqrt_pi * quarter;
+        return Some((
+            half * amplitude * su,
+            -half * amplitude_d * sv,
+            amplitude * tu,
+            amplitude_d * tv,
+        ));
+    }
+
+    let (sin, cos) = zeta.sin_cos();
+    let cos_omega = (cos + sin) * T::FRAC_1_SQRT_2();
+    let sin_omega = (sin - cos) * T::FRAC_1_SQRT_2();
+    let amplitude = rsqrt_pi / quarter;
+    let amplitude_d = rsqrt_pi * quarter;
+    Some((
+        amplitude * (cos_omega * su + sin_omega * tu),
+        amplitude_d * (sin_omega * sv - cos_omega * tv),
+        amplitude * (cos_omega * tu - sin_omega * su),
+        amplitude_d * (cos_omega * sv + sin_omega * tv),
+    ))
+}
+
+/// Computes the Airy functions for $|x| > 1$, scaled as $e^{\zeta}\mathrm{Ai}(x)$ and $e^{-\zeta}\mathrm{Bi}(x)$ for $x > 0$ and unscaled for $x < 0$.
+///
+/// The asymptotic expansions are attempted for $|x| \geq 10$. Otherwise, or if they fail, we use the Bessel functions of order $1/3$ and $2/3$, as in [Amos]. For $x > 0$, [DLMF 9.6.1](https://dlmf.nist.gov/9.6#E1) to [DLMF 9.6.4](https://dlmf.nist.gov/9.6#E4) and the reflection formula of $I_{-\nu}$ give
+/// $$
+/// \mathrm{Ai}(x) = \frac{1}{\pi}\sqrt{\frac{x}{3}}K_{1/3}(\zeta), \quad \mathrm{Bi}(x) = \sqrt{\frac{x}{3}}\left(2I_{1/3}(\zeta) + \frac{\sqrt{3}}{\pi}K_{1/3}(\zeta)\right)
+/// $$
+/// and for $x = -t < 0$, [DLMF 9.6.6](https://dlmf.nist.gov/9.6#E6) to [DLMF 9.6.9](https://dlmf.nist.gov/9.6#E9) together with the reflection formula of $J_{-\nu}$ give
+/// $$
+/// \mathrm{Ai}(-t) = \frac{\sqrt{t}}{2}\left(J_{1/3}(\zeta) - \frac{Y_{1/3}(\zeta)}{\sqrt{3}}\right), \quad \mathrm{Bi}(-t) = -\frac{\sqrt{t}}{2}\left(\frac{J_{1/3}(\zeta)}{\sqrt{3}} + Y_{1/3}(\zeta)\right)
+/// $$
+/// The derivatives follow in the same way from the order $2/3$.
+///
+/// [Amos]: https://doi.org/10.1145/7921.214331
+fn airy_large<T>(x: T) -> (T, T, T, T)
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if x.abs() >= cast::<f64, T>(ASYMPTOTIC).unwrap() {
+        if let Some(result) = airy_asymptotic(x) {
+            return result;
+        }
+    }
+
+    let three = cast::<f64, T>(3.0).unwrap();
+    let third = three.recip();
+    let sqrt3 = three.sqrt();
+    let zeta = airy_zeta(x);
+    let t = x.abs();
+    if x > T::zero() {
+        let (i1, k1) = ik_positive(third, zeta);
+        let (i2, k2) = ik_positive(third + third, zeta);
+        let reflection = sqrt3 * T::FRAC_1_PI() * (-zeta - zeta).exp();
+        let root = (t / three).sqrt();
+        let two = T::one() + T::one();
+        return (
+            root * T::FRAC_1_PI() * k1,
+            -t / sqrt3 * T::FRAC_1_PI() * k2,
+            root * (two * i1 + reflection * k1),
+            t / sqrt3 * (two * i2 + reflection * k2),
+        );
+    }
+
+    let (j1, y1) = jy_positive(third, zeta);
+    let (j2, y2) = jy_positive(third + third, zeta);
+    let half = cast::<f64, T>(0.5).unwrap();
+    let root = half * t.sqrt();
+    let t = half * t;
+    (
+        root * (j1 - y1 / sqrt3),
+        t * (j2 + y2 / sqrt3),
+        -root * (j1 / sqrt3 + y1),
+        t * (j2 / sqrt3 - y2),
+    )
+}
+
+/// Airy functions implementation.
+///
+/// Returns $(\mathrm{Ai}(x), \mathrm{Ai}'(x), \mathrm{Bi}(x), \mathrm{Bi}'(x))$. The power series is used for $|x| \leq 1$, see [airy_series], and [airy_large] otherwise. As $x \to -\infty$, the derivatives oscillate with growing amplitude and NaN is returned.
+pub(crate) fn r_airy<T>(x: T) -> (T, T, T, T)
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if x.is_nan() {
+        return (x, x, x, x);
+    }
+    if x.is_infinite() {
+        return if x > T::zero() {
+            (T::zero(), T::zero(), x, x)
+        } else {
+            (T::zero(), T::nan(), T::zero(), T::nan())
+        };
+    }
+    if x.abs() <= T::one() {
+        return airy_series(x);
+    }
+    let (ai, aip, bi, bip) = airy_large(x);
+    if x < T::zero() {
+        return (ai, aip, bi, bip);
+    }
+    let scale = airy_zeta(x).exp();
+    (ai / scale, aip / scale, bi * scale, bip * scale)
+}
+
+/// Exponentially scaled Airy functions implementation.
+///
+/// Returns $(e^{\zeta}\mathrm{Ai}(x), e^{\zeta}\mathrm{Ai}'(x), e^{-|\Re\zeta|}\mathrm{Bi}(x), e^{-|\Re\zeta|}\mathrm{Bi}'(x))$ with $\zeta = \frac{2}{3}x^{3/2}$. For $x < 0$, $\zeta$ is imaginary, such that the scaled $\mathrm{Ai}$ and $\mathrm{Ai}'$ are complex and NaN is returned, while $\mathrm{Bi}$ and $\mathrm{Bi}'$ are not scaled.
+pub(crate) fn r_airye<T>(x: T) -> (T, T, T, T)
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if x.is_nan() {
+        return (x, x, x, x);
+    }
+    if x < T::zero() {
+        let (_, _, bi, bip) = r_airy(x);
+        return (T::nan(), T::nan(), bi, bip);
+    }
+    if x.is_infinite() {
+        return (T::zero(), -x, T::zero(), x);
+    }
+    if x > T::one() {
+        return airy_large(x);
+    }
+    let (ai, aip, bi, bip) = airy_series(x);
+    let scale = airy_zeta(x).exp();
+    (ai * scale, aip * scale, bi / scale, bip / scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 5]; 12] = [
+        // x, Ai(x), Ai'(x), Bi(x), Bi'(x)
+        [
+            -50.0, -0.1618814236123209, 0.968989837276749, -0.13715015212882006,
+            -1.1453617002654777,
+        ],
+        [
+            -12.5, -0.27627456138116024, -0.41933133041950515, 0.11703336725739277,
+            -0.974516536167174,
+        ],
+        [
+            -5.2, 0.25258033810474473, 0.6399051669012839, -0.2750270441896408, 0.5634589795751737,
+        ],
+        [
+            -2.0, 0.22740742820168558, 0.618259020741691, -0.4123025879563985, 0.2787951669211695,
+        ],
+        [
+            -0.5, 0.4757280916105396, -0.20408167033954738, 0.38035265975105387, 0.5059337136238472,
+        ],
+        [
+            0.0, 0.3550280538878172, -0.2588194037928068, 0.6149266274460007, 0.4482883573538264,
+        ],
+        [
+            0.25, 0.2911639543485452, -0.24906211200489714, 0.728746903936215, 0.4698611937679594,
+        ],
+        [
+            1.0, 0.13529241631288141, -0.1591474412967932, 1.2074235949528713, 0.9324359333927756,
+        ],
+        [
+            2.0, 0.03492413042327438, -0.05309038443365363, 3.2980949999782148, 4.10068204993289,
+        ],
+        [
+            4.5,
+            0.00033025032351430896,
+            -0.0007178665675575089,
+            227.58808183559972,
+            469.13507732796637,
+        ],
+        [
+            12.0,
+            1.3931846888753607e-13,
+            -4.854736554985309e-13,
+            329807225829.07416,
+            1135507502443.3708,
+        ],
+        [
+            25.0,
+            8.116026824691387e-38,
+            -4.066089337243281e-37,
+            3.9220307780413816e+35,
+            1.957073508323331e+36,
+        ],
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_SCALED: [[f64; 5]; 4] = [
+        // x, eAi(x), eAi'(x), eBi(x), eBi'(x)
+        [
+            0.5, 0.29327715912994734, -0.28469116209194256, 0.6748924111156303, 0.43022096146376937,
+        ],
+        [
+            2.0, 0.2301649186525116, -0.3498882825800875, 0.5004372543040949, 0.6222179973154376,
+        ],
+        [
+            12.0, 0.15119256068463707, -0.5268505009124518, 0.3039054138807329, 1.046329038508012,
+        ],
+        [
+            150.0, 0.08060233779162404, -0.9873072898891083, 0.16122295760752584,
+            1.9743011083846553,
+        ],
+    ];
+
+    #[test]
+    fn test_r_airy() {
+        for [x, ai, aip, bi, bip] in KNOWN_VALUES {
+            let (result_ai, result_aip, result_bi, result_bip) = r_airy(x);
+            assert_almost_eq!(result_ai, ai, PRECISION * ai.abs());
+            assert_almost_eq!(result_aip, aip, PRECISION * aip.abs());
+            assert_almost_eq!(result_bi, bi, PRECISION * bi.abs());
+            assert_almost_eq!(result_bip, bip, PRECISION * bip.abs());
+        }
+
+        assert_eq!(
+            r_airy(f64::INFINITY),
+            (0.0, 0.0, f64::INFINITY, f64::INFINITY)
+        );
+        assert_eq!(r_airy(1000.0).2, f64::INFINITY);
+        let (ai, aip, bi, bip) = r_airy(f64::NEG_INFINITY);
+        assert_eq!((ai, bi), (0.0, 0.0));
+        assert!(aip.is_nan() && bip.is_nan());
+        assert!(r_airy(f64::NAN).0.is_nan());
+    }
+
+    #[test]
+    fn test_r_airye() {
+        for [x, ai, aip, bi, bip] in KNOWN_SCALED {
+            let (result_ai, result_aip, result_bi, result_bip) = r_airye(x);
+            assert_almost_eq!(result_ai, ai, PRECISION * ai.abs());
+            assert_almost_eq!(result_aip, aip, PRECISION * aip.abs());
+            assert_almost_eq!(result_bi, bi, PRECISION * bi.abs());
+            assert_almost_eq!(result_bip, bip, PRECISION * bip.abs());
+        }
+
+        // Ai is complex after scaling for x < 0, Bi is not scaled
+        let (ai, aip, bi, bip) = r_airye(-2.0);
+        assert!(ai.is_nan() && aip.is_nan());
+        assert_eq!((bi, bip), (r_airy(-2.0).2, r_airy(-2.0).3));
+
+        let (ai, aip, bi, bip) = r_airye(f64::INFINITY);
+        assert_eq!(
+            (ai, aip, bi, bip),
+            (0.0, f64::NEG_INFINITY, 0.0, f64::INFINITY)
+        );
+        assert!(r_airye(f64::NAN).2.is_nan());
+    }
+}
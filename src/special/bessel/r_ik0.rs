@@ -0,0 +1,259 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::eval_cheby;
+use num_traits::{cast, Float};
+
+/// Chebyshev coefficients for the modified Bessel functions of order 0, computed with mpmath.
+///
+/// `I0_SMALL` approximates $e^{-x}I_0(x)$ in $x$ on $\[0, 8\]$ and `I0_LARGE` approximates $\sqrt{x}e^{-x}I_0(x)$ in $1/x$ on $\[8, \infty)$. `K0_SMALL` approximates $K_0(x) + \ln(x/2)I_0(x)$ in $x^2$ on $\[0, 2\]$ and `K0_LARGE` approximates $\sqrt{x}e^xK_0(x)$ in $1/x$ on $\[2, \infty)$. The intervals are those of [cephes].
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/i0.c
+pub(crate) trait BesselIK0Consts: Sized {
+    const I0_SMALL: [Self; 30];
+    const I0_LARGE: [Self; 25];
+    const K0_SMALL: [Self; 10];
+    const K0_LARGE: [Self; 24];
+}
+
+macro_rules! impl_besselik0consts {
+    ($($T: ty)*) => ($(
+        impl BesselIK0Consts for $T {
+            const I0_SMALL: [Self; 30] = [
+                -4.4153416464793395e-18,
+                3.3307945188222384e-17,
+                -2.431279846547955e-16,
+                1.715391285555133e-15,
+                -1.1685332877993451e-14,
+                7.676185498604936e-14,
+                -4.856446783111929e-13,
+                2.95505266312964e-12,
+                -1.726826291441556e-11,
+                9.675809035373237e-11,
+                -5.189795601635263e-10,
+                2.6598237246823866e-09,
+                -1.300025009986248e-08,
+                6.046995022541919e-08,
+                -2.670793853940612e-07,
+                1.1173875391201037e-06,
+                -4.4167383584587505e-06,
+                1.6448448070728896e-05,
+                -5.754195010082104e-05,
+                0.00018850288509584165,
+                -0.0005763755745385824,
+                0.0016394756169413357,
+                -0.004324309995050576,
+                0.010546460394594998,
+                -0.02373741480589947,
+                0.04930528423967071,
+                -0.09490109704804764,
+                0.17162090152220877,
+                -0.3046826723431984,
+                0.33839763720473803,
+            ];
+            const I0_LARGE: [Self; 25] = [
+                -7.233180487874754e-18,
+                -4.830504485944182e-18,
+                4.46562142029676e-17,
+                3.461222867697461e-17,
+                -2.8276239805165836e-16,
+                -3.425485619677219e-16,
+                1.7725601330565263e-15,
+                3.8116806693526224e-15,
+                -9.554846698828307e-15,
+                -4.150569347287222e-14,
+                1.54008621752141e-14,
+                3.8527783827421426e-13,
+                7.180124451383666e-13,
+                -1.7941785315068062e-12,
+                -1.3215811840447713e-11,
+                -3.1499165279632416e-11,
+                1.1889147107846439e-11,
+                4.94060238822497e-10,
+                3.3962320257083865e-09,
+                2.266668990498178e-08,
+                2.0489185894690638e-07,
+                2.8913705208347567e-06,
+                6.889758346916825e-05,
+                0.0033691164782556943,
+                0.4022452055070544,
+            ];
+            const K0_SMALL: [Self; 10] = [
+                1.3744654358807508e-16,
+                4.2598161427910826e-14,
+                1.0349695257633625e-11,
+                1.904516377220209e-09,
+                2.5347910790261494e-07,
+                2.286212103119452e-05,
+                0.001264615411446926,
+                0.0359799365153615,
+                0.3442898999246285,
+                -0.2676636966169514,
+            ];
+            const K0_LARGE: [Self; 24] = [
+                -1.6475805939842632e-17,
+                5.2103917776435543e-17,
+                -1.6782311257549006e-16,
+                5.5120559994043335e-16,
+                -1.848593377920907e-15,
+                6.340076476276646e-15,
+                -2.2275133267462965e-14,
+                8.032890775068375e-14,
+                -2.9800969231481784e-13,
+                1.1403405882073441e-12,
+                -4.514597883374519e-12,
+                1.8559491149549264e-11,
+                -7.957489244477396e-11,
+                3.5773972814003283e-10,
+                -1.6975345093890614e-09,
+                8.574034017414225e-09,
+                -4.660489897687948e-08,
+                2.766813639445015e-07,
+                -1.8317555227191195e-06,
+                1.39498137188765e-05,
+                -0.00012849549581627802,
+                0.0015698838857300533,
+                -0.0314481013119645,
+                1.2201515410329777,
+            ];
+        }
+    )*)
+}
+
+impl_besselik0consts! {f32 f64}
+
+/// Exponentially scaled modified Bessel function of the first kind of order 0 implementation.
+///
+/// Follows the structure of [cephes]. For $|x| \leq 8$, $e^{-|x|}I_0(x)$ is a Chebyshev expansion in $|x|$, and otherwise $\sqrt{|x|}e^{-|x|}I_0(x)$ is a Chebyshev expansion in $1/|x|$.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/i0.c
+pub(crate) fn r_i0e<T>(x: T) -> T
+where
+    T: Float + BesselIK0Consts,
+{
+    let x = x.abs();
+    let eight = cast::<f64, T>(8.0).unwrap();
+    if x <= eight {
+        return eval_cheby(x / (eight / (T::one() + T::one())) - T::one(), &T::I0_SMALL);
+    }
+    let sixteen = eight + eight;
+    eval_cheby(sixteen / x - T::one(), &T::I0_LARGE) / x.sqrt()
+}
+
+/// Modified Bessel function of the first kind of order 0 implementation.
+///
+/// Evaluated as $I_0(x) = e^{|x|}\left(e^{-|x|}I_0(x)\right)$, see [r_i0e].
+pub(crate) fn r_i0<T>(x: T) -> T
+where
+    T: Float + BesselIK0Consts,
+{
+    if x.is_infinite() {
+        return T::infinity();
+    }
+    x.abs().exp() * r_i0e(x)
+}
+
+/// Modified Bessel function of the second kind of order 0 for $0 < x \leq 2$.
+fn k0_small<T>(x: T) -> T
+where
+    T: Float + BesselIK0Consts,
+{
+    let half = cast::<f64, T>(0.5).unwrap();
+    eval_cheby(x * x * half - T::one(), &T::K0_SMALL) - (x * half).ln() * r_i0(x)
+}
+
+/// Modified Bessel function of the second kind of order 0 implementation.
+///
+/// Follows the structure of [cephes]. For $x \leq 2$, we use $K_0(x) = R(x^2) - \ln(x/2)I_0(x)$, where $R$ is a Chebyshev expansion. Otherwise, $\sqrt{x}e^xK_0(x)$ is a Chebyshev expansion in $1/x$.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/k0.c
+pub(crate) fn r_k0<T>(x: T) -> T
+where
+    T: Float + BesselIK0Consts,
+{
+    if x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return T::infinity();
+    }
+    if x <= T::one() + T::one() {
+        return k0_small(x);
+    }
+    (-x).exp() * r_k0e(x)
+}
+
+/// Exponentially scaled modified Bessel function of the second kind of order 0 implementation.
+///
+/// Evaluates $e^xK_0(x)$, see [r_k0].
+pub(crate) fn r_k0e<T>(x: T) -> T
+where
+    T: Float + BesselIK0Consts,
+{
+    if x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return T::infinity();
+    }
+    let two = T::one() + T::one();
+    if x <= two {
+        return x.exp() * k0_small(x);
+    }
+    eval_cheby((two + two) / x - T::one(), &T::K0_LARGE) / x.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 3]; 8] = [
+        // x, e^{-x}I0(x), e^{x}K0(x)
+        [0.1, 0.9071009257823011, 2.6823261022628944],
+        [1.0, 0.46575960759364043, 1.144463079806895],
+        [2.0, 0.30850832255367105, 0.8415682150707714],
+        [3.5, 0.22280243801077917, 0.6490263376886884],
+        [8.0, 0.14343178185685032, 0.4366230186015861],
+        [10.0, 0.1278333371634286, 0.39163193443659866],
+        [30.0, 0.0731459464822373, 0.22788666561625373],
+        [100.0, 0.03994437929909668, 0.12517562165912657],
+    ];
+
+    #[test]
+    fn test_r_i0() {
+        for [x, i0e, _] in KNOWN_VALUES {
+            assert_almost_eq!(r_i0e(x), i0e, PRECISION * i0e);
+            // I0 is even
+            assert_almost_eq!(r_i0e(-x), i0e, PRECISION * i0e);
+            let i0 = i0e * x.exp();
+            assert_almost_eq!(r_i0(x), i0, PRECISION * i0);
+        }
+
+        assert_eq!(r_i0(0.0), 1.0);
+        assert_eq!(r_i0(f64::INFINITY), f64::INFINITY);
+        assert_eq!(r_i0(f64::NEG_INFINITY), f64::INFINITY);
+        assert_eq!(r_i0e(f64::INFINITY), 0.0);
+        assert!(r_i0(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_r_k0() {
+        for [x, _, k0e] in KNOWN_VALUES {
+            assert_almost_eq!(r_k0e(x), k0e, PRECISION * k0e);
+            let k0 = k0e * (-x).exp();
+            assert_almost_eq!(r_k0(x), k0, PRECISION * k0);
+        }
+
+        assert_eq!(r_k0(0.0), f64::INFINITY);
+        assert_eq!(r_k0e(0.0), f64::INFINITY);
+        assert_eq!(r_k0(f64::INFINITY), 0.0);
+        assert!(r_k0(-1.0).is_nan());
+        assert!(r_k0e(-1.0).is_nan());
+        assert!(r_k0(f64::NAN).is_nan());
+    }
+}
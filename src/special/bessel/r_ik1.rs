@@ -0,0 +1,257 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::eval_cheby;
+use num_traits::{cast, Float};
+
+/// Chebyshev coefficients for the modified Bessel functions of order 1, computed with mpmath.
+///
+/// `I1_SMALL` approximates $e^{-x}I_1(x)/x$ in $x$ on $\[0, 8\]$ and `I1_LARGE` approximates $\sqrt{x}e^{-x}I_1(x)$ in $1/x$ on $\[8, \infty)$. `K1_SMALL` approximates $x(K_1(x) - \ln(x/2)I_1(x))$ in $x^2$ on $\[0, 2\]$ and `K1_LARGE` approximates $\sqrt{x}e^xK_1(x)$ in $1/x$ on $\[2, \infty)$. The intervals are those of [cephes].
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/i1.c
+pub(crate) trait BesselIK1Consts: Sized {
+    const I1_SMALL: [Self; 29];
+    const I1_LARGE: [Self; 25];
+    const K1_SMALL: [Self; 11];
+    const K1_LARGE: [Self; 24];
+}
+
+macro_rules! impl_besselik1consts {
+    ($($T: ty)*) => ($(
+        impl BesselIK1Consts for $T {
+            const I1_SMALL: [Self; 29] = [
+                2.7779141127610464e-18,
+                -2.111421214358166e-17,
+                1.5536319577362005e-16,
+                -1.1055969477353862e-15,
+                7.600684294735408e-15,
+                -5.042185504727912e-14,
+                3.223793365945575e-13,
+                -1.9839743977649436e-12,
+                1.1736186298890901e-11,
+                -6.663489723502027e-11,
+                3.625590281552117e-10,
+                -1.8872497517228294e-09,
+                9.381537386495773e-09,
+                -4.445059128796328e-08,
+                2.0032947535521353e-07,
+                -8.568720264695455e-07,
+                3.4702513081376785e-06,
+                -1.3273163656039436e-05,
+                4.781565107550054e-05,
+                -0.00016176081582589674,
+                0.0005122859561685758,
+                -0.0015135724506312532,
+                0.004156422944312888,
+                -0.010564084894626197,
+                0.024726449030626516,
+                -0.05294598120809499,
+                0.1026436586898471,
+                -0.17641651835783406,
+                0.12629359322181682,
+            ];
+            const I1_LARGE: [Self; 25] = [
+                7.517296310842105e-18,
+                4.414348323071708e-18,
+                -4.6503053684893586e-17,
+                -3.209525921993424e-17,
+                2.96262899764595e-16,
+                3.3082023109209285e-16,
+                -1.8803547755107825e-15,
+                -3.8144030724370075e-15,
+                1.0420276984128802e-14,
+                4.272440016711951e-14,
+                -2.1015418427726643e-14,
+                -4.0835511110921974e-13,
+                -7.198551776245908e-13,
+                2.0356285441470896e-12,
+                1.4125807436613782e-11,
+                3.2526035830154884e-11,
+                -1.8974958123505413e-11,
+                -5.589743462196584e-10,
+                -3.835380385964237e-09,
+                -2.6314688468895196e-08,
+                -2.512236237870209e-07,
+                -3.882564808877691e-06,
+                -0.00011058893876262371,
+                -0.009761097491361469,
+                0.38928811750914005,
+            ];
+            const K1_SMALL: [Self; 11] = [
+                -7.023863479386288e-18,
+                -2.427449850519366e-15,
+                -6.666901694199329e-13,
+                -1.4114883926335278e-10,
+                -2.213387630734726e-08,
+                -2.4334061415659684e-06,
+                -0.0001730288957513052,
+                -0.006975723859639864,
+                -0.12261118082265715,
+                -0.3531559607765449,
+                0.7626501136694739,
+            ];
+            const K1_LARGE: [Self; 24] = [
+                1.7940510478863572e-17,
+                -5.689462849193648e-17,
+                1.8380935752430455e-16,
+                -6.057047270643018e-16,
+                2.038703166239861e-15,
+                -7.0198370892147685e-15,
+                2.4771544242195988e-14,
+                -8.976705182010146e-14,
+                3.348419666052243e-13,
+                -1.2891739609498229e-12,
+                5.139639673482343e-12,
+                -2.129967838427791e-11,
+                9.218315187605315e-11,
+                -4.1903547593419254e-10,
+                2.0150497551970347e-09,
+                -1.0345762465678097e-08,
+                5.7410841254500495e-08,
+                -3.5019606030878126e-07,
+                2.406484947837217e-06,
+                -1.936197974166083e-05,
+                0.00019521551847135162,
+                -0.002857816859622779,
+                0.10392373657681724,
+                1.3603130952422213,
+            ];
+        }
+    )*)
+}
+
+impl_besselik1consts! {f32 f64}
+
+/// Exponentially scaled modified Bessel function of the first kind of order 1 implementation.
+///
+/// Follows the structure of [cephes]. For $|x| \leq 8$, $e^{-|x|}I_1(x)/x$ is a Chebyshev expansion in $|x|$, and otherwise $\sqrt{|x|}e^{-|x|}I_1(|x|)$ is a Chebyshev expansion in $1/|x|$. The function is odd.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/i1.c
+pub(crate) fn r_i1e<T>(x: T) -> T
+where
+    T: Float + BesselIK1Consts,
+{
+    let eight = cast::<f64, T>(8.0).unwrap();
+    let z = x.abs();
+    if z <= eight {
+        return x * eval_cheby(z / (eight / (T::one() + T::one())) - T::one(), &T::I1_SMALL);
+    }
+    let sixteen = eight + eight;
+    x.signum() * eval_cheby(sixteen / z - T::one(), &T::I1_LARGE) / z.sqrt()
+}
+
+/// Modified Bessel function of the first kind of order 1 implementation.
+///
+/// Evaluated as $I_1(x) = e^{|x|}\left(e^{-|x|}I_1(x)\right)$, see [r_i1e].
+pub(crate) fn r_i1<T>(x: T) -> T
+where
+    T: Float + BesselIK1Consts,
+{
+    if x.is_infinite() {
+        return x;
+    }
+    x.abs().exp() * r_i1e(x)
+}
+
+/// Modified Bessel function of the second kind of order 1 for $0 < x \leq 2$.
+fn k1_small<T>(x: T) -> T
+where
+    T: Float + BesselIK1Consts,
+{
+    let half = cast::<f64, T>(0.5).unwrap();
+    (x * half).ln() * r_i1(x) + eval_cheby(x * x * half - T::one(), &T::K1_SMALL) / x
+}
+
+/// Modified Bessel function of the second kind of order 1 implementation.
+///
+/// Follows the structure of [cephes]. For $x \leq 2$, we use $K_1(x) = \ln(x/2)I_1(x) + R(x^2)/x$, where $R$ is a Chebyshev expansion. Otherwise, $\sqrt{x}e^xK_1(x)$ is a Chebyshev expansion in $1/x$.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/k1.c
+pub(crate) fn r_k1<T>(x: T) -> T
+where
+    T: Float + BesselIK1Consts,
+{
+    if x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return T::infinity();
+    }
+    if x <= T::one() + T::one() {
+        return k1_small(x);
+    }
+    (-x).exp() * r_k1e(x)
+}
+
+/// Exponentially scaled modified Bessel function of the second kind of order 1 implementation.
+///
+/// Evaluates $e^xK_1(x)$, see [r_k1].
+pub(crate) fn r_k1e<T>(x: T) -> T
+where
+    T: Float + BesselIK1Consts,
+{
+    if x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return T::infinity();
+    }
+    let two = T::one() + T::one();
+    if x <= two {
+        return x.exp() * k1_small(x);
+    }
+    eval_cheby((two + two) / x - T::one(), &T::K1_LARGE) / x.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 3]; 8] = [
+        // x, e^{-x}I1(x), e^{x}K1(x)
+        [0.1, 0.045298446808809324, 10.890182683049696],
+        [1.0, 0.20791041534970844, 1.6361534862632583],
+        [2.0, 0.21526928924893765, 1.0334768470686886],
+        [3.5, 0.18739997660305, 0.7364675480289125],
+        [8.0, 0.13414249329269817, 0.4631490928704961],
+        [10.0, 0.12126268138445552, 0.41076657059578875],
+        [30.0, 0.07191633059864755, 0.2316541293777118],
+        [100.0, 0.03974415302513025, 0.12579995047957854],
+    ];
+
+    #[test]
+    fn test_r_i1() {
+        for [x, i1e, _] in KNOWN_VALUES {
+            assert_almost_eq!(r_i1e(x), i1e, PRECISION * i1e);
+            // I1 is odd
+            assert_almost_eq!(r_i1e(-x), -i1e, PRECISION * i1e);
+            let i1 = i1e * x.exp();
+            assert_almost_eq!(r_i1(x), i1, PRECISION * i1);
+        }
+
+        assert_eq!(r_i1(0.0), 0.0);
+        assert_eq!(r_i1(f64::INFINITY), f64::INFINITY);
+        assert_eq!(r_i1(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(r_i1e(f64::INFINITY), 0.0);
+        assert!(r_i1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_r_k1() {
+        for [x, _, k1e] in KNOWN_VALUES {
+            assert_almost_eq!(r_k1e(x), k1e, PRECISION * k1e);
+            let k1 = k1e * (-x).exp();
+            assert_almost_eq!(r_k1(x), k1, PRECISION * k1);
+        }
+
+        assert_eq!(r_k1(0.0), f64::INFINITY);
+        assert_eq!(r_k1(f64::INFINITY), 0.0);
+        assert!(r_k1(-1.0).is_nan());
+        assert!(r_k1(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,409 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::bessel_util::{sin_pi, temme_gamma, TemmeConsts, MAX_TERM};
+use crate::special::bessel::r_ik0::{r_i0e, r_k0e, BesselIK0Consts};
+use crate::special::bessel::r_ik1::{r_i1e, r_k1e, BesselIK1Consts};
+use crate::special::Gamma;
+use num_traits::{cast, Float, FloatConst};
+
+/// Maximum number of terms for the series and continued fractions.
+const MAX_ITER: usize = 1000000;
+
+/// Power series of $e^{-x}I_\nu(x)$ for $\nu \geq 0$, see [DLMF 10.25.2](https://dlmf.nist.gov/10.25#E2),
+/// $$
+/// I_\nu(x) = \frac{(x/2)^\nu}{\Gamma(\nu+1)}\sum_{k=0}^{\infty}\frac{(x^2/4)^k}{k!(\nu+1)_k}
+/// $$
+/// which is only used for $x^2 \leq \nu + 1$. There, the Wronskian in [bessel_ik] suffers from cancellation as $x \to 0$.
+fn iv_series<T>(nu: T, x: T) -> T
+where
+    T: Float + Gamma,
+{
+    let quarter = cast::<f64, T>(0.25).unwrap();
+    let w = x * x * quarter;
+    let mut term = T::one();
+    let mut sum = term;
+    for k in 1..MAX_ITER {
+        let k = cast::<usize, T>(k).unwrap();
+        term = term * w / (k * (nu + k));
+        sum = sum + term;
+        if term <= T::epsilon() * sum {
+            break;
+        }
+    }
+    let half_x = x * quarter * (T::one() + T::one());
+    let gamma = (nu + T::one()).gamma();
+    let prefactor = if gamma.is_finite() {
+        half_x.powf(nu) / gamma * (-x).exp()
+    } else {
+        (half_x.ln() * nu - x - (nu + T::one()).lgamma()).exp()
+    };
+    prefactor * sum
+}
+
+/// Asymptotic expansion of the scaled modified Bessel functions for large $x$, see [DLMF 10.40](https://dlmf.nist.gov/10.40#i).
+/// $$
+/// e^{-x}I_\nu(x) \sim \frac{1}{\sqrt{2\pi x}}\sum_{k=0}^{\infty}(-1)^k\frac{a_k(\nu)}{x^k}, \quad e^xK_\nu(x) \sim \sqrt{\frac{\pi}{2x}}\sum_{k=0}^{\infty}\frac{a_k(\nu)}{x^k}
+/// $$
+/// Returns `None` if the terms start to increase again before the series has converged, or if its terms grow large enough to cause cancellation.
+fn ik_asymptotic<T>(nu: T, x: T) -> Option<(T, T)>
+where
+    T: Float + FloatConst,
+{
+    let mu = cast::<f64, T>(4.0).unwrap() * nu * nu;
+    let eight_x = cast::<f64, T>(8.0).unwrap() * x;
+    let max_term = cast::<f64, T>(MAX_TERM).unwrap();
+
+    let mut plus = T::one();
+    let mut minus = T::one();
+    let mut term = T::one();
+    let mut converged = false;
+    let mut decreasing = false;
+    for k in 1..MAX_ITER {
+        let previous = term.abs();
+        let odd = cast::<usize, T>(2 * k - 1).unwrap();
+        term = term * (mu - odd * odd) / (cast::<usize, T>(k).unwrap() * eight_x);
+        if (decreasing && term.abs() > previous) || term.abs() > max_term {
+            break;
+        }
+        decreasing = term.abs() < previous;
+        plus = plus + term;
+        minus = if k % 2 == 0 {
+            minus + term
+        } else {
+            minus - term
+        };
+        if term.abs() <= T::epsilon() * plus.abs().min(minus.abs()) {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return None;
+    }
+    Some((
+        minus / (T::TAU() * x).sqrt(),
+        plus * (T::FRAC_PI_2() / x).sqrt(),
+    ))
+}
+
+/// Computes $e^{-x}I_\nu(x)$ and $e^xK_\nu(x)$ for $\nu \geq 0$ and $x > 0$ with the algorithm `bessik` of [Numerical Recipes] (section 6.7).
+///
+/// The ratio $I_\nu'/I_\nu$ is found from its continued fraction and the recurrence is run downwards to $\mu = \nu - n$ with $|\mu| \leq 1/2$. There, $K_\mu$ and $K_{\mu+1}$ are computed with Temme's series for $x \leq 2$ and Steed's method for the second continued fraction otherwise. The Wronskian then fixes $I_\mu$, and $K_\nu$ follows from the forward recurrence.
+///
+/// [Numerical Recipes]: https://numerical.recipes/
+fn bessel_ik<T>(nu: T, x: T) -> (T, T)
+where
+    T: Float + FloatConst + TemmeConsts,
+{
+    let one = T::one();
+    let two = one + one;
+    let half = cast::<f64, T>(0.5).unwrap();
+    let eps = T::epsilon();
+    let tiny = T::min_positive_value() / eps;
+    let big = T::max_value().sqrt();
+
+    let nl = (nu + half).floor().to_usize().unwrap();
+    let mu = nu - cast::<usize, T>(nl).unwrap();
+    let mu2 = mu * mu;
+    let xi = x.recip();
+    let xi2 = two * xi;
+
+    // First continued fraction, I'_nu / I_nu
+    let mut h = (nu * xi).max(tiny);
+    let mut b = xi2 * nu;
+    let mut d = T::zero();
+    let mut c = h;
+    for _ in 0..MAX_ITER {
+        b = b + xi2;
+        d = (b + d).recip();
+        c = b + c.recip();
+        let delta = c * d;
+        h = delta * h;
+        if (delta - one).abs() < eps {
+            break;
+        }
+    }
+
+    // Downward recurrence from nu to mu
+    let mut ril = one;
+    let mut ripl = h;
+    let mut ril1 = ril;
+    let mut fact = nu * xi;
+    for _ in 0..nl {
+        let ritemp = fact * ril + ripl;
+        fact = fact - xi;
+        ripl = fact * ritemp + ril;
+        ril = ritemp;
+        if ril > big {
+            ril = ril / big;
+            ripl = ripl / big;
+            ril1 = ril1 / big;
+        }
+    }
+    let f = ripl / ril;
+
+    let (mut rkmu, mut rk1) = if x <= two {
+        // Temme's series
+        let x2 = half * x;
+        let pimu = T::PI() * mu;
+        let fact = if pimu.abs() < eps {
+            one
+        } else {
+            pimu / pimu.sin()
+        };
+        let d = -x2.ln();
+        let e = mu * d;
+        let fact2 = if e.abs() < eps { one } else { e.sinh() / e };
+        let (gam1, gam2, gampl, gammi) = temme_gamma(mu);
+        let mut ff = fact * (gam1 * e.cosh() + gam2 * fact2 * d);
+        let mut sum = ff;
+        let e = e.exp();
+        let mut p = half * e / gampl;
+        let mut q = half / (e * gammi);
+        let mut c = one;
+        let d = x2 * x2;
+        let mut sum1 = p;
+        for i in 1..MAX_ITER {
+            let i = cast::<usize, T>(i).unwrap();
+            ff = (i * ff + p + q) / (i * i - mu2);
+            c = c * d / i;
+            p = p / (i - mu);
+            q = q / (i + mu);
+            let delta = c * ff;
+            sum = sum + delta;
+            sum1 = sum1 + c * (p - i * ff);
+            if delta.abs() < sum.abs() * eps {
+                break;
+            }
+        }
+        let scale = x.exp();
+        (sum * scale, sum1 * xi2 * scale)
+    } else {
+        // Steed's method for the second continued fraction
+        let mut b = two * (one + x);
+        let mut d = b.recip();
+        let mut h = d;
+        let mut delh = d;
+        let mut q1 = T::zero();
+        let mut q2 = one;
+        let a1 = cast::<f64, T>(0.25).unwrap() - mu2;
+        let mut q = a1;
+        let mut c = a1;
+        let mut a = -a1;
+        let mut s = one + q * delh;
+        for i in 1..MAX_ITER {
+            a = a - cast::<usize, T>(2 * i).unwrap();
+            c = -a * c / cast::<usize, T>(i + 1).unwrap();
+            let qnew = (q1 - b * q2) / a;
+            q1 = q2;
+            q2 = qnew;
+            q = q + c * qnew;
+            b = b + two;
+            d = (b + a * d).recip();
+            delh = (b * d - one) * delh;
+            h = h + delh;
+            let dels = q * delh;
+            s = s + dels;
+            if (dels / s).abs() < eps {
+                break;
+            }
+        }
+        let rkmu = (T::FRAC_PI_2() * xi).sqrt() / s;
+        let rk1 = rkmu * (mu + x + half - a1 * h) * xi;
+        (rkmu, rk1)
+    };
+
+    let rkmup = mu * xi * rkmu - rk1;
+    let rimu = xi / (f * rkmu - rkmup);
+    let ri = rimu * ril1 / ril;
+    for i in 1..=nl {
+        let rktemp = (mu + cast::<usize, T>(i).unwrap()) * xi2 * rk1 + rkmu;
+        rkmu = rk1;
+        rk1 = rktemp;
+    }
+    (ri, rkmu)
+}
+
+/// Computes $e^{-x}I_\nu(x)$ and $e^xK_\nu(x)$ for $\nu \geq 0$ and $x > 0$.
+///
+/// Orders 0 and 1 are delegated to the Chebyshev expansions. The asymptotic expansion is attempted for $x \geq \max(25, \nu)$, and [bessel_ik] is used otherwise or if it fails. For $x^2 \leq \nu + 1$, $I_\nu$ is replaced by its power series.
+pub(crate) fn ik_positive<T>(nu: T, x: T) -> (T, T)
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if nu.is_zero() {
+        return (r_i0e(x), r_k0e(x));
+    }
+    if nu.is_one() {
+        return (r_i1e(x), r_k1e(x));
+    }
+    if x >= cast::<f64, T>(25.0).unwrap() && x >= nu {
+        if let Some(result) = ik_asymptotic(nu, x) {
+            return result;
+        }
+    }
+    let (ive, kve) = bessel_ik(nu, x);
+    if x * x <= nu + T::one() {
+        return (iv_series(nu, x), kve);
+    }
+    (ive, kve)
+}
+
+/// Exponentially scaled modified Bessel function of the first kind of real order implementation.
+///
+/// Evaluates $e^{-|x|}I_\nu(x)$. For integer orders, $I_\nu(-x) = (-1)^\nu I_\nu(x)$, and otherwise $I_\nu(x)$ is complex for $x < 0$ and NaN is returned. Negative orders use the reflection formula, [DLMF 10.27.2](https://dlmf.nist.gov/10.27#E2),
+/// $$
+/// I_{-\nu}(x) = I_\nu(x) + \frac{2}{\pi}\sin(\nu\pi)K_\nu(x)
+/// $$
+pub(crate) fn r_ive<T>(nu: T, x: T) -> T
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if nu.is_nan() || x.is_nan() {
+        return T::nan();
+    }
+    let integer = nu == nu.floor();
+    if x < T::zero() {
+        if !integer {
+            return T::nan();
+        }
+        let odd = (nu % (T::one() + T::one())).abs().is_one();
+        return if odd { -r_ive(nu, -x) } else { r_ive(nu, -x) };
+    }
+    if x.is_zero() {
+        return if nu.is_zero() {
+            T::one()
+        } else if nu > T::zero() || integer {
+            T::zero()
+        } else {
+            sin_pi(-nu).signum() * T::infinity()
+        };
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+
+    let (ive, kve) = ik_positive(nu.abs(), x);
+    if nu < T::zero() && !integer {
+        return ive + T::FRAC_2_PI() * sin_pi(-nu) * (-x - x).exp() * kve;
+    }
+    ive
+}
+
+/// Modified Bessel function of the first kind of real order implementation.
+///
+/// Evaluated as $I_\nu(x) = e^{|x|}\left(e^{-|x|}I_\nu(x)\right)$, see [r_ive].
+pub(crate) fn r_iv<T>(nu: T, x: T) -> T
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if x.is_infinite() && !nu.is_nan() {
+        return r_ive(nu, x.signum()).signum() * T::infinity();
+    }
+    let ive = r_ive(nu, x);
+    if ive.is_zero() || ive.is_infinite() {
+        return ive;
+    }
+    ive * x.abs().exp()
+}
+
+/// Exponentially scaled modified Bessel function of the second kind of real order implementation.
+///
+/// Evaluates $e^xK_\nu(x)$ for $x \geq 0$, using $K_{-\nu}(x) = K_\nu(x)$. $K_\nu(x)$ is complex for $x < 0$, in which case NaN is returned.
+pub(crate) fn r_kve<T>(nu: T, x: T) -> T
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    if nu.is_nan() || x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return T::infinity();
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+    ik_positive(nu.abs(), x).1
+}
+
+/// Modified Bessel function of the second kind of real order implementation.
+///
+/// Evaluated as $K_\nu(x) = e^{-x}\left(e^xK_\nu(x)\right)$, see [r_kve].
+pub(crate) fn r_kv<T>(nu: T, x: T) -> T
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselIK0Consts + BesselIK1Consts,
+{
+    let kve = r_kve(nu, x);
+    if kve.is_infinite() {
+        return kve;
+    }
+    kve * (-x).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::special::bessel::r_ik0::r_i0;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 4]; 12] = [
+        // nu, x, e^{-x}Iv(x), e^{x}Kv(x)
+        [0.3, 1e-08, 0.0036030535747396753, 462.56360781470244],
+        [0.5, 0.1, 0.2286831660755234, 3.963327297606011],
+        [2.5, 1.5, 0.038302974502277876, 4.434415734434784],
+        [-2.5, 1.5, 0.17885369745457544, 4.434415734434784],
+        [7.7, 3.0, 6.838201517641654e-05, 884.0979380090674],
+        [-0.3, 5.0, 0.18168207412986537, 0.5523447022332711],
+        [10.0, 20.0, 0.0072968964849783255, 3.0644074558832957],
+        [2.5, 40.0, 0.058465711408685894, 0.21340040418047365],
+        [45.3, 8.5, 2.3313320139968543e-32, 4.65318729658901e+29],
+        [100.0, 10.0, 4.913835073824649e-93, 1.0124848448703725e+90],
+        [30.0, 30.0, 5.020454657688059e-08, 234729.72396020615],
+        [-3.0, 2.0, 0.028791222639470898, 4.783566971347609],
+    ];
+
+    #[test]
+    fn test_r_iv() {
+        for [nu, x, ive, _] in KNOWN_VALUES {
+            assert_almost_eq!(r_ive(nu, x), ive, PRECISION * ive);
+            let iv = ive * x.exp();
+            assert_almost_eq!(r_iv(nu, x), iv, PRECISION * iv);
+        }
+
+        // Integer orders
+        assert_eq!(r_iv(0.0, 2.5), r_i0(2.5));
+        assert_eq!(r_iv(-3.0, -2.0), -r_iv(3.0, 2.0));
+        assert_eq!(r_iv(2.0, -2.0), r_iv(2.0, 2.0));
+
+        assert_eq!(r_iv(0.5, 0.0), 0.0);
+        assert_eq!(r_iv(-0.5, 0.0), f64::INFINITY);
+        assert_eq!(r_iv(-1.5, 0.0), f64::NEG_INFINITY);
+        assert_eq!(r_iv(0.5, f64::INFINITY), f64::INFINITY);
+        assert_eq!(r_ive(0.5, f64::INFINITY), 0.0);
+        assert_eq!(r_iv(2.5, 1000.0), f64::INFINITY);
+        assert!(r_iv(0.5, -1.0).is_nan());
+        assert!(r_iv(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_r_kv() {
+        for [nu, x, _, kve] in KNOWN_VALUES {
+            assert_almost_eq!(r_kve(nu, x), kve, PRECISION * kve);
+            // Kv is even in the order
+            assert_eq!(r_kve(-nu, x), r_kve(nu, x));
+            let kv = kve * (-x).exp();
+            assert_almost_eq!(r_kv(nu, x), kv, PRECISION * kv);
+        }
+
+        assert_eq!(r_kv(0.5, 0.0), f64::INFINITY);
+        assert_eq!(r_kv(0.5, f64::INFINITY), 0.0);
+        assert_eq!(r_kv(2.5, 1000.0), 0.0);
+        assert!(r_kv(0.5, -1.0).is_nan());
+        assert!(r_kv(f64::NAN, 1.0).is_nan());
+    }
+}
@@ -0,0 +1,231 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::eval_cheby;
+use num_traits::{cast, Float, FloatConst};
+
+/// Chebyshev coefficients for the Bessel functions of order 0, computed with mpmath.
+///
+/// For $x \leq 5$, `J0_SMALL` and `Y0_SMALL` approximate $J_0(x)/(x^2 - j_{0,1}^2)$ and $Y_0(x) - 2J_0(x)\ln(x)/\pi$ in $x^2$, where $j_{0,1}$ is the first zero of $J_0$. For $x > 5$, `P0` and `Q0` approximate the modulus-phase functions $P_0(x)$ and $xQ_0(x)$ in $1/x^2$.
+pub(crate) trait BesselJY0Consts: Sized {
+    const J0_SMALL: [Self; 13];
+    const Y0_SMALL: [Self; 15];
+    const P0: [Self; 21];
+    const Q0: [Self; 25];
+}
+
+macro_rules! impl_besseljy0consts {
+    ($($T: ty)*) => ($(
+        impl BesselJY0Consts for $T {
+            const J0_SMALL: [Self; 13] = [
+                -2.2014527429358544e-18,
+                2.3433615705472256e-16,
+                -2.119981793307844e-14,
+                1.606362191368658e-12,
+                -1.0017563061986844e-10,
+                5.032513126170568e-09,
+                -1.9827937012047592e-07,
+                5.919230226082256e-06,
+                -0.00012786334214099545,
+                0.0018734993201736279,
+                -0.01689860151045831,
+                0.07995724165008089,
+                -0.07405174056387834,
+            ];
+            const Y0_SMALL: [Self; 15] = [
+                -2.356607585530683e-19,
+                2.848154182311252e-17,
+                -2.9540707750193604e-15,
+                2.5954970583106197e-13,
+                -1.9021689490013906e-11,
+                1.141220652090372e-09,
+                -5.476431029755533e-08,
+                2.0406703657627165e-06,
+                -5.677525113380376e-05,
+                0.0011164629864165486,
+                -0.014279159901781987,
+                0.10268287966073635,
+                -0.291894720865317,
+                -0.13017522381095709,
+                0.20605257634092228,
+            ];
+            const P0: [Self; 21] = [
+                1.2418028891673288e-18,
+                -3.629905673795297e-18,
+                1.0942187869810236e-17,
+                -3.410936210498114e-17,
+                1.103023177871277e-16,
+                -3.714050821413154e-16,
+                1.3078555195901338e-15,
+                -4.841483019175697e-15,
+                1.8959456362961828e-14,
+                -7.914641501338115e-14,
+                3.5557750052411783e-13,
+                -1.7401405706283885e-12,
+                9.423105578391987e-12,
+                -5.765747662655223e-11,
+                4.103246366872386e-10,
+                -3.5409678948019087e-09,
+                3.948825587093808e-08,
+                -6.319367118733069e-07,
+                1.761305551290559e-05,
+                -0.00132937162125028,
+                0.9986523398776954,
+            ];
+            const Q0: [Self; 25] = [
+                -2.2743642238250536e-19,
+                5.8629592355191775e-19,
+                -1.544369143900366e-18,
+                4.16320426537859e-18,
+                -1.150518178532616e-17,
+                3.2658161943648366e-17,
+                -9.542905011731343e-17,
+                2.877768906557925e-16,
+                -8.982151171303344e-16,
+                2.9115274918592e-15,
+                -9.84005757254373e-15,
+                3.4837635776889786e-14,
+                -1.2993242656059178e-13,
+                5.140127162427605e-13,
+                -2.1751206043008833e-12,
+                9.950713667806645e-12,
+                -4.988908027652833e-11,
+                2.79085713479036e-10,
+                -1.785075905119705e-09,
+                1.3513032763134409e-08,
+                -1.2743289742032805e-07,
+                1.6237093205642789e-06,
+                -3.218799121266175e-05,
+                0.0013190194049922607,
+                -0.12364702582167493,
+            ];
+        }
+    )*)
+}
+
+impl_besseljy0consts! {f32 f64}
+
+/// First zero of $J_0(x)$, split into a high and low part.
+const J0_ZERO: [f64; 2] = [2.404825557695773, -1.176691651530894e-16];
+
+/// Modulus-phase form of $J_0(x)$ and $Y_0(x)$ for $x > 5$,
+/// $$
+/// J_0(x) = \sqrt{\frac{2}{\pi x}}\left(P_0(x)\cos\xi - Q_0(x)\sin\xi\right), \quad Y_0(x) = \sqrt{\frac{2}{\pi x}}\left(P_0(x)\sin\xi + Q_0(x)\cos\xi\right)
+/// $$
+/// with $\xi = x - \pi/4$.
+fn j0y0_modulus_phase<T>(x: T) -> (T, T)
+where
+    T: Float + FloatConst + BesselJY0Consts,
+{
+    let t = cast::<f64, T>(50.0).unwrap() / (x * x) - T::one();
+    let p = eval_cheby(t, &T::P0);
+    let q = eval_cheby(t, &T::Q0) / x;
+
+    let (sin, cos) = x.sin_cos();
+    let cos_xi = (cos + sin) * T::FRAC_1_SQRT_2();
+    let sin_xi = (sin - cos) * T::FRAC_1_SQRT_2();
+    let amplitude = (T::FRAC_2_PI() / x).sqrt();
+    (
+        amplitude * (p * cos_xi - q * sin_xi),
+        amplitude * (p * sin_xi + q * cos_xi),
+    )
+}
+
+/// Bessel function of the first kind of order 0 implementation.
+///
+/// Follows the structure of [cephes]. For $|x| \leq 5$, the first zero $j_{0,1}$ is factored out, $J_0(x) = (x^2 - j_{0,1}^2)R(x^2)$, where $R$ is a Chebyshev expansion. Otherwise, the modulus-phase form is used.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/j0.c
+pub(crate) fn r_j0<T>(x: T) -> T
+where
+    T: Float + FloatConst + BesselJY0Consts,
+{
+    let x = x.abs();
+    if x.is_infinite() {
+        return T::zero();
+    }
+    if x <= cast::<f64, T>(5.0).unwrap() {
+        let z = x * x;
+        let t = z / cast::<f64, T>(12.5).unwrap() - T::one();
+        let zero = cast::<f64, T>(J0_ZERO[0]).unwrap();
+        let factor = ((x - zero) - cast::<f64, T>(J0_ZERO[1]).unwrap()) * (x + zero);
+        return factor * eval_cheby(t, &T::J0_SMALL);
+    }
+    j0y0_modulus_phase(x).0
+}
+
+/// Bessel function of the second kind of order 0 implementation.
+///
+/// Follows the structure of [cephes]. For $x \leq 5$, we use $Y_0(x) = 2J_0(x)\ln(x)/\pi + R(x^2)$, where $R$ is a Chebyshev expansion. Otherwise, the modulus-phase form is used.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/j0.c
+pub(crate) fn r_y0<T>(x: T) -> T
+where
+    T: Float + FloatConst + BesselJY0Consts,
+{
+    if x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return T::neg_infinity();
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+    if x <= cast::<f64, T>(5.0).unwrap() {
+        let t = x * x / cast::<f64, T>(12.5).unwrap() - T::one();
+        return eval_cheby(t, &T::Y0_SMALL) + T::FRAC_2_PI() * x.ln() * r_j0(x);
+    }
+    j0y0_modulus_phase(x).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 3]; 10] = [
+        // x, J0(x), Y0(x)
+        [1e-08, 1.0, -11.80077387717953],
+        [0.1, 0.99750156206604, -1.5342386513503667],
+        [1.0, 0.7651976865579666, 0.08825696421567696],
+        [4.9, -0.2097383275853262, -0.2920545942440142],
+        [5.0, -0.1775967713143383, -0.30851762524903376],
+        [5.1, -0.14433474706050065, -0.3216024491248594],
+        [10.0, -0.24593576445134835, 0.055671167283599395],
+        [31.5, 0.10823892671147262, -0.09215174743370735],
+        [100.0, 0.019985850304223122, -0.07724431336508315],
+        [10000.0, -0.0070961603533888015, 0.0036478055589866058],
+    ];
+
+    #[test]
+    fn test_r_j0() {
+        for [x, j0, _] in KNOWN_VALUES {
+            assert_almost_eq!(r_j0(x), j0, PRECISION * j0.abs());
+            // J0 is even
+            assert_almost_eq!(r_j0(-x), j0, PRECISION * j0.abs());
+        }
+        // First zero is captured to absolute precision
+        assert_almost_eq!(r_j0(2.404825557695773), -6.10876525973673e-17, 1e-30);
+
+        assert_eq!(r_j0(0.0), 1.0);
+        assert_eq!(r_j0(f64::INFINITY), 0.0);
+        assert!(r_j0(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_r_y0() {
+        for [x, _, y0] in KNOWN_VALUES {
+            assert_almost_eq!(r_y0(x), y0, PRECISION * y0.abs());
+        }
+
+        assert_eq!(r_y0(0.0), f64::NEG_INFINITY);
+        assert_eq!(r_y0(f64::INFINITY), 0.0);
+        assert!(r_y0(-1.0).is_nan());
+        assert!(r_y0(f64::NAN).is_nan());
+    }
+}
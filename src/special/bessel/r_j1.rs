@@ -0,0 +1,230 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::eval_cheby;
+use num_traits::{cast, Float, FloatConst};
+
+/// Chebyshev coefficients for the Bessel functions of order 1, computed with mpmath.
+///
+/// For $x \leq 5$, `J1_SMALL` and `Y1_SMALL` approximate $J_1(x)/(x(x^2 - j_{1,1}^2))$ and $(Y_1(x) - 2(J_1(x)\ln x - 1/x)/\pi)/x$ in $x^2$, where $j_{1,1}$ is the first zero of $J_1$. For $x > 5$, `P1` and `Q1` approximate the modulus-phase functions $P_1(x)$ and $xQ_1(x)$ in $1/x^2$.
+pub(crate) trait BesselJY1Consts: Sized {
+    const J1_SMALL: [Self; 13];
+    const Y1_SMALL: [Self; 14];
+    const P1: [Self; 21];
+    const Q1: [Self; 24];
+}
+
+macro_rules! impl_besseljy1consts {
+    ($($T: ty)*) => ($(
+        impl BesselJY1Consts for $T {
+            const J1_SMALL: [Self; 13] = [
+                -7.891777052481785e-20,
+                9.051454724528835e-18,
+                -8.876744102278278e-16,
+                7.34347631506432e-14,
+                -5.042568567278396e-12,
+                2.8183488843903415e-10,
+                -1.251337564352681e-08,
+                4.278886056259686e-07,
+                -1.0816174736957452e-05,
+                0.0001909490103660189,
+                -0.0021632651559238044,
+                0.013661487082063361,
+                -0.018028415801166292,
+            ];
+            const Y1_SMALL: [Self; 14] = [
+                1.0444110354608508e-18,
+                -1.1707921590181557e-16,
+                1.1194186560306676e-14,
+                -9.000965667133812e-13,
+                5.984534092223177e-11,
+                -3.222883768023259e-09,
+                1.3699277670028168e-07,
+                -4.44507526565354e-06,
+                0.000105257530278434,
+                -0.0017057852565133666,
+                0.017111109934210778,
+                -0.08834328868289824,
+                0.14189450894667655,
+                0.0531074450560213,
+            ];
+            const P1: [Self; 21] = [
+                -1.3090769675043101e-18,
+                3.832473906744907e-18,
+                -1.1572356053251518e-17,
+                3.614078564738109e-17,
+                -1.171117080126803e-16,
+                3.9523601713314257e-16,
+                -1.3953502148258493e-15,
+                5.180421738900549e-15,
+                -2.03544697865723e-14,
+                8.529914525779044e-14,
+                -3.849703387824105e-13,
+                1.8943274995111034e-12,
+                -1.0327344567640412e-11,
+                6.373158962859246e-11,
+                -4.586773977170972e-10,
+                4.020515478419001e-09,
+                -4.589685232343401e-08,
+                7.639181732533905e-07,
+                -2.3071018862548286e-05,
+                0.0022437352958079985,
+                1.00226762068534,
+            ];
+            const Q1: [Self; 24] = [
+                -6.1484941486086215e-19,
+                1.6214444005747313e-18,
+                -4.376388902428328e-18,
+                1.2110503970221687e-17,
+                -3.4426306619813246e-17,
+                1.0075487579997493e-16,
+                -3.0436450667050517e-16,
+                9.518051831081499e-16,
+                -3.091784202917912e-15,
+                1.0474063117829167e-14,
+                -3.7181516752164614e-14,
+                1.390972848139383e-13,
+                -5.522046856541826e-13,
+                2.346352831758894e-12,
+                -1.0786652192538468e-11,
+                5.440233592059753e-11,
+                -3.065981449437696e-10,
+                1.979922260589517e-09,
+                -1.5183398644673165e-08,
+                1.458816510742199e-07,
+                -1.914402839085632e-06,
+                4.0056994520460306e-05,
+                -0.0018705189681051477,
+                0.37308734621468637,
+            ];
+        }
+    )*)
+}
+
+impl_besseljy1consts! {f32 f64}
+
+/// First zero of $J_1(x)$, split into a high and low part.
+const J1_ZERO: [f64; 2] = [3.8317059702075125, -1.5269184090088067e-16];
+
+/// Modulus-phase form of $J_1(x)$ and $Y_1(x)$ for $x > 5$,
+/// $$
+/// J_1(x) = \sqrt{\frac{2}{\pi x}}\left(P_1(x)\cos\xi - Q_1(x)\sin\xi\right), \quad Y_1(x) = \sqrt{\frac{2}{\pi x}}\left(P_1(x)\sin\xi + Q_1(x)\cos\xi\right)
+/// $$
+/// with $\xi = x - 3\pi/4$.
+fn j1y1_modulus_phase<T>(x: T) -> (T, T)
+where
+    T: Float + FloatConst + BesselJY1Consts,
+{
+    let t = cast::<f64, T>(50.0).unwrap() / (x * x) - T::one();
+    let p = eval_cheby(t, &T::P1);
+    let q = eval_cheby(t, &T::Q1) / x;
+
+    let (sin, cos) = x.sin_cos();
+    let cos_xi = (sin - cos) * T::FRAC_1_SQRT_2();
+    let sin_xi = -(sin + cos) * T::FRAC_1_SQRT_2();
+    let amplitude = (T::FRAC_2_PI() / x).sqrt();
+    (
+        amplitude * (p * cos_xi - q * sin_xi),
+        amplitude * (p * sin_xi + q * cos_xi),
+    )
+}
+
+/// Bessel function of the first kind of order 1 implementation.
+///
+/// Follows the structure of [cephes]. For $|x| \leq 5$, the first zero $j_{1,1}$ is factored out, $J_1(x) = x(x^2 - j_{1,1}^2)R(x^2)$, where $R$ is a Chebyshev expansion. Otherwise, the modulus-phase form is used.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/j1.c
+pub(crate) fn r_j1<T>(x: T) -> T
+where
+    T: Float + FloatConst + BesselJY1Consts,
+{
+    let sign = x.signum();
+    let x = x.abs();
+    if x.is_infinite() {
+        return T::zero();
+    }
+    if x <= cast::<f64, T>(5.0).unwrap() {
+        let z = x * x;
+        let t = z / cast::<f64, T>(12.5).unwrap() - T::one();
+        let zero = cast::<f64, T>(J1_ZERO[0]).unwrap();
+        let factor = ((x - zero) - cast::<f64, T>(J1_ZERO[1]).unwrap()) * (x + zero);
+        return sign * x * factor * eval_cheby(t, &T::J1_SMALL);
+    }
+    sign * j1y1_modulus_phase(x).0
+}
+
+/// Bessel function of the second kind of order 1 implementation.
+///
+/// Follows the structure of [cephes]. For $x \leq 5$, we use $Y_1(x) = 2(J_1(x)\ln x - 1/x)/\pi + xR(x^2)$, where $R$ is a Chebyshev expansion. Otherwise, the modulus-phase form is used.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/j1.c
+pub(crate) fn r_y1<T>(x: T) -> T
+where
+    T: Float + FloatConst + BesselJY1Consts,
+{
+    if x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return T::neg_infinity();
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+    if x <= cast::<f64, T>(5.0).unwrap() {
+        let t = x * x / cast::<f64, T>(12.5).unwrap() - T::one();
+        return x * eval_cheby(t, &T::Y1_SMALL) + T::FRAC_2_PI() * (r_j1(x) * x.ln() - x.recip());
+    }
+    j1y1_modulus_phase(x).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 3]; 10] = [
+        // x, J1(x), Y1(x)
+        [1e-08, 5e-09, -63661977.236758195],
+        [0.1, 0.049937526036242, -6.4589510947020266],
+        [1.0, 0.4400505857449335, -0.7812128213002887],
+        [4.9, -0.31469467101519066, 0.18124669204504856],
+        [5.0, -0.32757913759146523, 0.14786314339122683],
+        [5.1, -0.3370972020182318, 0.11373644197749973],
+        [10.0, 0.04347274616886144, 0.24901542420695388],
+        [31.5, -0.09044569145442247, -0.109714899705293],
+        [100.0, -0.07714535201411216, -0.020372312002759792],
+        [10000.0, 0.0036474507555295803, 0.007096342752536495],
+    ];
+
+    #[test]
+    fn test_r_j1() {
+        for [x, j1, _] in KNOWN_VALUES {
+            assert_almost_eq!(r_j1(x), j1, PRECISION * j1.abs());
+            // J1 is odd
+            assert_almost_eq!(r_j1(-x), -j1, PRECISION * j1.abs());
+        }
+        // First zero is captured to absolute precision
+        assert_almost_eq!(r_j1(3.8317059702075125), -6.149807356994906e-17, 1e-30);
+
+        assert_eq!(r_j1(0.0), 0.0);
+        assert_eq!(r_j1(f64::INFINITY), 0.0);
+        assert!(r_j1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_r_y1() {
+        for [x, _, y1] in KNOWN_VALUES {
+            assert_almost_eq!(r_y1(x), y1, PRECISION * y1.abs());
+        }
+
+        assert_eq!(r_y1(0.0), f64::NEG_INFINITY);
+        assert_eq!(r_y1(f64::INFINITY), 0.0);
+        assert!(r_y1(-1.0).is_nan());
+        assert!(r_y1(f64::NAN).is_nan());
+    }
+}
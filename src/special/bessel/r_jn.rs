@@ -0,0 +1,212 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::r_j0::{r_j0, r_y0, BesselJY0Consts};
+use crate::special::bessel::r_j1::{r_j1, r_y1, BesselJY1Consts};
+use num_traits::{cast, Float, FloatConst};
+
+/// Maximum number of terms for the continued fraction.
+const MAX_ITER: usize = 100000;
+
+/// Continued fraction for the ratio $J_n(x)/J_{n-1}(x)$ with $x > 0$, see [DLMF 10.10.1](https://dlmf.nist.gov/10.10#E1),
+/// $$
+/// \frac{J_n(x)}{J_{n-1}(x)} = \frac{1}{2n/x-}\frac{1}{2(n+1)/x-}\cdots
+/// $$
+/// evaluated with the modified Lentz algorithm.
+fn jn_ratio<T>(n: u32, x: T) -> T
+where
+    T: Float,
+{
+    let tiny = T::min_positive_value() / T::epsilon();
+    let two_over_x = (T::one() + T::one()) / x;
+
+    let mut b = cast::<u32, T>(n).unwrap() * two_over_x;
+    let mut c = b;
+    let mut d = T::zero();
+    let mut h = if b.is_zero() { tiny } else { b };
+    for _ in 1..MAX_ITER {
+        b = b + two_over_x;
+        d = b - d;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = b - c.recip();
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = d.recip();
+        let delta = c * d;
+        h = h * delta;
+        if (delta - T::one()).abs() < T::epsilon() {
+            break;
+        }
+    }
+    h.recip()
+}
+
+/// Bessel function of the first kind of integer order implementation.
+///
+/// Negative orders and arguments are reduced with $J_{-n}(x) = J_n(-x) = (-1)^nJ_n(x)$. The forward recurrence
+/// $$
+/// J_{k+1}(x) = \frac{2k}{x}J_k(x) - J_{k-1}(x)
+/// $$
+/// is stable for $n \leq x$. Otherwise, the ratio $J_n(x)/J_{n-1}(x)$ is obtained from its continued fraction, and the recurrence is run backwards down to $J_0(x)$ and $J_1(x)$ to fix the normalization.
+pub(crate) fn r_jn<T>(n: i32, x: T) -> T
+where
+    T: Float + FloatConst + BesselJY0Consts + BesselJY1Consts,
+{
+    if x.is_nan() {
+        return x;
+    }
+    let n_abs = n.unsigned_abs();
+    let odd = n_abs % 2 == 1;
+    let sign = if odd && ((n < 0) != (x < T::zero())) {
+        -T::one()
+    } else {
+        T::one()
+    };
+    let x = x.abs();
+
+    match n_abs {
+        0 => return r_j0(x),
+        1 => return sign * r_j1(x),
+        _ => {}
+    }
+    if x.is_zero() || x.is_infinite() {
+        return sign * T::zero();
+    }
+
+    let two_over_x = (T::one() + T::one()) / x;
+    let nf = cast::<u32, T>(n_abs).unwrap();
+    if nf <= x {
+        let mut previous = r_j0(x);
+        let mut current = r_j1(x);
+        for k in 1..n_abs {
+            let next = cast::<u32, T>(k).unwrap() * two_over_x * current - previous;
+            previous = current;
+            current = next;
+        }
+        return sign * current;
+    }
+
+    // Unnormalized backward recurrence, J_n = 1
+    let big = T::max_value().sqrt();
+    let mut result = T::one();
+    let mut current = T::one();
+    let mut previous = jn_ratio(n_abs, x).recip();
+    for k in (1..n_abs).rev() {
+        let next = cast::<u32, T>(k).unwrap() * two_over_x * previous - current;
+        current = previous;
+        previous = next;
+        if previous.abs() > big {
+            result = result / big;
+            current = current / big;
+            previous = previous / big;
+        }
+    }
+    // previous = J_0, current = J_1 (up to normalization)
+    let scale = if previous.abs() > current.abs() {
+        r_j0(x) / previous
+    } else {
+        r_j1(x) / current
+    };
+    sign * result * scale
+}
+
+/// Bessel function of the second kind of integer order implementation.
+///
+/// Negative orders are reduced with $Y_{-n}(x) = (-1)^nY_n(x)$, and the forward recurrence from $Y_0(x)$ and $Y_1(x)$ is used, which is stable for all orders.
+pub(crate) fn r_yn<T>(n: i32, x: T) -> T
+where
+    T: Float + FloatConst + BesselJY0Consts + BesselJY1Consts,
+{
+    let n_abs = n.unsigned_abs();
+    let sign = if n < 0 && n_abs % 2 == 1 {
+        -T::one()
+    } else {
+        T::one()
+    };
+    match n_abs {
+        0 => return r_y0(x),
+        1 => return sign * r_y1(x),
+        _ => {}
+    }
+    if x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return sign * T::neg_infinity();
+    }
+    if x.is_infinite() {
+        return sign * T::zero();
+    }
+
+    let two_over_x = (T::one() + T::one()) / x;
+    let mut previous = r_y0(x);
+    let mut current = r_y1(x);
+    for k in 1..n_abs {
+        let next = cast::<u32, T>(k).unwrap() * two_over_x * current - previous;
+        previous = current;
+        current = next;
+        if current.is_infinite() {
+            break;
+        }
+    }
+    sign * current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [(i32, f64, f64, f64); 11] = [
+        // n, x, Jn(x), Yn(x)
+        (2, 1e-05, 1.2499999999895835e-11, -12732395447.669935),
+        (2, 0.5, 0.03060402345868264, -5.441370837174266),
+        (3, 2.5, 0.21660039103911352, -0.756055496753671),
+        (5, 1.0, 0.00024975773021123444, -260.4058666258122),
+        (5, 10.0, -0.23406152818679363, 0.13540304768936232),
+        (10, 3.0, 1.2928351645715883e-05, -2582.6071294842995),
+        (10, 30.0, -0.12987689399858876, 0.07505670212239711),
+        (20, 15.0, 0.007360234079223486, -3.3087330924737643),
+        (50, 1.0, 2.9060049481732392e-80, -2.191142812605339e+77),
+        (50, 60.0, -0.13798273148535212, 0.008641769962674491),
+        (100, 120.0, 0.0757371791300107, 0.06205259095687714),
+    ];
+
+    #[test]
+    fn test_r_jn() {
+        for (n, x, jn, _) in KNOWN_VALUES {
+            assert_almost_eq!(r_jn(n, x), jn, PRECISION * jn.abs());
+
+            let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+            assert_almost_eq!(r_jn(-n, x), sign * jn, PRECISION * jn.abs());
+            assert_almost_eq!(r_jn(n, -x), sign * jn, PRECISION * jn.abs());
+        }
+
+        assert_eq!(r_jn(0, 0.0), 1.0);
+        assert_eq!(r_jn(4, 0.0), 0.0);
+        assert_eq!(r_jn(4, f64::INFINITY), 0.0);
+        assert!(r_jn(4, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_r_yn() {
+        for (n, x, _, yn) in KNOWN_VALUES {
+            assert_almost_eq!(r_yn(n, x), yn, PRECISION * yn.abs());
+
+            let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+            assert_almost_eq!(r_yn(-n, x), sign * yn, PRECISION * yn.abs());
+        }
+
+        assert_eq!(r_yn(4, 0.0), f64::NEG_INFINITY);
+        assert_eq!(r_yn(-3, 0.0), f64::INFINITY);
+        assert_eq!(r_yn(200, 1.0), f64::NEG_INFINITY);
+        assert!(r_yn(4, -1.0).is_nan());
+    }
+}
@@ -0,0 +1,470 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::bessel_util::{cos_pi, sin_pi, temme_gamma, TemmeConsts, MAX_TERM};
+use crate::special::bessel::r_j0::BesselJY0Consts;
+use crate::special::bessel::r_j1::BesselJY1Consts;
+use crate::special::bessel::r_jn::{r_jn, r_yn};
+use crate::special::Gamma;
+use num_traits::{cast, Float, FloatConst};
+
+/// Maximum number of terms for the series and continued fractions.
+const MAX_ITER: usize = 1000000;
+
+/// Power series of $J_\nu(x)$ for $\nu \geq 0$, see [DLMF 10.2.2](https://dlmf.nist.gov/10.2#E2),
+/// $$
+/// J_\nu(x) = \frac{(x/2)^\nu}{\Gamma(\nu+1)}\sum_{k=0}^{\infty}\frac{(-x^2/4)^k}{k!(\nu+1)_k}
+/// $$
+/// which is only used for $x^2 \leq \nu + 1$, where the terms decrease from the start.
+fn jv_series<T>(nu: T, x: T) -> T
+where
+    T: Float + Gamma,
+{
+    let quarter = cast::<f64, T>(0.25).unwrap();
+    let w = -x * x * quarter;
+    let mut term = T::one();
+    let mut sum = term;
+    for k in 1..MAX_ITER {
+        let k = cast::<usize, T>(k).unwrap();
+        term = term * w / (k * (nu + k));
+        sum = sum + term;
+        if term.abs() <= T::epsilon() * sum.abs() {
+            break;
+        }
+    }
+    let half_x = x * quarter * (T::one() + T::one());
+    let gamma = (nu + T::one()).gamma();
+    let prefactor = if gamma.is_finite() {
+        half_x.powf(nu) / gamma
+    } else {
+        (half_x.ln() * nu - (nu + T::one()).lgamma()).exp()
+    };
+    prefactor * sum
+}
+
+/// Hankel's asymptotic expansion of $J_\nu(x)$ and $Y_\nu(x)$ for large $x$, see [DLMF 10.17.3](https://dlmf.nist.gov/10.17#E3).
+/// $$
+/// J_\nu(x) \sim \sqrt{\frac{2}{\pi x}}\left(P\cos\omega - Q\sin\omega\right), \quad Y_\nu(x) \sim \sqrt{\frac{2}{\pi x}}\left(P\sin\omega + Q\cos\omega\right)
+/// $$
+/// where $\omega = x - (\nu/2 + 1/4)\pi$ and
+/// $$
+/// P = \sum_{k=0}^{\infty}(-1)^k\frac{a_{2k}(\nu)}{x^{2k}}, \quad Q = \sum_{k=0}^{\infty}(-1)^k\frac{a_{2k+1}(\nu)}{x^{2k+1}}, \quad a_k(\nu) = \frac{(4\nu^2-1^2)(4\nu^2-3^2)\cdots(4\nu^2-(2k-1)^2)}{k!8^k}
+/// $$
+/// Returns `None` if the terms start to increase again before the series has converged, or if its terms grow large enough to cause cancellation.
+pub(crate) fn jy_hankel<T>(nu: T, x: T) -> Option<(T, T)>
+where
+    T: Float + FloatConst,
+{
+    let mu = cast::<f64, T>(4.0).unwrap() * nu * nu;
+    let eight_x = cast::<f64, T>(8.0).unwrap() * x;
+    let max_term = cast::<f64, T>(MAX_TERM).unwrap();
+
+    let mut p = T::one();
+    let mut q = T::zero();
+    let mut term = T::one();
+    let mut converged = false;
+    let mut decreasing = false;
+    for k in 1..MAX_ITER {
+        let previous = term.abs();
+        let odd = cast::<usize, T>(2 * k - 1).unwrap();
+        term = term * (mu - odd * odd) / (cast::<usize, T>(k).unwrap() * eight_x);
+        if (decreasing && term.abs() > previous) || term.abs() > max_term {
+            break;
+        }
+        decreasing = term.abs() < previous;
+        match k % 4 {
+            0 => p = p + term,
+            1 => q = q + term,
+            2 => p = p - term,
+            _ => q = q - term,
+        }
+        if term.abs() <= T::epsilon() * (p.abs() + q.abs()) {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return None;
+    }
+
+    let phase = nu / (T::one() + T::one()) + cast::<f64, T>(0.25).unwrap();
+    let (sin_phase, cos_phase) = (sin_pi(phase), cos_pi(phase));
+    let (sin, cos) = x.sin_cos();
+    let cos_omega = cos * cos_phase + sin * sin_phase;
+    let sin_omega = sin * cos_phase - cos * sin_phase;
+
+    let amplitude = (T::FRAC_2_PI() / x).sqrt();
+    Some((
+        amplitude * (p * cos_omega - q * sin_omega),
+        amplitude * (p * sin_omega + q * cos_omega),
+    ))
+}
+
+/// Computes $J_\nu(x)$ and $Y_\nu(x)$ for $\nu \geq 0$ and $x > 0$ with the algorithm `bessjy` of [Numerical Recipes] (section 6.7).
+///
+/// The ratio $J_\nu'/J_\nu$ is found from its continued fraction and the recurrence is run downwards to $\mu = \nu - n$ with $|\mu| \leq 1/2$ or $\mu \approx x$. There, $J_\mu$ and $Y_\mu$ are fixed by Temme's series for $x < 2$ and Steed's method for the second continued fraction otherwise. Finally, $Y_\nu$ follows from the forward recurrence.
+///
+/// [Numerical Recipes]: https://numerical.recipes/
+fn bessel_jy<T>(nu: T, x: T) -> (T, T)
+where
+    T: Float + FloatConst + TemmeConsts,
+{
+    let one = T::one();
+    let two = one + one;
+    let half = cast::<f64, T>(0.5).unwrap();
+    let eps = T::epsilon();
+    let tiny = T::min_positive_value() / eps;
+    let big = T::max_value().sqrt();
+
+    let nl = if x < two {
+        (nu + half).floor()
+    } else {
+        (nu - x + cast::<f64, T>(1.5).unwrap())
+            .floor()
+            .max(T::zero())
+    };
+    let nl = nl.to_usize().unwrap();
+    let mu = nu - cast::<usize, T>(nl).unwrap();
+    let mu2 = mu * mu;
+    let xi = x.recip();
+    let xi2 = two * xi;
+    let w = xi2 / T::PI();
+
+    // First continued fraction, J'_nu / J_nu
+    let mut sign = one;
+    let mut h = (nu * xi).max(tiny);
+    let mut b = xi2 * nu;
+    let mut d = T::zero();
+    let mut c = h;
+    for _ in 0..MAX_ITER {
+        b = b + xi2;
+        d = b - d;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = b - c.recip();
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = d.recip();
+        let delta = c * d;
+        h = delta * h;
+        if d < T::zero() {
+            sign = -sign;
+        }
+        if (delta - one).abs() < eps {
+            break;
+        }
+    }
+
+    // Downward recurrence from nu to mu
+    let mut rjl = sign;
+    let mut rjpl = h * rjl;
+    let mut rjl1 = rjl;
+    let mut fact = nu * xi;
+    for _ in 0..nl {
+        let rjtemp = fact * rjl + rjpl;
+        fact = fact - xi;
+        rjpl = fact * rjtemp - rjl;
+        rjl = rjtemp;
+        if rjl.abs() > big {
+            rjl = rjl / big;
+            rjpl = rjpl / big;
+            rjl1 = rjl1 / big;
+        }
+    }
+    if rjl.is_zero() {
+        rjl = eps;
+    }
+    let f = rjpl / rjl;
+
+    let (rjmu, mut rymu, mut ry1) = if x < two {
+        // Temme's series
+        let x2 = half * x;
+        let pimu = T::PI() * mu;
+        let fact = if pimu.abs() < eps {
+            one
+        } else {
+            pimu / pimu.sin()
+        };
+        let d = -x2.ln();
+        let e = mu * d;
+        let fact2 = if e.abs() < eps { one } else { e.sinh() / e };
+        let (gam1, gam2, gampl, gammi) = temme_gamma(mu);
+        let mut ff = T::FRAC_2_PI() * fact * (gam1 * e.cosh() + gam2 * fact2 * d);
+        let e = e.exp();
+        let mut p = e / (gampl * T::PI());
+        let mut q = (e * T::PI() * gammi).recip();
+        let pimu2 = half * pimu;
+        let fact3 = if pimu2.abs() < eps {
+            one
+        } else {
+            pimu2.sin() / pimu2
+        };
+        let r = T::PI() * pimu2 * fact3 * fact3;
+
+        let mut c = one;
+        let d = -x2 * x2;
+        let mut sum = ff + r * q;
+        let mut sum1 = p;
+        for i in 1..MAX_ITER {
+            let i = cast::<usize, T>(i).unwrap();
+            ff = (i * ff + p + q) / (i * i - mu2);
+            c = c * d / i;
+            p = p / (i - mu);
+            q = q / (i + mu);
+            let delta = c * (ff + r * q);
+            sum = sum + delta;
+            sum1 = sum1 + (c * p - i * delta);
+            if delta.abs() < (one + sum.abs()) * eps {
+                break;
+            }
+        }
+        let rymu = -sum;
+        let ry1 = -sum1 * xi2;
+        let rymup = mu * xi * rymu - ry1;
+        (w / (rymup - f * rymu), rymu, ry1)
+    } else {
+        // Steed's method for the second continued fraction
+        let mut a = cast::<f64, T>(0.25).unwrap() - mu2;
+        let mut p = -half * xi;
+        let mut q = one;
+        let br = two * x;
+        let mut bi = two;
+        let fact = a * xi / (p * p + q * q);
+        let mut cr = br + q * fact;
+        let mut ci = bi + p * fact;
+        let den = br * br + bi * bi;
+        let mut dr = br / den;
+        let mut di = -bi / den;
+        let dlr = cr * dr - ci * di;
+        let dli = cr * di + ci * dr;
+        let temp = p * dlr - q * dli;
+        q = p * dli + q * dlr;
+        p = temp;
+        for i in 1..MAX_ITER {
+            a = a + cast::<usize, T>(2 * i).unwrap();
+            bi = bi + two;
+            dr = a * dr + br;
+            di = a * di + bi;
+            if dr.abs() + di.abs() < tiny {
+                dr = tiny;
+            }
+            let fact = a / (cr * cr + ci * ci);
+            cr = br + cr * fact;
+            ci = bi - ci * fact;
+            if cr.abs() + ci.abs() < tiny {
+                cr = tiny;
+            }
+            let den = dr * dr + di * di;
+            dr = dr / den;
+            di = -di / den;
+            let dlr = cr * dr - ci * di;
+            let dli = cr * di + ci * dr;
+            let temp = p * dlr - q * dli;
+            q = p * dli + q * dlr;
+            p = temp;
+            if (dlr - one).abs() + dli.abs() < eps {
+                break;
+            }
+        }
+        let gam = (p - f) / q;
+        let rjmu = (w / ((p - f) * gam + q)).sqrt().copysign(rjl);
+        let rymu = rjmu * gam;
+        let rymup = rymu * (p + q / gam);
+        (rjmu, rymu, mu * xi * rymu - rymup)
+    };
+
+    let rj = rjl1 * (rjmu / rjl);
+    for i in 1..=nl {
+        let rytemp = (mu + cast::<usize, T>(i).unwrap()) * xi2 * ry1 - rymu;
+        rymu = ry1;
+        ry1 = rytemp;
+        if ry1.is_infinite() {
+            // |Y| increases with the order
+            rymu = ry1;
+            break;
+        }
+    }
+    (rj, rymu)
+}
+
+/// Computes $J_\nu(x)$ and $Y_\nu(x)$ for $\nu \geq 0$ and $x > 0$.
+///
+/// Hankel's expansion is attempted for $x \geq \max(25, \nu)$, and [bessel_jy] is used otherwise or if it fails.
+pub(crate) fn jy_positive<T>(nu: T, x: T) -> (T, T)
+where
+    T: Float + FloatConst + TemmeConsts,
+{
+    let asymptotic = cast::<f64, T>(25.0).unwrap();
+    if x >= asymptotic && x >= nu {
+        if let Some(result) = jy_hankel(nu, x) {
+            return result;
+        }
+    }
+    bessel_jy(nu, x)
+}
+
+/// Bessel function of the first kind of real order implementation.
+///
+/// Integer orders are delegated to [r_jn]. For $x^2 \leq \nu + 1$, the power series is used. Negative orders use the reflection formula, [DLMF 10.4.7](https://dlmf.nist.gov/10.4#E7),
+/// $$
+/// J_{-\nu}(x) = \cos(\nu\pi)J_\nu(x) - \sin(\nu\pi)Y_\nu(x)
+/// $$
+/// For non-integer orders, $J_\nu(x)$ is complex for $x < 0$ and NaN is returned.
+pub(crate) fn r_jv<T>(nu: T, x: T) -> T
+where
+    T: Float + FloatConst + Gamma + TemmeConsts + BesselJY0Consts + BesselJY1Consts,
+{
+    if nu.is_nan() || x.is_nan() {
+        return T::nan();
+    }
+    if nu == nu.floor() {
+        if let Some(n) = nu.to_i32() {
+            return r_jn(n, x);
+        }
+    }
+    if x < T::zero() {
+        return T::nan();
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+    if x.is_zero() {
+        return if nu > T::zero() {
+            T::zero()
+        } else {
+            -sin_pi(nu).signum() * T::infinity()
+        };
+    }
+
+    if nu < T::zero() {
+        let (j, y) = jy_positive(-nu, x);
+        let (sin, cos) = (sin_pi(-nu), cos_pi(-nu));
+        return if cos.is_zero() {
+            -sin * y
+        } else {
+            cos * j - sin * y
+        };
+    }
+    if x * x <= nu + T::one() {
+        return jv_series(nu, x);
+    }
+    jy_positive(nu, x).0
+}
+
+/// Bessel function of the second kind of real order implementation.
+///
+/// Integer orders are delegated to [r_yn]. Negative orders use the reflection formula, [DLMF 10.4.8](https://dlmf.nist.gov/10.4#E8),
+/// $$
+/// Y_{-\nu}(x) = \sin(\nu\pi)J_\nu(x) + \cos(\nu\pi)Y_\nu(x)
+/// $$
+/// $Y_\nu(x)$ is complex for $x < 0$, in which case NaN is returned.
+pub(crate) fn r_yv<T>(nu: T, x: T) -> T
+where
+    T: Float + FloatConst + TemmeConsts + BesselJY0Consts + BesselJY1Consts,
+{
+    if nu.is_nan() || x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if nu == nu.floor() {
+        if let Some(n) = nu.to_i32() {
+            return r_yn(n, x);
+        }
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+    if x.is_zero() {
+        return if nu > T::zero() {
+            T::neg_infinity()
+        } else {
+            let cos = cos_pi(nu);
+            if cos.is_zero() {
+                T::zero()
+            } else {
+                -cos.signum() * T::infinity()
+            }
+        };
+    }
+
+    if nu < T::zero() {
+        let (j, y) = jy_positive(-nu, x);
+        let (sin, cos) = (sin_pi(-nu), cos_pi(-nu));
+        return if cos.is_zero() {
+            sin * j
+        } else {
+            sin * j + cos * y
+        };
+    }
+    jy_positive(nu, x).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 4]; 16] = [
+        // nu, x, Jv(x), Yv(x)
+        [0.5, 3.0, 0.06500818287737578, 0.45604882079463316],
+        [0.3, 1e-05, 0.028620072142050115, -37.052241917585754],
+        [0.3, 1.0, 0.7402224792810205, -0.24570419535649946],
+        [1.5, 1.9, 0.4754309186530739, -0.44927021455323163],
+        [2.5, 2.1, 0.24513299591767077, -0.7678397898393284],
+        [7.7, 0.01, 8.976848352607402e-23, -4.60506628759079e+20],
+        [7.7, 10.0, 0.30316565461369926, 0.06537816636455024],
+        [12.25, 30.0, 0.15214264219282458, -0.00950944199816228],
+        [45.3, 1.9, 2.5513242023832976e-58, -2.756568580110411e+55],
+        [45.3, 300.0, 0.025264072035382407, -0.03883816976690889],
+        [0.1, 1000.0, 0.02521924918164821, 0.0007804913114654824],
+        [
+            30.5,
+            100000.0,
+            -7.847295675466313e-05,
+            -0.002521911976013875,
+        ],
+        [-0.5, 3.0, -0.45604882079463316, 0.06500818287737578],
+        [-0.3, 1.0, 0.6338707263693847, 0.4544312628997638],
+        [-2.5, 2.1, 0.7678397898393284, 0.24513299591767077],
+        [-7.7, 10.0, 0.23108834843351983, -0.20683784468228184],
+    ];
+
+    #[test]
+    fn test_r_jv() {
+        for [nu, x, jv, _] in KNOWN_VALUES {
+            assert_almost_eq!(r_jv(nu, x), jv, PRECISION * jv.abs());
+        }
+
+        // Integer orders
+        assert_eq!(r_jv(3.0, 2.5), r_jn(3, 2.5));
+        assert_eq!(r_jv(-3.0, -2.5), r_jn(-3, -2.5));
+
+        assert_eq!(r_jv(0.5, 0.0), 0.0);
+        assert_eq!(r_jv(-0.5, 0.0), f64::INFINITY);
+        assert_eq!(r_jv(-1.5, 0.0), f64::NEG_INFINITY);
+        assert_eq!(r_jv(0.5, f64::INFINITY), 0.0);
+        assert!(r_jv(0.5, -1.0).is_nan());
+        assert!(r_jv(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_r_yv() {
+        for [nu, x, _, yv] in KNOWN_VALUES {
+            assert_almost_eq!(r_yv(nu, x), yv, PRECISION * yv.abs());
+        }
+
+        // Integer orders
+        assert_eq!(r_yv(3.0, 2.5), r_yn(3, 2.5));
+
+        assert_eq!(r_yv(0.5, 0.0), f64::NEG_INFINITY);
+        assert_eq!(r_yv(-0.5, 0.0), 0.0);
+        assert_eq!(r_yv(45.3, 1e-8), f64::NEG_INFINITY);
+        assert_eq!(r_yv(0.5, f64::INFINITY), 0.0);
+        assert!(r_yv(0.5, -1.0).is_nan());
+    }
+}
@@ -0,0 +1,461 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::spherical_util::{
+    sph_in_orders, sph_jn_orders, sph_kn_orders, sph_orders, sph_yn_orders,
+};
+use num_traits::{cast, Float, FloatConst};
+
+/// Values at $x = 0$ of $j_n$ and $i_n$, or of their derivatives, for $n = 0, \ldots, n_{max}$.
+fn regular_at_zero<T>(n_max: u32, derivative: bool) -> Vec<T>
+where
+    T: Float,
+{
+    let mut values = vec![T::zero(); n_max as usize + 1];
+    if !derivative {
+        values[0] = T::one();
+    } else if n_max >= 1 {
+        values[1] = cast::<f64, T>(1.0 / 3.0).unwrap();
+    }
+    values
+}
+
+/// Alternates the sign of `value` with the order, starting with `value` at $n = 0$.
+fn alternating<T>(n_max: u32, value: T) -> Vec<T>
+where
+    T: Float,
+{
+    (0..=n_max)
+        .map(|n| if n % 2 == 0 { value } else { -value })
+        .collect()
+}
+
+/// Spherical Bessel functions of the first kind $j_0(x), \ldots, j_{n_{max}}(x)$, or their derivatives, implementation.
+pub(crate) fn r_spherical_jn_all_orders<T>(n_max: u32, x: T, derivative: bool) -> Vec<T>
+where
+    T: Float + FloatConst,
+{
+    if x.is_nan() {
+        return vec![T::nan(); n_max as usize + 1];
+    }
+    if x.is_zero() {
+        return regular_at_zero(n_max, derivative);
+    }
+    if x.is_infinite() {
+        return vec![T::zero(); n_max as usize + 1];
+    }
+    sph_orders(n_max, x, derivative, sph_jn_orders, T::one(), -T::one())
+}
+
+/// Spherical Bessel function of the first kind implementation, see [r_spherical_jn_all_orders].
+pub(crate) fn r_spherical_jn<T>(n: u32, x: T, derivative: bool) -> T
+where
+    T: Float + FloatConst,
+{
+    r_spherical_jn_all_orders(n, x, derivative)[n as usize]
+}
+
+/// Spherical Bessel functions of the second kind $y_0(x), \ldots, y_{n_{max}}(x)$, or their derivatives, implementation.
+pub(crate) fn r_spherical_yn_all_orders<T>(n_max: u32, x: T, derivative: bool) -> Vec<T>
+where
+    T: Float + FloatConst,
+{
+    if x.is_nan() {
+        return vec![T::nan(); n_max as usize + 1];
+    }
+    if x.is_zero() {
+        let value = if derivative {
+            T::infinity()
+        } else {
+            T::neg_infinity()
+        };
+        return vec![value; n_max as usize + 1];
+    }
+    if x.is_infinite() {
+        return vec![T::zero(); n_max as usize + 1];
+    }
+    sph_orders(n_max, x, derivative, sph_yn_orders, T::one(), -T::one())
+}
+
+/// Spherical Bessel function of the second kind implementation, see [r_spherical_yn_all_orders].
+pub(crate) fn r_spherical_yn<T>(n: u32, x: T, derivative: bool) -> T
+where
+    T: Float + FloatConst,
+{
+    r_spherical_yn_all_orders(n, x, derivative)[n as usize]
+}
+
+/// Modified spherical Bessel functions of the first kind $i_0(x), \ldots, i_{n_{max}}(x)$, or their derivatives, implementation.
+pub(crate) fn r_spherical_in_all_orders<T>(n_max: u32, x: T, derivative: bool) -> Vec<T>
+where
+    T: Float + FloatConst,
+{
+    if x.is_nan() {
+        return vec![T::nan(); n_max as usize + 1];
+    }
+    if x.is_zero() {
+        return regular_at_zero(n_max, derivative);
+    }
+    if x.is_infinite() {
+        return if x > T::zero() {
+            vec![T::infinity(); n_max as usize + 1]
+        } else if derivative {
+            alternating(n_max, T::neg_infinity())
+        } else {
+            alternating(n_max, T::infinity())
+        };
+    }
+    sph_orders(n_max, x, derivative, sph_in_orders, T::one(), T::one())
+}
+
+/// Modified spherical Bessel function of the first kind implementation, see [r_spherical_in_all_orders].
+pub(crate) fn r_spherical_in<T>(n: u32, x: T, derivative: bool) -> T
+where
+    T: Float + FloatConst,
+{
+    r_spherical_in_all_orders(n, x, derivative)[n as usize]
+}
+
+/// Modified spherical Bessel functions of the second kind $k_0(x), \ldots, k_{n_{max}}(x)$, or their derivatives, implementation.
+///
+/// Unlike $K_\nu(x)$, $k_n(x)$ has no branch cut and is real-valued for $x < 0$.
+pub(crate) fn r_spherical_kn_all_orders<T>(n_max: u32, x: T, derivative: bool) -> Vec<T>
+where
+    T: Float + FloatConst,
+{
+    if x.is_nan() {
+        return vec![T::nan(); n_max as usize + 1];
+    }
+    if x.is_zero() {
+        let value = if derivative {
+            T::neg_infinity()
+        } else {
+            T::infinity()
+        };
+        return vec![value; n_max as usize + 1];
+    }
+    if x.is_infinite() {
+        let value = if x > T::zero() {
+            T::zero()
+        } else if derivative {
+            T::infinity()
+        } else {
+            T::neg_infinity()
+        };
+        return vec![value; n_max as usize + 1];
+    }
+    sph_orders(n_max, x, derivative, sph_kn_orders, -T::one(), -T::one())
+}
+
+/// Modified spherical Bessel function of the second kind implementation, see [r_spherical_kn_all_orders].
+pub(crate) fn r_spherical_kn<T>(n: u32, x: T, derivative: bool) -> T
+where
+    T: Float + FloatConst,
+{
+    r_spherical_kn_all_orders(n, x, derivative)[n as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_JY: [[f64; 6]; 13] = [
+        // n, x, jn(x), jn'(x), yn(x), yn'(x)
+        [
+            0.0,
+            0.1,
+            0.9983341664682815,
+            -0.033300011902557575,
+            -9.950041652780257,
+            100.49875069427085,
+        ],
+        [
+            1.0, 0.1, 0.033300011902557575, 0.3323339284171301, -100.49875069427085,
+            2000.0249722326366,
+        ],
+        [
+            5.0,
+            0.1,
+            9.616310232916448e-10,
+            4.8074153623488654e-08,
+            -945525187.5625257,
+            56721003749.99946,
+        ],
+        [
+            0.0, 1.0, 0.8414709848078965, -0.3011686789397568, -0.5403023058681398,
+            1.3817732906760363,
+        ],
+        [
+            2.0, 1.0, 0.06203505201137386, 0.11506352290563521, -3.605017566159969,
+            9.433279407803871,
+        ],
+        [
+            10.0,
+            1.0,
+            7.116552640047314e-11,
+            7.085557121499413e-10,
+            -672215008.2562084,
+            7358875042.392181,
+        ],
+        [
+            3.0, 2.5, 0.10392046970240394, 0.09379397796505892, -0.7966031232532494,
+            0.8206604960015378,
+        ],
+        [
+            0.0, 10.0, -0.05440211108893698, -0.07846694179875155, 0.08390715290764525,
+            -0.0627928263797015,
+        ],
+        [
+            7.0,
+            10.0,
+            0.11338623065577473,
+            -0.046207662190525516,
+            0.042506332207659946,
+            0.07087176030029647,
+        ],
+        [
+            30.0,
+            10.0,
+            2.512057384998943e-13,
+            7.12720577762413e-13,
+            -6908318646.094516,
+            20207735593.551346,
+        ],
+        [
+            1.0,
+            50.0,
+            -0.01940427051132384,
+            -0.0044713262536256225,
+            0.00486151066268173,
+            -0.019493780996349536,
+        ],
+        [
+            20.0,
+            50.0,
+            -0.015785029898269298,
+            -0.01220418104503915,
+            0.013759531302541216,
+            -0.014702296440651592,
+        ],
+        [
+            30.0,
+            50.0,
+            -0.0014946734536051122,
+            0.01780704395395153,
+            -0.022412268120502118,
+            -0.0006046514496475698,
+        ],
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_IK: [[f64; 6]; 13] = [
+        // n, x, in(x), in'(x), kn(x), kn'(x)
+        [
+            0.0, 0.1, 1.0016675001984403, 0.03336667857363341, 14.213152925974635,
+            -156.34468218572098,
+        ],
+        [
+            1.0, 0.1, 0.03336667857363341, 0.3343339287257721, 156.34468218572098,
+            -3141.106796640394,
+        ],
+        [
+            5.0,
+            0.1,
+            9.623710240437372e-10,
+            4.812595367661877e-08,
+            1483578155.175787,
+            -89031170896.89333,
+        ],
+        [
+            0.0, 1.0, 1.1752011936438014, 0.36787944117144233, 0.5778636748954609,
+            -1.1557273497909217,
+        ],
+        [
+            2.0, 1.0, 0.07156287012947449, 0.15319083078301884, 4.045045724268226,
+            -13.2908645225956,
+        ],
+        [
+            10.0,
+            1.0,
+            7.432793549094827e-11,
+            7.465054020509088e-10,
+            1001775282.2335222,
+            -11072091018.412855,
+        ],
+        [
+            3.0, 2.5, 0.20843886982513896, 0.3047199108167624, 0.3486505539304313,
+            -0.6960632952433582,
+        ],
+        [
+            0.0,
+            10.0,
+            1101.3232874703394,
+            991.1909632632984,
+            7.131404290765751e-06,
+            -7.844544719842325e-06,
+        ],
+        [
+            7.0,
+            10.0,
+            66.54363519410532,
+            77.76197454656659,
+            9.438305538053467e-05,
+            -0.00012576034497378892,
+        ],
+        [
+            30.0,
+            10.0,
+            1.2292843254988045e-12,
+            3.878426015171e-12,
+            1990436137.795925,
+            -6498255776.867083,
+        ],
+        [
+            1.0,
+            50.0,
+            5.081011418015331e+19,
+            4.981465071866459e+19,
+            6.1805332800353925e-24,
+            -6.306567684177291e-24,
+        ],
+        [
+            20.0,
+            50.0,
+            7.904304103767395e+17,
+            8.396070836813153e+17,
+            3.6774830166338424e-22,
+            -4.042790452322047e-22,
+        ],
+        [
+            30.0,
+            50.0,
+            5676593928693482.0,
+            6551302132286350.0,
+            4.724600057268812e-20,
+            -5.615968139145695e-20,
+        ],
+    ];
+
+    #[test]
+    fn test_r_spherical_jn() {
+        let orders = r_spherical_jn_all_orders(30, 10.0, false);
+        for [n, x, jn, djn, _, _] in KNOWN_JY {
+            let n = n as u32;
+            assert_almost_eq!(r_spherical_jn(n, x, false), jn, PRECISION * jn.abs());
+            assert_almost_eq!(r_spherical_jn(n, x, true), djn, PRECISION * djn.abs());
+            if x == 10.0 {
+                assert_almost_eq!(orders[n as usize], jn, PRECISION * jn.abs());
+            }
+            // jn(-x) = (-1)^n jn(x)
+            let sign = (-1.0_f64).powi(n as i32);
+            assert_almost_eq!(
+                r_spherical_jn(n, -x, false),
+                sign * jn,
+                PRECISION * jn.abs()
+            );
+        }
+
+        assert_eq!(
+            r_spherical_jn_all_orders(2, 0.0, false),
+            vec![1.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            r_spherical_jn_all_orders(2, 0.0, true),
+            vec![0.0, 1.0 / 3.0, 0.0]
+        );
+        assert_eq!(r_spherical_jn(3, f64::INFINITY, false), 0.0);
+        assert_eq!(r_spherical_jn(3, f64::NEG_INFINITY, true), 0.0);
+        assert!(r_spherical_jn(3, f64::NAN, false).is_nan());
+    }
+
+    #[test]
+    fn test_r_spherical_yn() {
+        let orders = r_spherical_yn_all_orders(30, 10.0, false);
+        for [n, x, _, _, yn, dyn_] in KNOWN_JY {
+            let n = n as u32;
+            assert_almost_eq!(r_spherical_yn(n, x, false), yn, PRECISION * yn.abs());
+            assert_almost_eq!(r_spherical_yn(n, x, true), dyn_, PRECISION * dyn_.abs());
+            if x == 10.0 {
+                assert_almost_eq!(orders[n as usize], yn, PRECISION * yn.abs());
+            }
+            // yn(-x) = (-1)^(n+1) yn(x)
+            let sign = -(-1.0_f64).powi(n as i32);
+            assert_almost_eq!(
+                r_spherical_yn(n, -x, false),
+                sign * yn,
+                PRECISION * yn.abs()
+            );
+        }
+
+        assert_eq!(r_spherical_yn(2, 0.0, false), f64::NEG_INFINITY);
+        assert_eq!(r_spherical_yn(2, 0.0, true), f64::INFINITY);
+        assert_eq!(r_spherical_yn(3, f64::INFINITY, false), 0.0);
+        assert!(r_spherical_yn(3, f64::NAN, false).is_nan());
+    }
+
+    #[test]
+    fn test_r_spherical_in() {
+        let orders = r_spherical_in_all_orders(30, 10.0, false);
+        for [n, x, i_n, di_n, _, _] in KNOWN_IK {
+            let n = n as u32;
+            assert_almost_eq!(r_spherical_in(n, x, false), i_n, PRECISION * i_n.abs());
+            assert_almost_eq!(r_spherical_in(n, x, true), di_n, PRECISION * di_n.abs());
+            if x == 10.0 {
+                assert_almost_eq!(orders[n as usize], i_n, PRECISION * i_n.abs());
+            }
+            // in(-x) = (-1)^n in(x)
+            let sign = (-1.0_f64).powi(n as i32);
+            assert_almost_eq!(
+                r_spherical_in(n, -x, false),
+                sign * i_n,
+                PRECISION * i_n.abs()
+            );
+        }
+
+        assert_eq!(
+            r_spherical_in_all_orders(2, 0.0, false),
+            vec![1.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            r_spherical_in_all_orders(2, 0.0, true),
+            vec![0.0, 1.0 / 3.0, 0.0]
+        );
+        assert_eq!(r_spherical_in(3, f64::INFINITY, false), f64::INFINITY);
+        assert_eq!(
+            r_spherical_in(3, f64::NEG_INFINITY, false),
+            f64::NEG_INFINITY
+        );
+        assert!(r_spherical_in(3, f64::NAN, false).is_nan());
+    }
+
+    #[test]
+    fn test_r_spherical_kn() {
+        let orders = r_spherical_kn_all_orders(30, 10.0, false);
+        for [n, x, _, _, kn, dkn] in KNOWN_IK {
+            let n = n as u32;
+            assert_almost_eq!(r_spherical_kn(n, x, false), kn, PRECISION * kn.abs());
+            assert_almost_eq!(r_spherical_kn(n, x, true), dkn, PRECISION * dkn.abs());
+            if x == 10.0 {
+                assert_almost_eq!(orders[n as usize], kn, PRECISION * kn.abs());
+            }
+        }
+
+        // k0(-x) = -pi i0(x) - k0(x)
+        let expected = -std::f64::consts::PI * KNOWN_IK[3][2] - KNOWN_IK[3][4];
+        assert_almost_eq!(
+            r_spherical_kn(0, -1.0, false),
+            expected,
+            PRECISION * expected.abs()
+        );
+
+        assert_eq!(r_spherical_kn(2, 0.0, false), f64::INFINITY);
+        assert_eq!(r_spherical_kn(2, 0.0, true), f64::NEG_INFINITY);
+        assert_eq!(r_spherical_kn(3, f64::INFINITY, false), 0.0);
+        assert!(r_spherical_kn(3, f64::NAN, false).is_nan());
+    }
+}
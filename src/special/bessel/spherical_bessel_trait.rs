@@ -0,0 +1,356 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::complex_bessel_impl::*;
+use crate::special::bessel::real_bessel_impl::*;
+
+use num_complex::Complex;
+
+/// Spherical Bessel functions and their derivatives for both real and complex-valued arguments.
+pub trait SphericalBessel {
+    /// Spherical Bessel function of the first kind of order $n$, or its derivative if `derivative` is set.
+    /// $$
+    /// j_n(z) = \sqrt{\frac{\pi}{2z}}J_{n+1/2}(z)
+    /// $$
+    /// It is a solution of
+    /// $$
+    /// z^2\frac{d^2w}{dz^2} + 2z\frac{dw}{dz} + (z^2 - n(n+1))w = 0
+    /// $$
+    /// which is regular at the origin, with $j_0(z) = \sin(z)/z$. The spherical Bessel functions are entire functions of $z$ (up to a power of $z$ for $y_n$ and $k_n$), so there is no branch cut. See the [DLMF] page for more details.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// assert!((1.0_f64.spherical_jn(0, false) - 1.0_f64.sin()).abs() < 1e-15);
+    /// assert!((2.5_f64.spherical_jn(3, false) - 0.10392046970240394).abs() < 1e-16);
+    /// assert!((2.5_f64.spherical_jn(3, true) - 0.09379397796505892).abs() < 1e-16);
+    /// assert_eq!(0.0_f64.spherical_jn(1, true), 1.0 / 3.0);
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(1.0, 1.0);
+    /// assert!((z.spherical_jn(0, false) - z.sin() / z).norm() < 1e-15);
+    /// ```
+    /// # Notes
+    /// All orders up to $n$ are evaluated together, see [spherical_jn_all_orders].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [spherical_jn_all_orders]: crate::special::SphericalBessel::spherical_jn_all_orders
+    /// [DLMF]: https://dlmf.nist.gov/10.47
+    fn spherical_jn(self, n: u32, derivative: bool) -> Self;
+
+    /// Spherical Bessel functions of the first kind $j_n(z)$ for all orders $n = 0, 1, \ldots, n_{max}$, or their derivatives if `derivative` is set.
+    ///
+    /// Returns a vector of length $n_{max} + 1$ where the $n$th element is $j_n(z)$, as needed for partial-wave expansions or Mie scattering. This is considerably cheaper than calling [spherical_jn] for each order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// let values = 2.5_f64.spherical_jn_all_orders(3, false);
+    /// assert_eq!(values.len(), 4);
+    /// for (n, value) in values.iter().enumerate() {
+    ///     assert!((value - 2.5_f64.spherical_jn(n as u32, false)).abs() < 1e-16);
+    /// }
+    /// ```
+    /// # Notes
+    /// For $n_{max} < |z|$ and $n_{max}^2|\Im z| \leq |z|^2$, the forward recurrence
+    /// $$
+    /// j_{n+1}(z) = \frac{2n+1}{z}j_n(z) - j_{n-1}(z)
+    /// $$
+    /// is stable and started from $j_0(z)$ and $j_1(z)$. Otherwise, $j_n(z)$ is the minimal solution of the recurrence and Miller's backward recurrence is used. The recurrence is started from arbitrary values at an order $N > n_{max}$ chosen from the growth of the dominant solution, so that the values at all orders $n \leq n_{max}$ are accurate. The result is normalized with the exact $j_0(z)$, or $j_1(z)$ close to the zeros of $j_0(z)$. The derivatives follow from [DLMF 10.51.2](https://dlmf.nist.gov/10.51#E2),
+    /// $$
+    /// (2n+1)j_n'(z) = nj_{n-1}(z) - (n+1)j_{n+1}(z)
+    /// $$
+    ///
+    /// # References
+    /// - W. Gautschi, "Computational Aspects of Three-Term Recurrence Relations", SIAM Rev. 9, 24 (1967).
+    /// - [DLMF]
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [spherical_jn]: crate::special::SphericalBessel::spherical_jn
+    /// [DLMF]: https://dlmf.nist.gov/10.51
+    fn spherical_jn_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self>
+    where
+        Self: Sized;
+
+    /// Spherical Bessel function of the second kind of order $n$, or its derivative if `derivative` is set.
+    /// $$
+    /// y_n(z) = \sqrt{\frac{\pi}{2z}}Y_{n+1/2}(z)
+    /// $$
+    /// with $y_0(z) = -\cos(z)/z$. It is the second solution of the spherical Bessel equation, see [spherical_jn], and is singular at the origin.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// assert!((2.5_f64.spherical_yn(3, false) + 0.7966031232532494).abs() < 1e-15);
+    /// assert!((2.5_f64.spherical_yn(3, true) - 0.8206604960015378).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.spherical_yn(2, false), f64::NEG_INFINITY);
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(1.0, 1.0);
+    /// assert!((z.spherical_yn(0, false) + z.cos() / z).norm() < 1e-15);
+    /// ```
+    /// # Notes
+    /// All orders up to $n$ are evaluated together, see [spherical_yn_all_orders].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [spherical_jn]: crate::special::SphericalBessel::spherical_jn
+    /// [spherical_yn_all_orders]: crate::special::SphericalBessel::spherical_yn_all_orders
+    fn spherical_yn(self, n: u32, derivative: bool) -> Self;
+
+    /// Spherical Bessel functions of the second kind $y_n(z)$ for all orders $n = 0, 1, \ldots, n_{max}$, or their derivatives if `derivative` is set.
+    ///
+    /// Returns a vector of length $n_{max} + 1$ where the $n$th element is $y_n(z)$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// let values = 2.5_f64.spherical_yn_all_orders(3, true);
+    /// for (n, value) in values.iter().enumerate() {
+    ///     assert_eq!(*value, 2.5_f64.spherical_yn(n as u32, true));
+    /// }
+    /// ```
+    /// # Notes
+    /// For real arguments, the forward recurrence
+    /// $$
+    /// y_{n+1}(z) = \frac{2n+1}{z}y_n(z) - y_{n-1}(z)
+    /// $$
+    /// is stable and started from $y_0(z)$ and $y_1(z)$. Away from the real axis, the same recurrence is applied to the spherical Hankel function $h^{(1)}_n(z) = j_n(z) + iy_n(z)$ for $\Im z > 0$, or $h^{(2)}_n(z) = j_n(z) - iy_n(z)$ for $\Im z < 0$, which is exponentially small for low orders. Then, $y_n(z)$ follows from [spherical_jn_all_orders]. The derivatives follow from
+    /// $$
+    /// (2n+1)y_n'(z) = ny_{n-1}(z) - (n+1)y_{n+1}(z)
+    /// $$
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [spherical_jn_all_orders]: crate::special::SphericalBessel::spherical_jn_all_orders
+    fn spherical_yn_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self>
+    where
+        Self: Sized;
+
+    /// Modified spherical Bessel function of the first kind of order $n$, or its derivative if `derivative` is set.
+    /// $$
+    /// i_n(z) = \sqrt{\frac{\pi}{2z}}I_{n+1/2}(z)
+    /// $$
+    /// It is a solution of
+    /// $$
+    /// z^2\frac{d^2w}{dz^2} + 2z\frac{dw}{dz} - (z^2 + n(n+1))w = 0
+    /// $$
+    /// which is regular at the origin, with $i_0(z) = \sinh(z)/z$. See the [DLMF] page for more details.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// assert!((2.5_f64.spherical_in(3, false) - 0.20843886982513896).abs() < 1e-15);
+    /// assert!((2.5_f64.spherical_in(3, true) - 0.3047199108167624).abs() < 1e-15);
+    /// assert_eq!((-2.5_f64).spherical_in(3, false), -2.5_f64.spherical_in(3, false));
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(1.0, 1.0);
+    /// assert!((z.spherical_in(0, false) - z.sinh() / z).norm() < 1e-15);
+    /// ```
+    /// # Notes
+    /// All orders up to $n$ are evaluated together, see [spherical_in_all_orders].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [spherical_in_all_orders]: crate::special::SphericalBessel::spherical_in_all_orders
+    /// [DLMF]: https://dlmf.nist.gov/10.47
+    fn spherical_in(self, n: u32, derivative: bool) -> Self;
+
+    /// Modified spherical Bessel functions of the first kind $i_n(z)$ for all orders $n = 0, 1, \ldots, n_{max}$, or their derivatives if `derivative` is set.
+    ///
+    /// Returns a vector of length $n_{max} + 1$ where the $n$th element is $i_n(z)$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// let values = 0.5_f64.spherical_in_all_orders(20, false);
+    /// assert!((values[20] - 7.29387127444089e-32).abs() < 1e-46);
+    /// ```
+    /// # Notes
+    /// Similar to [spherical_jn_all_orders], with the forward recurrence
+    /// $$
+    /// i_{n+1}(z) = i_{n-1}(z) - \frac{2n+1}{z}i_n(z)
+    /// $$
+    /// for $n_{max} < |z|$ and $n_{max}^2|\Re z| \leq |z|^2$, and Miller's backward recurrence otherwise. For $|\Re z| > 1$, the values are computed with the scaling factor $e^{-|\Re z|}$ to avoid a premature overflow. The derivatives follow from
+    /// $$
+    /// (2n+1)i_n'(z) = ni_{n-1}(z) + (n+1)i_{n+1}(z)
+    /// $$
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [spherical_jn_all_orders]: crate::special::SphericalBessel::spherical_jn_all_orders
+    fn spherical_in_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self>
+    where
+        Self: Sized;
+
+    /// Modified spherical Bessel function of the second kind of order $n$, or its derivative if `derivative` is set.
+    /// $$
+    /// k_n(z) = \sqrt{\frac{\pi}{2z}}K_{n+1/2}(z)
+    /// $$
+    /// with $k_0(z) = \frac{\pi}{2}e^{-z}/z$. It is the second solution of the modified spherical Bessel equation, see [spherical_in], which decays exponentially for large positive arguments. See the [DLMF] page for more details. Unlike $K_\nu$, $k_n$ has no branch cut and is real-valued for negative real arguments.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// assert!((1.0_f64.spherical_kn(0, false) - FRAC_PI_2 / 1.0_f64.exp()).abs() < 1e-15);
+    /// assert!((2.5_f64.spherical_kn(3, false) - 0.3486505539304313).abs() < 1e-15);
+    /// assert!((2.5_f64.spherical_kn(3, true) + 0.6960632952433582).abs() < 1e-15);
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// use num_complex::Complex64;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// let z = Complex64::new(1.0, 1.0);
+    /// assert!((z.spherical_kn(0, false) - FRAC_PI_2 * (-z).exp() / z).norm() < 1e-15);
+    /// ```
+    /// # Notes
+    /// All orders up to $n$ are evaluated together, see [spherical_kn_all_orders].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [spherical_in]: crate::special::SphericalBessel::spherical_in
+    /// [spherical_kn_all_orders]: crate::special::SphericalBessel::spherical_kn_all_orders
+    /// [DLMF]: https://dlmf.nist.gov/10.47
+    fn spherical_kn(self, n: u32, derivative: bool) -> Self;
+
+    /// Modified spherical Bessel functions of the second kind $k_n(z)$ for all orders $n = 0, 1, \ldots, n_{max}$, or their derivatives if `derivative` is set.
+    ///
+    /// Returns a vector of length $n_{max} + 1$ where the $n$th element is $k_n(z)$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::SphericalBessel;
+    /// let values = 2.5_f64.spherical_kn_all_orders(3, false);
+    /// for (n, value) in values.iter().enumerate() {
+    ///     assert_eq!(*value, 2.5_f64.spherical_kn(n as u32, false));
+    /// }
+    /// ```
+    /// # Notes
+    /// The forward recurrence
+    /// $$
+    /// k_{n+1}(z) = k_{n-1}(z) + \frac{2n+1}{z}k_n(z)
+    /// $$
+    /// is stable for $\Re z \geq 0$ and started from $k_0(z)$ and $k_1(z) = k_0(z)(1 + 1/z)$, scaled by $e^z$ to avoid a premature underflow. In the left half-plane, we use the reflection formula
+    /// $$
+    /// k_n(z) = -\pi i_n(-z) - (-1)^nk_n(-z)
+    /// $$
+    /// with [spherical_in_all_orders]. The derivatives follow from
+    /// $$
+    /// (2n+1)k_n'(z) = -nk_{n-1}(z) - (n+1)k_{n+1}(z)
+    /// $$
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [spherical_in_all_orders]: crate::special::SphericalBessel::spherical_in_all_orders
+    fn spherical_kn_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self>
+    where
+        Self: Sized;
+}
+
+macro_rules! float_sphericalbessel_impl {
+    ($($T: ty)*) => ($(
+        impl SphericalBessel for $T {
+            #[inline(always)]
+            fn spherical_jn(self, n: u32, derivative: bool) -> Self {
+                r_spherical_jn(n, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_jn_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self> {
+                r_spherical_jn_all_orders(n_max, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_yn(self, n: u32, derivative: bool) -> Self {
+                r_spherical_yn(n, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_yn_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self> {
+                r_spherical_yn_all_orders(n_max, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_in(self, n: u32, derivative: bool) -> Self {
+                r_spherical_in(n, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_in_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self> {
+                r_spherical_in_all_orders(n_max, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_kn(self, n: u32, derivative: bool) -> Self {
+                r_spherical_kn(n, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_kn_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self> {
+                r_spherical_kn_all_orders(n_max, self, derivative)
+            }
+        }
+    )*)
+}
+
+float_sphericalbessel_impl! {f32 f64}
+
+macro_rules! complex_sphericalbessel_impl {
+    ($($T: ty)*) => ($(
+        impl SphericalBessel for Complex<$T> {
+            #[inline(always)]
+            fn spherical_jn(self, n: u32, derivative: bool) -> Self {
+                c_spherical_jn(n, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_jn_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self> {
+                c_spherical_jn_all_orders(n_max, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_yn(self, n: u32, derivative: bool) -> Self {
+                c_spherical_yn(n, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_yn_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self> {
+                c_spherical_yn_all_orders(n_max, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_in(self, n: u32, derivative: bool) -> Self {
+                c_spherical_in(n, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_in_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self> {
+                c_spherical_in_all_orders(n_max, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_kn(self, n: u32, derivative: bool) -> Self {
+                c_spherical_kn(n, self, derivative)
+            }
+
+            #[inline(always)]
+            fn spherical_kn_all_orders(self, n_max: u32, derivative: bool) -> Vec<Self> {
+                c_spherical_kn_all_orders(n_max, self, derivative)
+            }
+        }
+    )*)
+}
+
+complex_sphericalbessel_impl! {f32 f64}
@@ -0,0 +1,297 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use num_complex::ComplexFloat;
+use num_traits::{Float, FloatConst, One, Zero};
+
+/// Converts an order or a small integer to `T`.
+#[inline(always)]
+fn from_usize<T: ComplexFloat>(n: usize) -> T {
+    T::from(n).unwrap()
+}
+
+/// Finds the starting order $N > n_{max}$ of Miller's backward recurrence
+/// $$
+/// f_{k-1}(z) = \frac{2k+1}{z}f_k(z) + \sigma f_{k+1}(z)
+/// $$
+/// where $\sigma = -1$ for $j_n$ and $\sigma = 1$ for $i_n$. Starting from $p_{n_{max}} = 0$ and $p_{n_{max}+1} = 1$, the recurrence is run upwards until $|p_N| \geq 1/\epsilon$. The growth of $p_k$ follows the dominant solution, so that the relative error introduced by the arbitrary starting values is negligible at all orders $n \leq n_{max}$, see Gautschi, SIAM Rev. 9, 24 (1967).
+fn miller_start<T>(n_max: usize, z: T, sigma: T) -> usize
+where
+    T: ComplexFloat,
+{
+    let limit = Float::recip(T::Real::epsilon());
+    let mut p0 = T::zero();
+    let mut p1 = T::one();
+    let mut k = n_max + 1;
+    while p1.abs() < limit {
+        let p2 = from_usize::<T>(2 * k + 1) / z * p1 + sigma * p0;
+        p0 = p1;
+        p1 = p2;
+        k += 1;
+    }
+    k
+}
+
+/// Runs Miller's backward recurrence from the order found by [miller_start] and returns the unnormalized values $f_0, \ldots, f_{n_{max}}$. The values are rescaled whenever the next step could overflow, in which case the highest orders may underflow to zero.
+fn miller_backward<T>(n_max: usize, z: T, sigma: T) -> Vec<T>
+where
+    T: ComplexFloat,
+{
+    let big = Float::sqrt(T::Real::max_value());
+
+    let mut values = vec![T::zero(); n_max + 1];
+    let mut f1 = T::zero();
+    let mut f0 = T::one();
+    for k in (1..=miller_start(n_max, z, sigma)).rev() {
+        if k <= n_max {
+            values[k] = f0;
+        }
+        let factor = from_usize::<T>(2 * k + 1) / z;
+        if factor.abs() * f0.abs() > big {
+            let rescale = T::from(Float::recip(f0.abs())).unwrap();
+            f0 = f0 * rescale;
+            f1 = f1 * rescale;
+            for value in values.iter_mut().skip(k) {
+                *value = *value * rescale;
+            }
+        }
+        let f = factor * f0 + sigma * f1;
+        f1 = f0;
+        f0 = f;
+    }
+    values[0] = f0;
+    values
+}
+
+/// Normalizes the values of Miller's backward recurrence with the exact $f_0$ or $f_1$, whichever has the larger computed magnitude. This avoids dividing by a value close to a zero of either function. The exact $f_0$ is kept for the order zero.
+fn miller_normalize<T, F>(mut values: Vec<T>, f0: T, f1: F) -> Vec<T>
+where
+    T: ComplexFloat,
+    F: FnOnce() -> T,
+{
+    let scale = if values[0].abs() >= values[1].abs() {
+        f0 / values[0]
+    } else {
+        f1() / values[1]
+    };
+    for value in values.iter_mut() {
+        *value = *value * scale;
+    }
+    values[0] = f0;
+    values
+}
+
+/// Checks whether the forward recurrence of $j_n(z)$ is stable up to $n_{max}$, with $y = \Im z$, or of $i_n(z)$, with $y = \Re z$.
+///
+/// For $n < |z|$, the solutions of the recurrence oscillate, but away from the real axis their ratio still grows like $\exp(n^2|y|/|z|^2)$. The forward recurrence is therefore only used for $n_{max} < |z|$ and $n_{max}^2|y| \leq |z|^2$.
+fn forward_stable<T>(n_max: usize, z: T, y: T::Real) -> bool
+where
+    T: ComplexFloat,
+{
+    let n = from_usize::<T>(n_max).re();
+    let r = z.abs();
+    n < r && n * n * Float::abs(y) <= r * r
+}
+
+/// Spherical Bessel functions of the first kind $j_0(z), \ldots, j_{n_{max}}(z)$ for $z \neq 0$.
+///
+/// Starting from $j_0(z) = \sin(z)/z$ and $j_1(z) = (j_0(z) - \cos(z))/z$, the forward recurrence
+/// $$
+/// j_{k+1}(z) = \frac{2k+1}{z}j_k(z) - j_{k-1}(z)
+/// $$
+/// is used when it is stable, see [forward_stable]. Otherwise, Miller's backward recurrence is used, for which $j_k(z)$ is the minimal solution.
+pub(crate) fn sph_jn_orders<T>(n_max: usize, z: T) -> Vec<T>
+where
+    T: ComplexFloat,
+{
+    let j0 = z.sin() / z;
+    if n_max == 0 {
+        return vec![j0];
+    }
+    let j1 = || (j0 - z.cos()) / z;
+    if !forward_stable(n_max, z, z.im()) {
+        return miller_normalize(miller_backward(n_max, z, -T::one()), j0, j1);
+    }
+
+    let mut values = Vec::with_capacity(n_max + 1);
+    values.push(j0);
+    values.push(j1());
+    for k in 1..n_max {
+        values.push(from_usize::<T>(2 * k + 1) / z * values[k] - values[k - 1]);
+    }
+    values
+}
+
+/// Spherical Bessel functions of the second kind $y_0(z), \ldots, y_{n_{max}}(z)$ for $z \neq 0$.
+///
+/// Starting from $y_0(z) = -\cos(z)/z$ and $y_1(z) = (y_0(z) - \sin(z))/z$, the forward recurrence
+/// $$
+/// y_{k+1}(z) = \frac{2k+1}{z}y_k(z) - y_{k-1}(z)
+/// $$
+/// is stable on the real axis. Once a value overflows, the remaining orders keep its sign pattern.
+pub(crate) fn sph_yn_orders<T>(n_max: usize, z: T) -> Vec<T>
+where
+    T: ComplexFloat,
+{
+    let y0 = -z.cos() / z;
+    let mut values = Vec::with_capacity(n_max + 1);
+    values.push(y0);
+    if n_max == 0 {
+        return values;
+    }
+    values.push((y0 - z.sin()) / z);
+    for k in 1..n_max {
+        let factor = from_usize::<T>(2 * k + 1) / z;
+        let next = if values[k].is_infinite() {
+            factor * values[k]
+        } else {
+            factor * values[k] - values[k - 1]
+        };
+        values.push(next);
+    }
+    values
+}
+
+/// Multiplies the values by $e^{a}$, in two steps so that values which are representable do not overflow early.
+fn unscale<T>(values: Vec<T>, a: T) -> Vec<T>
+where
+    T: ComplexFloat,
+{
+    let half = (a / from_usize::<T>(2)).exp();
+    values
+        .into_iter()
+        .map(|value| value * half * half)
+        .collect()
+}
+
+/// Modified spherical Bessel functions of the first kind $i_0(z), \ldots, i_{n_{max}}(z)$ for $z \neq 0$.
+///
+/// Starting from $i_0(z) = \sinh(z)/z$ and $i_1(z) = (\cosh(z) - i_0(z))/z$, the forward recurrence
+/// $$
+/// i_{k+1}(z) = i_{k-1}(z) - \frac{2k+1}{z}i_k(z)
+/// $$
+/// is used when it is stable, see [forward_stable]. Otherwise, Miller's backward recurrence is used, for which $i_k(z)$ is the minimal solution. For $|\Re z| > 1$, the values are computed with the scaling factor $e^{-|\Re z|}$ to avoid overflow.
+pub(crate) fn sph_in_orders<T>(n_max: usize, z: T) -> Vec<T>
+where
+    T: ComplexFloat,
+{
+    let a = T::from(Float::abs(z.re())).unwrap();
+    let scaled = a.re() > T::Real::one();
+    let (sinh, cosh) = if scaled {
+        let half = T::from(0.5).unwrap();
+        let plus = (z - a).exp() * half;
+        let minus = (-z - a).exp() * half;
+        (plus - minus, plus + minus)
+    } else {
+        (z.sinh(), z.cosh())
+    };
+    let i0 = sinh / z;
+    let i1 = || (cosh - i0) / z;
+
+    let values = if n_max == 0 {
+        vec![i0]
+    } else if !forward_stable(n_max, z, z.re()) {
+        miller_normalize(miller_backward(n_max, z, T::one()), i0, i1)
+    } else {
+        let mut values = Vec::with_capacity(n_max + 1);
+        values.push(i0);
+        values.push(i1());
+        for k in 1..n_max {
+            values.push(values[k - 1] - from_usize::<T>(2 * k + 1) / z * values[k]);
+        }
+        values
+    };
+    if scaled {
+        unscale(values, a)
+    } else {
+        values
+    }
+}
+
+/// Modified spherical Bessel functions of the second kind $k_0(z), \ldots, k_{n_{max}}(z)$ for $z \neq 0$.
+///
+/// Starting from $k_0(z) = \frac{\pi}{2}e^{-z}/z$ and $k_1(z) = k_0(z)(1 + 1/z)$, the forward recurrence
+/// $$
+/// k_{k+1}(z) = k_{k-1}(z) + \frac{2k+1}{z}k_k(z)
+/// $$
+/// is stable for $\Re z \geq 0$. In the left half-plane, $k_n(z)$ becomes the minimal solution for large orders, and the reflection formula
+/// $$
+/// k_n(z) = -\pi i_n(-z) - (-1)^nk_n(-z)
+/// $$
+/// is used instead, which follows from the reflection formulas of [DLMF 10.47(v)](https://dlmf.nist.gov/10.47#v).
+pub(crate) fn sph_kn_orders<T>(n_max: usize, z: T) -> Vec<T>
+where
+    T: ComplexFloat,
+{
+    if z.re() < T::Real::zero() {
+        let pi = T::from(T::Real::PI()).unwrap();
+        return sph_in_orders(n_max, -z)
+            .into_iter()
+            .zip(sph_kn_orders(n_max, -z))
+            .enumerate()
+            .map(|(n, (i, k))| if n % 2 == 0 { -pi * i - k } else { k - pi * i })
+            .collect();
+    }
+
+    // Scaled by e^z to avoid underflow for large arguments
+    let k0 = T::from(T::Real::FRAC_PI_2()).unwrap() / z;
+    let mut values = Vec::with_capacity(n_max + 1);
+    values.push(k0);
+    if n_max > 0 {
+        values.push(k0 * (T::one() + z.recip()));
+    }
+    for k in 1..n_max {
+        let factor = from_usize::<T>(2 * k + 1) / z;
+        let next = if values[k].is_infinite() {
+            factor * values[k]
+        } else {
+            values[k - 1] + factor * values[k]
+        };
+        values.push(next);
+    }
+    unscale(values, -z)
+}
+
+/// Derivatives of the spherical Bessel functions from the values $f_0, \ldots, f_{n_{max}+1}$, using
+/// $$
+/// (2n+1)f_n'(z) = \sigma nf_{n-1}(z) + \tau(n+1)f_{n+1}(z)
+/// $$
+/// where $\sigma = 1$, $\tau = -1$ for $j_n$ and $y_n$, $\sigma = \tau = 1$ for $i_n$ and $\sigma = \tau = -1$ for $k_n$, see [DLMF 10.51](https://dlmf.nist.gov/10.51). Unlike the forms with a factor $1/z$, this does not suffer from cancellation for small $|z|$. Once $f_{n+1}$ has overflowed, only its term is kept.
+pub(crate) fn sph_derivatives<T>(values: &[T], sigma: T, tau: T) -> Vec<T>
+where
+    T: ComplexFloat,
+{
+    (0..values.len() - 1)
+        .map(|n| {
+            let upper = tau * from_usize::<T>(n + 1) * values[n + 1];
+            let sum = if n == 0 || values[n + 1].is_infinite() {
+                upper
+            } else {
+                sigma * from_usize::<T>(n) * values[n - 1] + upper
+            };
+            sum / from_usize::<T>(2 * n + 1)
+        })
+        .collect()
+}
+
+/// Evaluates the orders $0, \ldots, n_{max}$ with `orders`, or their derivatives with [sph_derivatives] if `derivative` is set.
+pub(crate) fn sph_orders<T, F>(
+    n_max: u32,
+    z: T,
+    derivative: bool,
+    orders: F,
+    sigma: T,
+    tau: T,
+) -> Vec<T>
+where
+    T: ComplexFloat,
+    F: Fn(usize, T) -> Vec<T>,
+{
+    let n_max = n_max as usize;
+    if !derivative {
+        return orders(n_max, z);
+    }
+    sph_derivatives(&orders(n_max + 1, z), sigma, tau)
+}
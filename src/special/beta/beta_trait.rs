@@ -3,9 +3,12 @@
 // Copyright 2023 Matthew R. Hennefarth                                *
 //**********************************************************************
 
+use crate::special::beta::complex_beta_impl::*;
 use crate::special::beta::real_beta_impl::*;
 
-/// Beta and related functions for real-valued arguments.
+use num_complex::Complex;
+
+/// Beta and related functions for both real and complex-valued arguments.
 pub trait Beta {
     /// The Beta function.
     /// $$
@@ -31,7 +34,10 @@ pub trait Beta {
     /// # Notes
     /// For real-valued inputs, the implementation is based on that from the [cephes] library in SciPy (v 1.10.1). For small enough inputs, the normal [Gamma] function is used, though some care is made to prevent overflows. For large values, an asymptotic expansion for $\ln\left|B(a,b)\right|$ is used.
     ///
+    /// For complex-valued inputs, the Beta function is evaluated as $e^{\ln B(a,b)}$ (see [lbeta]), which avoids overflow of the individual Gamma functions. When both arguments lie on the real axis, the real-valued implementation is used. At the poles of $\Gamma(a)$ or $\Gamma(b)$ we return $+\infty$, and where only $\Gamma(a+b)$ has a pole we return $0$.
+    ///
     /// [Gamma]: crate::special::Gamma::gamma
+    /// [lbeta]: crate::special::Beta::lbeta
     /// [dlmf]: https://dlmf.nist.gov/5.12
     /// [wiki]: https://en.wikipedia.org/wiki/Beta_function
     /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/beta.c
@@ -57,15 +63,111 @@ pub trait Beta {
     /// assert!((gamma_version - beta_version).abs() < 1.0e-7);
     /// ```
     ///
+    /// For complex-valued arguments, the log of the [Beta] function itself is returned, on the branch
+    /// $$
+    /// \ln B(a,b) = \ln\Gamma(a) + \ln\Gamma(b) - \ln\Gamma(a+b)
+    /// $$
+    /// where $\ln\Gamma$ is the principal branch of the log-Gamma function ([lngamma]). The real part is then $\ln\left|B(a,b)\right|$, and $e^{\ln B(a,b)} = B(a,b)$.
+    /// ```
+    /// use sci_rs::special::Beta;
+    /// use num_complex::Complex64;
+    /// let a = Complex64::new(1.5, 0.5);
+    /// let b = Complex64::new(2.0, -1.0);
+    /// assert!((a.lbeta(b).exp() - a.beta(b)).norm() < 1e-14);
+    /// ```
+    ///
     /// # Notes
     /// In general this is implemented as the natural logs of the [Gamma] functions, but with some care taken to prevent overflow. Note that for large values, an asymptotic expansion is used instead. This implementation largely follows that from the [cephes] library in SciPy (v 1.10.1).
     ///
+    /// For complex-valued arguments on the real axis, the real part is computed with the real-valued implementation. The imaginary part is then taken from the log-Gamma functions, or is $0$ or $\pi$ depending on the sign of $B(a,b)$ when one of the Gamma functions has a pole.
+    ///
     /// [Beta]: crate::special::Beta::beta
     /// [Gamma]: crate::special::Gamma::gamma
+    /// [lngamma]: crate::special::Gamma::lngamma
     /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/beta.c
     fn lbeta(self, b: Self) -> Self;
 }
 
+/// Beta related functions that are only defined for real-valued arguments.
+pub trait RealBeta: Beta {
+    /// Regularized incomplete Beta function.
+    /// $$
+    /// I_x(a,b) = \frac{1}{B(a,b)}\int^x_0 t^{a-1}(1-t)^{b-1}dt
+    /// $$
+    /// where $a, b > 0$, $0 \leq x \leq 1$ and $B(a,b)$ is the [Beta] function. It is the cumulative distribution function of the Beta distribution. See the [dlmf] or [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealBeta;
+    /// assert_eq!(2.0.betainc(3.0, 0.0), 0.0);
+    /// assert_eq!(2.0.betainc(3.0, 1.0), 1.0);
+    /// assert!((1.0_f64.betainc(1.0, 0.3) - 0.3).abs() < 1e-15); // Uniform distribution
+    /// assert!((2.0_f64.betainc(2.0, 0.5) - 0.5).abs() < 1e-15); // Symmetric about 1/2
+    /// ```
+    ///
+    /// # Notes
+    /// The implementation is based on the [cephes implementation] in SciPy (v 1.10.1). When $bx \leq 1$ and $x \leq 0.95$, a power series is used. Otherwise, one of two continued fraction expansions is used, after using the symmetry relation
+    /// $$
+    /// I_x(a,b) = 1 - I_{1-x}(b,a)
+    /// $$
+    /// when $x$ is larger than the mean $a/(a+b)$.
+    ///
+    /// Returns `NaN` if $a \leq 0$, $b \leq 0$ or $x$ lies outside of $\[0,1\]$.
+    ///
+    /// [Beta]: crate::special::Beta::beta
+    /// [dlmf]: https://dlmf.nist.gov/8.17
+    /// [wiki]: https://en.wikipedia.org/wiki/Beta_function#Incomplete_beta_function
+    /// [cephes implementation]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/incbet.c
+    fn betainc(self, b: Self, x: Self) -> Self;
+
+    /// Complement of the regularized incomplete Beta function.
+    /// $$
+    /// 1 - I_x(a,b) = I_{1-x}(b,a)
+    /// $$
+    /// where $I_x(a,b)$ is the regularized incomplete Beta function ([betainc]). It is the survival function of the Beta distribution.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealBeta;
+    /// assert_eq!(2.0.betaincc(3.0, 0.0), 1.0);
+    /// assert!((1.0_f64.betaincc(1.0, 0.3) - 0.7).abs() < 1e-15);
+    /// ```
+    /// Computing the complement directly does not lose precision in the tail, unlike `1 - betainc`.
+    /// ```
+    /// use sci_rs::special::RealBeta;
+    /// let q = 2.0_f64.betaincc(50.0, 0.5);
+    /// assert!(((q - 2.3092638912203256e-14) / q).abs() < 1e-13);
+    /// ```
+    ///
+    /// # Notes
+    /// See [betainc] for details on the implementation.
+    ///
+    /// [betainc]: crate::special::RealBeta::betainc
+    fn betaincc(self, b: Self, x: Self) -> Self;
+
+    /// Inverse of the regularized incomplete Beta function.
+    ///
+    /// Returns $x \in \[0,1\]$ such that $I_x(a,b) = y$, where $a, b > 0$, $0 \leq y \leq 1$ and $I_x(a,b)$ is the regularized incomplete Beta function ([betainc]). This is the quantile function of the Beta distribution.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealBeta;
+    /// assert_eq!(2.0.betaincinv(3.0, 0.0), 0.0);
+    /// assert_eq!(2.0.betaincinv(3.0, 1.0), 1.0);
+    /// assert!((1.0_f64.betaincinv(1.0, 0.3) - 0.3).abs() < 1e-15); // Uniform distribution
+    /// let x = 2.5_f64.betaincinv(4.0, 0.95);
+    /// assert!((2.5_f64.betainc(4.0, x) - 0.95).abs() < 1e-15);
+    /// ```
+    ///
+    /// # Notes
+    /// An initial approximation is found from either the normal approximation (for $a, b \geq 1$) or the leading terms of the power series at $x=0$ or $x=1$, as described in Numerical Recipes. The approximation is then refined with Halley's method, where the iterates are kept within a bracket of the root and bisection is used whenever a step would leave the bracket. For $y > 1/2$, the complement $1 - I_x(a,b) = 1 - y$ is solved instead to retain precision.
+    ///
+    /// Returns `NaN` if $a \leq 0$, $b \leq 0$ or $y$ lies outside of $\[0,1\]$.
+    ///
+    /// [betainc]: crate::special::RealBeta::betainc
+    fn betaincinv(self, b: Self, y: Self) -> Self;
+}
+
 macro_rules! float_beta_impl {
     ($($T: ty)*) => ($(
         impl Beta for $T {
@@ -79,7 +181,42 @@ macro_rules! float_beta_impl {
                 r_lbeta(self, b)
             }
         }
+
+        impl RealBeta for $T {
+            #[inline(always)]
+            fn betainc(self, b: Self, x: Self) -> Self {
+                r_betainc(self, b, x)
+            }
+
+            #[inline(always)]
+            fn betaincc(self, b: Self, x: Self) -> Self {
+                r_betaincc(self, b, x)
+            }
+
+            #[inline(always)]
+            fn betaincinv(self, b: Self, y: Self) -> Self {
+                r_betaincinv(self, b, y)
+            }
+        }
     )*)
 }
 
 float_beta_impl! {f32 f64}
+
+macro_rules! float_complexbeta_impl {
+    ($($T: ty)*) => ($(
+        impl Beta for Complex<$T> {
+            #[inline(always)]
+            fn beta(self, b: Self) -> Self {
+                c_beta(self, b)
+            }
+
+            #[inline(always)]
+            fn lbeta(self, b: Self) -> Self {
+                c_lbeta(self, b)
+            }
+        }
+    )*)
+}
+
+float_complexbeta_impl! {f32 f64}
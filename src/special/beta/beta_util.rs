@@ -13,15 +13,16 @@ pub(crate) trait RealBetaConsts {
 }
 
 macro_rules! float_rbetaconsts_impl {
-    ($($T: ty)*) => ($(
+    ($($T: ty, $max_gamma: expr;)*) => ($(
         impl RealBetaConsts for $T {
             const ASYMP_FACTOR: Self = 1.0E6;
-            const MAX_GAMMA: Self = 171.624376956302725;
+            const MAX_GAMMA: Self = $max_gamma;
         }
     )*)
 }
 
-float_rbetaconsts_impl! {f32 f64}
+// Largest argument for which the Gamma function does not overflow
+float_rbetaconsts_impl! {f32, 35.040096; f64, 171.624376956302725;}
 
 /// Asymptotic expansion for  ln(|B(a, b)|) for a > ASYMP_FACTOR*max(|b|, 1).
 /// Taken from the Cephes library, and an unknown source.
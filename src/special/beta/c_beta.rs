@@ -0,0 +1,305 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::beta::real_beta_impl::*;
+use crate::special::beta_util::RealBetaConsts;
+use crate::special::gamma_util::is_gamma_pole;
+use crate::special::{Gamma, RealGamma};
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst, Zero};
+use std::mem;
+use std::ops::{AddAssign, SubAssign};
+
+/// Asymptotic expansion of $\ln B(a,b)$ for $|a| \gg \max(|b|, 1)$ and $\Re(a) > 0$.
+/// $$
+/// \ln B(a,b) \sim \ln\Gamma(b) - b\ln a + \frac{b(1-b)}{2a} + \frac{b(1-b)(1-2b)}{12a^2} - \frac{b^2(1-b)^2}{12a^3}
+/// $$
+/// This is the complex analogue of the expansion used for real-valued arguments.
+fn c_lbeta_asymp<T>(a: Complex<T>, b: Complex<T>) -> Complex<T>
+where
+    T: Float,
+    Complex<T>: Gamma,
+{
+    let one = T::one();
+    let two = one + one;
+    let twelve = cast::<usize, T>(12).unwrap();
+
+    let omb = -b + one;
+    let mut r = b.lngamma() - b * a.ln();
+    r = r + b * omb / (a * two);
+    r = r + b * omb * (-b * two + one) / (a * a * twelve);
+    r - b * b * omb * omb / (a * a * a * twelve)
+}
+
+/// Natural log of the Beta function for complex-valued arguments.
+/// $$
+/// \ln B(a,b) = \ln\Gamma(a) + \ln\Gamma(b) - \ln\Gamma(a+b)
+/// $$
+/// where $\ln\Gamma$ is the principal branch of the log-Gamma function, such that the result is an analytic function of each argument away from the branch cuts of $\ln\Gamma$. This is the same branch as obtained from [lngamma] and $e^{\ln B(a,b)} = B(a,b)$.
+///
+/// On the real axis, the real part is evaluated with the real-valued implementation, which handles the poles of the Gamma functions.
+///
+/// [lngamma]: crate::special::Gamma::lngamma
+pub(crate) fn c_lbeta<T>(a: Complex<T>, b: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + RealBetaConsts + RealGamma + SubAssign + AddAssign,
+    Complex<T>: Gamma,
+{
+    let c = a + b;
+    if a.im.is_zero() && b.im.is_zero() {
+        let re = r_lbeta(a.re, b.re);
+        if is_gamma_pole(a.re) || is_gamma_pole(b.re) || is_gamma_pole(c.re) {
+            // Choose the principal branch, since the Gamma functions are not individually defined
+            let im = if r_beta(a.re, b.re) < T::zero() {
+                T::PI()
+            } else {
+                T::zero()
+            };
+            return Complex::new(re, im);
+        }
+        let im = a.lngamma().im + b.lngamma().im - c.lngamma().im;
+        return Complex::new(re, im);
+    }
+
+    if is_gamma_pole(a) || is_gamma_pole(b) {
+        return Complex::new(T::infinity(), T::zero());
+    }
+    if is_gamma_pole(c) {
+        return Complex::new(T::neg_infinity(), T::zero());
+    }
+
+    let (mut a, mut b) = (a, b);
+    if a.norm() < b.norm() {
+        mem::swap(&mut a, &mut b);
+    }
+    // Avoid the loss of precision in lngamma(a+b) - lngamma(a)
+    if a.re > T::ASYMP_FACTOR && a.norm() > T::ASYMP_FACTOR * b.norm() {
+        return c_lbeta_asymp(a, b);
+    }
+
+    a.lngamma() + b.lngamma() - c.lngamma()
+}
+
+/// Beta function for complex-valued arguments.
+/// $$
+/// B(a,b) = \frac{\Gamma(a)\Gamma(b)}{\Gamma(a+b)}
+/// $$
+/// On the real axis, we defer to the real-valued implementation. Otherwise, the Beta function is evaluated as $e^{\ln B(a,b)}$, which avoids overflow of the individual Gamma functions for large arguments.
+pub(crate) fn c_beta<T>(a: Complex<T>, b: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + RealBetaConsts + RealGamma + SubAssign + AddAssign,
+    Complex<T>: Gamma,
+{
+    if a.im.is_zero() && b.im.is_zero() {
+        return Complex::new(r_beta(a.re, b.re), T::zero());
+    }
+    if is_gamma_pole(a) || is_gamma_pole(b) {
+        return Complex::new(T::infinity(), T::zero());
+    }
+    if is_gamma_pole(a + b) {
+        return Complex::zero();
+    }
+    c_lbeta(a, b).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    #[test]
+    fn test_c_beta() {
+        const KNOWN_VALUES: [[Complex64; 3]; 8] = [
+            // Values from mpmath (v 1.3.0)
+            [
+                Complex { re: 1.5, im: 0.5 },
+                Complex { re: 2.0, im: -1.0 },
+                Complex {
+                    re: 0.18197428289903986,
+                    im: 0.01912745247293244,
+                },
+            ],
+            [
+                Complex { re: 0.5, im: 3.0 },
+                Complex { re: 0.5, im: -3.0 },
+                Complex {
+                    re: 0.0005070500197921,
+                    im: 0.0,
+                },
+            ],
+            [
+                Complex { re: -2.5, im: 1.2 },
+                Complex { re: 1.0, im: 0.0 },
+                Complex {
+                    re: -0.32509752925877766,
+                    im: -0.15604681404421325,
+                },
+            ],
+            [
+                Complex { re: 10.0, im: 20.0 },
+                Complex { re: 5.0, im: -7.0 },
+                Complex {
+                    re: -7.512575752683533e-11,
+                    im: -1.1382460242082844e-10,
+                },
+            ],
+            [
+                Complex { re: 0.1, im: 0.1 },
+                Complex { re: 0.2, im: 0.0 },
+                Complex {
+                    re: 9.592183698412938,
+                    im: -5.103971486587702,
+                },
+            ],
+            [
+                Complex { re: -3.7, im: -0.4 },
+                Complex { re: 2.2, im: 5.1 },
+                Complex {
+                    re: 22.55542317386517,
+                    im: 6.416704601323814,
+                },
+            ],
+            [
+                Complex {
+                    re: 150.0,
+                    im: 30.0,
+                },
+                Complex { re: 80.0, im: 10.0 },
+                Complex {
+                    re: -1.1012244250891137e-66,
+                    im: 8.633010269293344e-66,
+                },
+            ],
+            [
+                Complex {
+                    re: 3000000.0,
+                    im: 100000.0,
+                },
+                Complex { re: 0.5, im: 0.25 },
+                Complex {
+                    re: -0.0004412116919566306,
+                    im: 0.0007802171278825391,
+                },
+            ],
+        ];
+
+        for values in KNOWN_VALUES {
+            let [a, b, expected] = values;
+            // The absolute error in ln(B) becomes a relative error in B
+            let tol = PRECISION * expected.norm() * c_lbeta(a, b).norm().max(1.0);
+            assert_almost_eq!(c_beta(a, b), expected, tol);
+            assert_almost_eq!(c_beta(b, a), expected, tol);
+        }
+
+        // Values along the real axis match the real-valued implementation
+        let one = Complex64::new(1.0, 0.0);
+        assert_eq!(c_beta(one * 2.3, one * -1.7).re, r_beta(2.3, -1.7));
+        assert_eq!(c_beta(one * -2.0, one).re, -0.5);
+
+        let a = Complex64::new(-2.0, 0.0);
+        assert_eq!(c_beta(a, Complex64::new(1.5, 2.0)).re, f64::INFINITY);
+        let a = Complex64::new(-1.5, 2.0);
+        assert_eq!(c_beta(a, Complex64::new(-0.5, -2.0)), Complex64::zero());
+    }
+
+    #[test]
+    fn test_c_lbeta() {
+        const KNOWN_VALUES: [[Complex64; 3]; 8] = [
+            // Values from mpmath (v 1.3.0)
+            [
+                Complex { re: 1.5, im: 0.5 },
+                Complex { re: 2.0, im: -1.0 },
+                Complex {
+                    re: -1.698396063409757,
+                    im: 0.10472619386740296,
+                },
+            ],
+            [
+                Complex { re: 0.5, im: 3.0 },
+                Complex { re: 0.5, im: -3.0 },
+                Complex {
+                    re: -7.586900900872446,
+                    im: 0.0,
+                },
+            ],
+            [
+                Complex { re: -2.5, im: 1.2 },
+                Complex { re: 1.0, im: 0.0 },
+                Complex {
+                    re: -1.0199603917587763,
+                    im: -2.694072678432623,
+                },
+            ],
+            [
+                Complex { re: 10.0, im: 20.0 },
+                Complex { re: 5.0, im: -7.0 },
+                Complex {
+                    re: -22.715564848441705,
+                    im: 4.12900667193199,
+                },
+            ],
+            [
+                Complex { re: 0.1, im: 0.1 },
+                Complex { re: 0.2, im: 0.0 },
+                Complex {
+                    re: 2.385598657695042,
+                    im: -0.48899423344224663,
+                },
+            ],
+            [
+                Complex { re: -3.7, im: -0.4 },
+                Complex { re: 2.2, im: 5.1 },
+                Complex {
+                    re: 3.154887508976067,
+                    im: 19.126719729458223,
+                },
+            ],
+            [
+                Complex {
+                    re: 150.0,
+                    im: 30.0,
+                },
+                Complex { re: 80.0, im: 10.0 },
+                Complex {
+                    re: -149.80695261789356,
+                    im: -23.435070360809394,
+                },
+            ],
+            [
+                Complex {
+                    re: 3000000.0,
+                    im: 100000.0,
+                },
+                Complex { re: 0.5, im: 0.25 },
+                Complex {
+                    re: -7.017202497432183,
+                    im: -4.197724572161068,
+                },
+            ],
+        ];
+
+        for values in KNOWN_VALUES {
+            let [a, b, expected] = values;
+            assert_almost_eq!(c_lbeta(a, b), expected, PRECISION * expected.norm());
+            assert_almost_eq!(c_lbeta(b, a), expected, PRECISION * expected.norm());
+            assert_almost_eq!(
+                c_lbeta(a, b).exp(),
+                c_beta(a, b),
+                PRECISION * c_beta(a, b).norm()
+            );
+        }
+
+        let one = Complex64::new(1.0, 0.0);
+        assert_eq!(c_lbeta(one * 2.3, one * 1.7), one * r_lbeta(2.3, 1.7));
+        let lbeta = c_lbeta(one * -2.0, one);
+        assert_eq!(lbeta.re, r_lbeta(-2.0, 1.0));
+        assert_eq!(lbeta.im, std::f64::consts::PI);
+        // The branch is consistent with the sum of log-Gamma functions
+        let lbeta = c_lbeta(one * -2.5, one * 1.2);
+        assert_almost_eq!(lbeta.exp().re, r_beta(-2.5, 1.2), PRECISION);
+    }
+}
@@ -10,10 +10,18 @@ pub(crate) mod beta_util;
 
 pub use beta_trait::*;
 
+mod c_beta;
 mod r_beta;
+mod r_betainc;
+mod r_betaincinv;
 mod r_lbeta;
 
 mod real_beta_impl {
     pub(crate) use super::r_beta::*;
+    pub(crate) use super::r_betainc::*;
+    pub(crate) use super::r_betaincinv::*;
     pub(crate) use super::r_lbeta::*;
 }
+mod complex_beta_impl {
+    pub(crate) use super::c_beta::*;
+}
@@ -118,4 +118,24 @@ mod tests {
             assert_eq!(r_beta(values[0], values[1]), r_beta(values[1], values[0]));
         }
     }
+
+    #[test]
+    fn test_beta_f32_max_gamma() {
+        // Values from mpmath (v 1.3.0). Gamma(a + b) overflows in f32 above 35.04, such that
+        // the last three evaluate the logarithms instead
+        const KNOWN_VALUES: [[f64; 3]; 4] = [
+            [17.5, 17.0, 3.55174873348667e-11],
+            [18.0, 17.5, 1.7501370570803882e-11],
+            [20.0, 30.0, 1.7681885473062026e-15],
+            [0.5, 40.0, 0.28112668765619286],
+        ];
+
+        for values in KNOWN_VALUES {
+            assert_almost_eq!(
+                r_beta(values[0] as f32, values[1] as f32) / values[2] as f32,
+                1.0,
+                1e-5
+            );
+        }
+    }
 }
@@ -5,8 +5,9 @@
 
 use crate::special::beta_util::{r_lbeta_asymp, RealBetaConsts};
 use crate::special::gamma_util::is_gamma_pole;
+use crate::special::tools::log1pmx;
 use crate::special::RealGamma;
-use num_traits::{Float, FloatConst};
+use num_traits::{cast, Float, FloatConst};
 use std::mem;
 use std::ops::{AddAssign, SubAssign};
 
@@ -29,6 +30,10 @@ where
         return r_lbeta_asymp(a, b);
     }
 
+    if a > T::MAX_GAMMA && b > T::zero() {
+        return b.lgamma() - lgamma_difference(a, b);
+    }
+
     let y = a + b;
     if y.abs() > T::MAX_GAMMA || a.abs() > T::MAX_GAMMA || b.abs() > T::MAX_GAMMA {
         let sign = y.gammasgn() * a.gammasgn() * b.gammasgn();
@@ -48,6 +53,25 @@ where
     }
 }
 
+/// Computes $\ln\Gamma(a+b) - \ln\Gamma(a)$ for large $a \geq b > 0$ from the Stirling series,
+/// $$
+/// b\ln a + a\left[\ln\left(1+\frac{b}{a}\right) - \frac{b}{a}\right] + \left(b - \frac{1}{2}\right)\ln\left(1+\frac{b}{a}\right) + \frac{1}{12}\left(\frac{1}{a+b} - \frac{1}{a}\right) - \frac{1}{360}\left(\frac{1}{(a+b)^3} - \frac{1}{a^3}\right)
+/// $$
+/// which avoids the cancellation between the two log-Gamma functions.
+fn lgamma_difference<T>(a: T, b: T) -> T
+where
+    T: Float,
+{
+    let c = a + b;
+    let s = b / a;
+    let correction = |z: T| {
+        let rz = z.recip();
+        rz * (cast::<f64, T>(1.0 / 12.0).unwrap() - rz * rz * cast::<f64, T>(1.0 / 360.0).unwrap())
+    };
+    b * a.ln() + a * log1pmx(s) + (b - cast::<f64, T>(0.5).unwrap()) * s.ln_1p() + correction(c)
+        - correction(a)
+}
+
 fn r_lbeta_negint<T>(a: T, b: T) -> T
 where
     T: Float + FloatConst + RealBetaConsts + RealGamma + SubAssign + AddAssign,
@@ -84,13 +108,18 @@ mod tests {
             const KNOWN_VALUES: [[f64; 3]; 5] = [
                 [1.1e6, 0.5, -6.3830453123232357981465], // check asymptote (From SciPy v 1.10.1)
                 [1.1e6, 0.7, -9.4767071744518105447241],
-                [122000.64, 1.1, -12.9328326184768229722977],
-                [142346.1124, 11551.51221, -41022.7405061810277402400970],
+                // From mpmath (v 1.3.0)
+                [122000.64, 1.1, -12.932832618640008458],
+                [142346.1124, 11551.51221, -41022.740506181139238],
                 [-2.0, 1.0, -std::f64::consts::LN_2],
             ];
 
             for values in KNOWN_VALUES {
-                assert_almost_eq!(r_lbeta(values[0], values[1]), values[2], PRECISION);
+                assert_almost_eq!(
+                    r_lbeta(values[0], values[1]),
+                    values[2],
+                    PRECISION * values[2].abs().max(1.0)
+                );
                 assert_eq!(r_lbeta(values[0], values[1]), r_lbeta(values[1], values[0]));
             }
 
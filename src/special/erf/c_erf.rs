@@ -0,0 +1,325 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::erf::real_erf_impl::*;
+use crate::special::faddeeva::complex_faddeeva_impl::c_faddeeva_w;
+use crate::special::faddeeva::real_faddeeva_impl::{r_erfi, w_im};
+use crate::special::Erf;
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// Error function for complex-valued arguments.
+///
+/// Evaluated in terms of the Faddeeva function, following the [Faddeeva Package] of S. G. Johnson. Taylor series are used near the origin and near the imaginary axis to avoid cancellation, and the mirror symmetries of $w(z)$ are used for $\Re(z) < 0$.
+///
+/// [Faddeeva Package]: http://ab-initio.mit.edu/faddeeva
+pub(crate) fn c_erf<T>(z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Erf + RealErfConsts,
+{
+    let c = |v: f64| cast::<f64, T>(v).unwrap();
+    let x = z.re;
+    let y = z.im;
+
+    if y.is_zero() {
+        return Complex::new(r_erf(x, false), y);
+    }
+    if x.is_zero() {
+        return Complex::new(x, r_erfi(y));
+    }
+
+    let mre_z2 = (y - x) * (x + y);
+    let mim_z2 = c(-2.0) * x * y;
+    let exp_mre_z2 = mre_z2.exp();
+    if exp_mre_z2.is_zero() {
+        return Complex::new(T::one().copysign(x), T::zero());
+    }
+
+    if x.abs() < c(8e-2) {
+        if y.abs() < c(1e-2) {
+            // erf(z) = 2/sqrt(pi) * z * (1 - z^2/3 + z^4/10 - z^6/42 + z^8/216)
+            let mz2 = Complex::new(mre_z2, mim_z2);
+            return z
+                * (mz2
+                    * (mz2
+                        * (mz2
+                            * (mz2 * c(0.0052239776254421878422) + c(0.026866170645131251760))
+                            + c(0.11283791670955125739))
+                        + c(0.37612638903183752464))
+                    + T::FRAC_2_SQRT_PI());
+        }
+        if mim_z2.abs() < c(5e-3) && x.abs() < c(5e-3) {
+            // Expansion about the imaginary axis
+            let x2 = x * x;
+            let y2 = y * y;
+            let exp_y2 = exp_sqr(y, false);
+            return Complex::new(
+                exp_y2
+                    * x
+                    * (T::FRAC_2_SQRT_PI()
+                        - x2 * (c(0.37612638903183752464) + c(0.75225277806367504925) * y2)
+                        + x2 * x2
+                            * (c(0.11283791670955125739)
+                                + y2 * (c(0.45135166683820502956)
+                                    + c(0.15045055561273500986) * y2))),
+                exp_y2
+                    * (w_im(y)
+                        - x2 * y
+                            * (T::FRAC_2_SQRT_PI()
+                                - x2 * (c(0.56418958354775628695)
+                                    + c(0.37612638903183752464) * y2))),
+            );
+        }
+    } else if x.is_nan() {
+        return Complex::new(T::nan(), T::nan());
+    }
+
+    // Avoid the complex exponential, which produces spurious NaN when w overflows
+    let phase = Complex::new(mim_z2.cos(), mim_z2.sin());
+    if x >= T::zero() {
+        -(phase * c_faddeeva_w(Complex::new(-y, x))) * exp_mre_z2 + T::one()
+    } else {
+        phase * c_faddeeva_w(Complex::new(y, -x)) * exp_mre_z2 - T::one()
+    }
+}
+
+/// Complementary error function for complex-valued arguments.
+///
+/// Evaluated as $\mathrm{erfc}(z) = e^{-z^2}w(iz)$ for $\Re(z) \geq 0$ and $\mathrm{erfc}(z) = 2 - e^{-z^2}w(-iz)$ otherwise, following the [Faddeeva Package] of S. G. Johnson.
+///
+/// [Faddeeva Package]: http://ab-initio.mit.edu/faddeeva
+pub(crate) fn c_erfc<T>(z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Erf + RealErfConsts,
+{
+    let x = z.re;
+    let y = z.im;
+    let two = T::one() + T::one();
+
+    if x.is_zero() {
+        return Complex::new(T::one(), -r_erfi(y));
+    }
+    if y.is_zero() {
+        return Complex::new(r_erf(x, true), -y);
+    }
+
+    let mz2 = Complex::new((y - x) * (x + y), -two * x * y);
+    let exp_mre_z2 = mz2.re.exp();
+    if exp_mre_z2.is_zero() {
+        let re = if x >= T::zero() { T::zero() } else { two };
+        return Complex::new(re, T::zero());
+    }
+
+    // Avoid the complex exponential, which produces spurious NaN when it overflows
+    let phase = Complex::new(mz2.im.cos(), mz2.im.sin());
+    if x >= T::zero() {
+        phase * c_faddeeva_w(Complex::new(-y, x)) * exp_mre_z2
+    } else {
+        -(phase * c_faddeeva_w(Complex::new(y, -x)) * exp_mre_z2) + two
+    }
+}
+
+/// Scaled complementary error function for complex-valued arguments.
+/// $$
+/// \mathrm{erfcx}(z) = e^{z^2}\mathrm{erfc}(z) = w(iz)
+/// $$
+pub(crate) fn c_erfcx<T>(z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Erf + RealErfConsts,
+{
+    if z.im.is_zero() {
+        return Complex::new(r_erfcx(z.re), z.im);
+    }
+    c_faddeeva_w(Complex::new(-z.im, z.re))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Columns are z, erf(z), erfc(z) and erfcx(z)
+    const KNOWN_VALUES: [[Complex64; 4]; 8] = [
+        // Values from mpmath (v 1.3.0)
+        [
+            Complex { re: 1.0, im: 2.0 },
+            Complex {
+                re: -0.536643565778565,
+                im: -5.049143703447035,
+            },
+            Complex {
+                re: 1.536643565778565,
+                im: 5.049143703447035,
+            },
+            Complex {
+                re: 0.14023958136627795,
+                im: -0.2222134401798991,
+            },
+        ],
+        [
+            Complex { re: -3.0, im: 0.5 },
+            Complex {
+                re: -1.0000280653614764,
+                im: -2.6284897222588233e-07,
+            },
+            Complex {
+                re: 2.0000280653614766,
+                im: 2.6284897222588233e-07,
+            },
+            Complex {
+                re: -12495.242856000212,
+                im: -1781.1553495221087,
+            },
+        ],
+        [
+            Complex {
+                re: 0.01,
+                im: 0.005,
+            },
+            Complex {
+                re: 0.011283697625958948,
+                im: 0.005641378676150063,
+            },
+            Complex {
+                re: 0.9887163023740411,
+                im: -0.005641378676150063,
+            },
+            Complex {
+                re: 0.9887910181137796,
+                im: -0.005542922721485583,
+            },
+        ],
+        [
+            Complex { re: 0.001, im: 2.5 },
+            Complex {
+                re: 0.5845122493316632,
+                im: 130.3942937298228,
+            },
+            Complex {
+                re: 0.41548775066833676,
+                im: -130.3942937298228,
+            },
+            Complex {
+                re: 0.002060667855708547,
+                im: -0.2517132985048851,
+            },
+        ],
+        [
+            Complex { re: -0.05, im: 4.0 },
+            Complex {
+                re: -487684.81529332144,
+                im: 1198143.5402942435,
+            },
+            Complex {
+                re: 487685.81529332144,
+                im: -1198143.5402942435,
+            },
+            Complex {
+                re: -0.0019619630645824975,
+                im: -0.14592602884458344,
+            },
+        ],
+        [
+            Complex { re: 5.0, im: -5.0 },
+            Complex {
+                re: 0.9303796037430951,
+                im: -0.03893619089512138,
+            },
+            Complex {
+                re: 0.06962039625690489,
+                im: 0.03893619089512138,
+            },
+            Complex {
+                re: 0.056965439888176976,
+                im: 0.055838742775391026,
+            },
+        ],
+        [
+            Complex { re: 2.5, im: -0.1 },
+            Complex {
+                re: 0.999646645651895,
+                im: -0.00020953945247637892,
+            },
+            Complex {
+                re: 0.0003533543481050246,
+                im: 0.00020953945247637892,
+            },
+            Complex {
+                re: 0.21055723058309828,
+                im: 0.007426740439624053,
+            },
+        ],
+        [
+            Complex { re: 0.0, im: 1.5 },
+            Complex {
+                re: 0.0,
+                im: 4.584733257284427,
+            },
+            Complex {
+                re: 1.0,
+                im: -4.584733257284427,
+            },
+            Complex {
+                re: 0.10539922456186433,
+                im: -0.4832273301407691,
+            },
+        ],
+    ];
+
+    #[test]
+    fn test_c_erf() {
+        for [z, expected, _, _] in KNOWN_VALUES {
+            assert_almost_eq!(c_erf(z), expected, PRECISION * expected.norm());
+            // Error function is odd
+            assert_almost_eq!(c_erf(-z), -expected, PRECISION * expected.norm());
+        }
+
+        let one = Complex64::new(1.0, 0.0);
+        assert_eq!(c_erf(one * 0.3).re, r_erf(0.3, false));
+        assert_eq!(c_erf(Complex64::new(30.0, 0.5)), one);
+        assert_eq!(c_erf(Complex64::new(-30.0, 0.5)), -one);
+
+        // Values from mpmath (v 1.3.0)
+        let z = Complex64::new(0.0, 1e-10);
+        let expected = Complex64::new(0.0, 1.1283791670955126150e-10);
+        assert_almost_eq!(c_erf(z), expected, PRECISION * expected.norm());
+        let z = Complex64::new(1e-12, 1e-10);
+        let expected = Complex64::new(1.1283791670955125512e-12, 1.1283791670955126150e-10);
+        assert_almost_eq!(c_erf(z), expected, PRECISION * expected.norm());
+    }
+
+    #[test]
+    fn test_c_erfc() {
+        for [z, _, expected, _] in KNOWN_VALUES {
+            assert_almost_eq!(c_erfc(z), expected, PRECISION * expected.norm());
+            assert_almost_eq!(
+                c_erfc(-z),
+                -expected + 2.0,
+                PRECISION * expected.norm().max(1.0)
+            );
+        }
+
+        let one = Complex64::new(1.0, 0.0);
+        assert_eq!(c_erfc(one * 0.3).re, r_erf(0.3, true));
+        assert_eq!(c_erfc(Complex64::new(30.0, 0.5)), one * 0.0);
+        assert_eq!(c_erfc(Complex64::new(-30.0, 0.5)), one * 2.0);
+    }
+
+    #[test]
+    fn test_c_erfcx() {
+        for [z, _, _, expected] in KNOWN_VALUES {
+            assert_almost_eq!(c_erfcx(z), expected, PRECISION * expected.norm());
+        }
+
+        let one = Complex64::new(1.0, 0.0);
+        assert_eq!(c_erfcx(one * 0.3).re, r_erfcx(0.3));
+        // Does not underflow, unlike erfc
+        let z = Complex64::new(30.0, 20.0);
+        let expected = Complex64::new(0.01302090842413885, -0.008673934748446674);
+        assert_almost_eq!(c_erfcx(z), expected, PRECISION * expected.norm());
+    }
+}
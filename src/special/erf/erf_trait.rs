@@ -3,9 +3,12 @@
 // Copyright 2023 Matthew R. Hennefarth                                *
 //**********************************************************************
 
+use crate::special::erf::complex_erf_impl::*;
 use crate::special::erf::real_erf_impl::*;
 
-/// Error and related functions.
+use num_complex::Complex;
+
+/// Error and related functions for both real and complex-valued arguments.
 pub trait Erf {
     /// The Error Function.
     /// $$
@@ -20,16 +23,33 @@ pub trait Erf {
     /// assert!((2.0_f32.erf() - 0.9953222650189527341).abs() < 1e-14);
     /// assert_eq!((-3.2).erf(), -(3.2.erf())); // Error Function is odd!
     /// ```
+    /// For complex-valued inputs
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(1.0, 2.0);
+    /// let expected = Complex64::new(-0.5366435657785650, -5.0491437034470346);
+    /// assert!((z.erf() - expected).norm() < 1e-14 * expected.norm());
+    /// ```
     /// # Notes
     /// The real-valued implementation is based on the [Boost] library version (v 1.82.0) ([documentation](https://www.boost.org/doc/libs/1_82_0/libs/math/doc/html/math_toolkit/sf_erf/error_function.html)). Largely, it uses various rational polynomial approximations. Additionally, it uses the fact that the Error function is odd to always evaluate at positive arguments. At times, it will use the [Compliment Error function] to evaluate.
     ///
+    /// For complex-valued arguments, the Error function is evaluated in terms of the [Faddeeva function] $w(z)$ following the [Faddeeva Package] of S. G. Johnson,
+    /// $$
+    /// \mathrm{erf}z = 1 - e^{-z^2}w(iz)
+    /// $$
+    /// where the mirror symmetries of $w(z)$ are used to avoid cancellation for $\Re z < 0$. Near the origin and the imaginary axis, Taylor series are used instead.
+    ///
     /// # References
     /// - [Boost Error function implementation][Boost] version 1.82.0
+    /// - [Faddeeva Package]
     /// - [DLMF]
     /// - [Wikipedia][wiki]
     ///
     /// [comment]: <> (Reference hyperlinks)
     /// [Boost]: https://www.boost.org/doc/libs/1_82_0/boost/math/special_functions/erf.hpp
+    /// [Faddeeva function]: https://dlmf.nist.gov/7.2#E3
+    /// [Faddeeva Package]: http://ab-initio.mit.edu/faddeeva
     /// [DLMF]: https://dlmf.nist.gov/7.2
     /// [wiki]: https://en.wikipedia.org/wiki/Error_function
     /// [Compliment Error function]: crate::special::erf::Erf::erfc
@@ -49,9 +69,18 @@ pub trait Erf {
     /// assert_eq!((-3.2).erfc(), 1.0 + (3.2.erf())); // Error and Complementary are related
     /// assert_eq!(3.0.erfc() + 3.0.erf(), 1.0);
     /// ```
+    /// For complex-valued inputs
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(-3.0, 0.5);
+    /// assert!((z.erfc() + z.erf() - 1.0).norm() < 1e-14);
+    /// ```
     /// # Notes
     /// The real-valued implementation is based on the [Boost] library (v 1.82.0) ([documentation](https://www.boost.org/doc/libs/1_82_0/libs/math/doc/html/math_toolkit/sf_erf/error_function.html)).
     ///
+    /// For complex-valued arguments, we use $\mathrm{erfc}z = e^{-z^2}w(iz)$ for $\Re z \geq 0$ and $\mathrm{erfc}z = 2 - e^{-z^2}w(-iz)$ otherwise, where $w(z)$ is the Faddeeva function. See [Error function] for more details.
+    ///
     /// [comment]: <> (Reference hyperlinks)
     /// [Boost]: https://www.boost.org/doc/libs/1_82_0/boost/math/special_functions/erf.hpp
     /// [wiki]: https://en.wikipedia.org/wiki/Error_function
@@ -59,6 +88,41 @@ pub trait Erf {
     /// [Error function]: crate::special::erf::Erf::erf
     fn erfc(self) -> Self;
 
+    /// The Scaled Complementary Error Function.
+    /// $$
+    /// \mathrm{erfcx}z = e^{z^2}\mathrm{erfc}z
+    /// $$
+    /// For large positive $\Re z$, $\mathrm{erfc}z$ underflows while $\mathrm{erfcx}z \sim 1/(z\sqrt{\pi})$ remains representable.
+    ///
+    /// # Examples
+    /// For real-valued inputs
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// assert_eq!(0.0_f64.erfcx(), 1.0);
+    /// let x = 1.0e10_f64;
+    /// assert!((x.erfcx() * x * std::f64::consts::PI.sqrt() - 1.0).abs() < 1e-15);
+    /// ```
+    /// For complex-valued inputs
+    /// ```
+    /// use sci_rs::special::Erf;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(30.0, 20.0);
+    /// let expected = Complex64::new(0.0130209084241388, -0.0086739347484467);
+    /// assert!((z.erfcx() - expected).norm() < 1e-14 * expected.norm());
+    /// ```
+    /// # Notes
+    /// For real-valued arguments with $x \geq 0.5$, the rational approximations of [Boost] for $xe^{x^2}\mathrm{erfc}x$ are used directly. For smaller arguments, we compute $e^{x^2}\mathrm{erfc}x$, and for $x < 0$ we use $\mathrm{erfcx}(x) = 2e^{x^2} - \mathrm{erfcx}(-x)$.
+    ///
+    /// For complex-valued arguments, we use the Faddeeva function $\mathrm{erfcx}z = w(iz)$, see [Error function] for more details.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Boost]: https://www.boost.org/doc/libs/1_82_0/boost/math/special_functions/erf.hpp
+    /// [Error function]: crate::special::erf::Erf::erf
+    fn erfcx(self) -> Self;
+}
+
+/// Error related functions that are only defined for real-valued arguments.
+pub trait RealErf: Erf {
     /// Inverse of the Error Function.
     /// $$
     /// \mathrm{erf}y = z
@@ -67,7 +131,7 @@ pub trait Erf {
     /// # Examples
     /// For real-valued inputs
     /// ```
-    /// use sci_rs::special::Erf;
+    /// use sci_rs::special::{Erf, RealErf};
     /// assert_eq!(1.0_f32.erf().erf_inv(), 1.0);
     /// assert_eq!(0.5_f64.erf_inv().erf(), 0.5);
     /// ```
@@ -86,7 +150,7 @@ pub trait Erf {
     /// # Examples
     /// For real-valued inputs
     /// ```
-    /// use sci_rs::special::Erf;
+    /// use sci_rs::special::{Erf, RealErf};
     /// assert_eq!(1.0_f32.erfc().erfc_inv(), 1.0);
     /// assert_eq!(1.5_f64.erfc_inv().erfc(), 1.5);
     /// assert_eq!(0.5_f32.erfc_inv(), 0.5_f32.erf_inv());
@@ -98,6 +162,25 @@ pub trait Erf {
     /// [comment]: <> (Reference hyperlinks)
     /// [Boost]: https://www.boost.org/doc/libs/1_82_0/boost/math/special_functions/detail/erf_inv.hpp
     fn erfc_inv(self) -> Self;
+
+    /// Inverse of the Scaled Complementary Error Function
+    /// $$
+    /// \mathrm{erfcx}y = z
+    /// $$
+    /// Returns the value $y$ such that $\mathrm{erfcx}y = e^{y^2}\mathrm{erfc}y = z$. The valid domain is $z \geq 0$.
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::{Erf, RealErf};
+    /// assert_eq!(1.0_f64.erfcx_inv(), 0.0);
+    /// assert!((0.5_f64.erfcx_inv().erfcx() - 0.5).abs() < 1e-15);
+    /// assert!((1.0e-10_f64.erfcx_inv() - 5.641895835477563e9).abs() < 1e-5);
+    /// ```
+    /// # Notes
+    /// The inverse is computed with Newton's method on $\ln\mathrm{erfcx}y$, which is convex, starting from an initial guess obtained from bounds on the [Scaled Complementary Error function]. For very small $z$, the asymptotic expansion $\mathrm{erfcx}y \sim \frac{1}{y\sqrt{\pi}}\left(1 - \frac{1}{2y^2}\right)$ is inverted directly.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Scaled Complementary Error function]: crate::special::Erf::erfcx
+    fn erfcx_inv(self) -> Self;
 }
 
 macro_rules! float_erf_impl {
@@ -113,6 +196,13 @@ macro_rules! float_erf_impl {
                 r_erf(self, true)
             }
 
+            #[inline(always)]
+            fn erfcx(self) -> Self {
+                r_erfcx(self)
+            }
+        }
+
+        impl RealErf for $T {
             #[inline(always)]
             fn erf_inv(self) -> Self {
                 r_erf_inv(self)
@@ -122,8 +212,36 @@ macro_rules! float_erf_impl {
             fn erfc_inv(self) -> Self {
                 r_erfc_inv(self)
             }
+
+            #[inline(always)]
+            fn erfcx_inv(self) -> Self {
+                r_erfcx_inv(self)
+            }
         }
     )*)
 }
 
 float_erf_impl! {f32 f64}
+
+macro_rules! float_complexerf_impl {
+    ($($T: ty)*) => ($(
+        impl Erf for Complex<$T> {
+            #[inline(always)]
+            fn erf(self) -> Self {
+                c_erf(self)
+            }
+
+            #[inline(always)]
+            fn erfc(self) -> Self {
+                c_erfc(self)
+            }
+
+            #[inline(always)]
+            fn erfcx(self) -> Self {
+                c_erfcx(self)
+            }
+        }
+    )*)
+}
+
+float_complexerf_impl! {f32 f64}
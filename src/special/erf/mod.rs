@@ -7,12 +7,16 @@
 
 mod erf_trait;
 
-pub use erf_trait::*;
+pub use erf_trait::{Erf, RealErf};
 
+mod c_erf;
 mod r_erf;
 mod r_erf_inv;
 
-mod real_erf_impl {
+pub(crate) mod real_erf_impl {
     pub(crate) use super::r_erf::*;
     pub(crate) use super::r_erf_inv::*;
 }
+pub(crate) mod complex_erf_impl {
+    pub(crate) use super::c_erf::*;
+}
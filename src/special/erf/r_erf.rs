@@ -116,6 +116,48 @@ macro_rules! impl_realerfconsts {
 
 impl_realerfconsts! {f32 f64}
 
+/// Rational approximation of $x e^{x^2}\mathrm{erfc}(x)$ for $x \geq 0.5$.
+fn erfc_rational<T>(x: T) -> T
+where
+    T: Float + RealErfConsts,
+{
+    if x < T::from(1.5).unwrap() {
+        T::Y[1]
+            + eval_poly(x - T::from(0.5).unwrap(), &T::P_2)
+                / eval_poly(x - T::from(0.5).unwrap(), &T::Q_2)
+    } else if x < T::from(2.5).unwrap() {
+        T::Y[2]
+            + eval_poly(x - T::from(1.5).unwrap(), &T::P_3)
+                / eval_poly(x - T::from(1.5).unwrap(), &T::Q_3)
+    } else if x < T::from(4.5).unwrap() {
+        T::Y[3]
+            + eval_poly(x - T::from(3.5).unwrap(), &T::P_4)
+                / eval_poly(x - T::from(3.5).unwrap(), &T::Q_4)
+    } else {
+        T::Y[4] + eval_poly(x.recip(), &T::P_5) / eval_poly(x.recip(), &T::Q_5)
+    }
+}
+
+/// Computes $e^{x^2}$, or $e^{-x^2}$ when `negative` is true, without the loss of precision from rounding $x^2$.
+///
+/// The argument is split into $x = x_{hi} + x_{lo}$ such that $x_{hi}^2$ is exact, and the error in $x^2$ is then applied as a second exponential.
+pub(crate) fn exp_sqr<T>(x: T, negative: bool) -> T
+where
+    T: Float,
+{
+    let (hi, expon): (T, i64) = frexp(x);
+    let hi = ldexp(hi, 32).floor();
+    let hi = ldexp(hi, expon - 32);
+    let lo = x - hi;
+    let x_sqr = x * x;
+    let err_sqr = ((hi * hi - x_sqr) + T::from(2.0).unwrap() * hi * lo) + lo * lo;
+    if negative {
+        (-x_sqr).exp() * (-err_sqr).exp()
+    } else {
+        x_sqr.exp() * err_sqr.exp()
+    }
+}
+
 /// Error and Complementary Error Function implementation.
 ///
 /// Implementation details (and polynomial values) taken from [Boost] v1.82.0.
@@ -154,28 +196,7 @@ where
     } {
         // We calculate erfc here
         compliment = !compliment;
-        let r = if x < T::from(1.5).unwrap() {
-            T::Y[1]
-                + eval_poly(x - T::from(0.5).unwrap(), &T::P_2)
-                    / eval_poly(x - T::from(0.5).unwrap(), &T::Q_2)
-        } else if x < T::from(2.5).unwrap() {
-            T::Y[2]
-                + eval_poly(x - T::from(1.5).unwrap(), &T::P_3)
-                    / eval_poly(x - T::from(1.5).unwrap(), &T::Q_3)
-        } else if x < T::from(4.5).unwrap() {
-            T::Y[3]
-                + eval_poly(x - T::from(3.5).unwrap(), &T::P_4)
-                    / eval_poly(x - T::from(3.5).unwrap(), &T::Q_4)
-        } else {
-            T::Y[4] + eval_poly(x.recip(), &T::P_5) / eval_poly(x.recip(), &T::Q_5)
-        };
-        let (hi, expon): (T, i64) = frexp(x);
-        let hi = ldexp(hi, 32).floor();
-        let hi = ldexp(hi, expon - 32);
-        let lo = x - hi;
-        let x_sqr = x * x;
-        let err_sqr = ((hi * hi - x_sqr) + T::from(2.0).unwrap() * hi * lo) + lo * lo;
-        r * (-x_sqr).exp() * (-err_sqr).exp() / x
+        erfc_rational(x) * exp_sqr(x, true) / x
     } else {
         compliment = !compliment;
         T::zero()
@@ -188,6 +209,36 @@ where
     }
 }
 
+/// Scaled Complementary Error Function implementation.
+/// $$
+/// \mathrm{erfcx}(x) = e^{x^2}\mathrm{erfc}(x)
+/// $$
+/// For $x \geq 0.5$, the rational approximations of [Boost] for $x e^{x^2}\mathrm{erfc}(x)$ are used directly, such that no overflow or underflow occurs. For negative arguments, we use $\mathrm{erfcx}(x) = 2e^{x^2} - \mathrm{erfcx}(-x)$.
+///
+/// [Boost]: https://www.boost.org/doc/libs/1_82_0/boost/math/special_functions/erf.hpp
+pub(crate) fn r_erfcx<T>(x: T) -> T
+where
+    T: Float + RealErfConsts,
+{
+    if x.is_nan() {
+        return x;
+    }
+    if x < T::zero() {
+        let exp_x2 = exp_sqr(x, false);
+        if exp_x2.is_infinite() {
+            return T::infinity();
+        }
+        return exp_x2 + exp_x2 - r_erfcx(-x);
+    }
+    if x < T::from(0.5).unwrap() {
+        return exp_sqr(x, false) * r_erf(x, true);
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+    erfc_rational(x) / x
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,4 +303,33 @@ mod tests {
             assert_almost_eq!(r_erf(-values[0], true), 2.0 - values[1], PRECISION);
         }
     }
+
+    #[test]
+    fn test_r_erfcx() {
+        const KNOWN_VALUES: [[f64; 2]; 9] = [
+            // Values from mpmath (v 1.3.0)
+            [-3.0, 16205.988853999586],
+            [-0.5, 1.952360489182557],
+            [0.2, 0.8090195199015807],
+            [0.5, 0.6156903441929259],
+            [1.0, 0.427583576155807],
+            [3.0, 0.17900115118138996],
+            [10.0, 0.05614099274382259],
+            [100.0, 0.005641613782989433],
+            [100000.0, 5.6418958351954685e-06],
+        ];
+
+        for [x, expected] in KNOWN_VALUES {
+            assert_almost_eq!(r_erfcx(x), expected, PRECISION * expected);
+        }
+
+        assert_eq!(r_erfcx(0.0), 1.0);
+        // Values from mpmath (v 1.3.0)
+        assert_almost_eq!(r_erfcx(1e-10), 0.99999999988716208330, PRECISION);
+        assert_almost_eq!(r_erfcx(-1e-10), 1.0000000001128379167, PRECISION);
+        assert_eq!(r_erfcx(1e-300), 1.0);
+        assert_eq!(r_erfcx(f64::INFINITY), 0.0);
+        assert_eq!(r_erfcx(-30.0), f64::INFINITY);
+        assert!(r_erfcx(f64::NAN).is_nan());
+    }
 }
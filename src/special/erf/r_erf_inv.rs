@@ -3,8 +3,10 @@
 // Copyright 2023 Matthew R. Hennefarth                                *
 //**********************************************************************
 
+use crate::special::erf::real_erf_impl::{r_erfcx, RealErfConsts};
 use crate::special::eval_poly;
-use num_traits::Float;
+use crate::traits::FloatSciConst;
+use num_traits::{cast, Float};
 
 pub(crate) trait RealErfInvConsts: Sized {
     const Y: [Self; 7];
@@ -271,6 +273,62 @@ where
     }
 }
 
+/// Inverse of the scaled complementary error function implementation.
+///
+/// Since $\ln\mathrm{erfcx}(x)$ is strictly convex and decreasing, Newton's method converges monotonically to the root from any initial guess to the left of it. Such a guess is obtained from the bounds
+/// $$
+/// \mathrm{erfcx}(x) > 2e^{x^2} - 1 \quad (x \leq 0), \qquad \mathrm{erfcx}(x) > \frac{2}{\sqrt{\pi}\left(x + \sqrt{x^2+2}\right)} \quad (x \geq 0)
+/// $$
+/// For very small $y$, the asymptotic expansion $\mathrm{erfcx}(x) \sim \frac{1}{x\sqrt{\pi}}\left(1 - \frac{1}{2x^2}\right)$ is inverted directly.
+pub(crate) fn r_erfcx_inv<T>(y: T) -> T
+where
+    T: Float + FloatSciConst + RealErfConsts,
+{
+    if y.is_nan() {
+        return y;
+    }
+    if y < T::zero() {
+        panic!("Domain error");
+    }
+    if y.is_zero() {
+        return T::infinity();
+    }
+    if y.is_infinite() {
+        return T::neg_infinity();
+    }
+    if y.is_one() {
+        return T::zero();
+    }
+
+    let one = T::one();
+    let two = one + one;
+    if y < cast::<f64, T>(5e-5).unwrap() {
+        let x = (y * T::SQRT_PI()).recip();
+        return x - (two * x).recip();
+    }
+
+    let mut x = if y > one {
+        -((y + one) / two).ln().sqrt()
+    } else {
+        let c = two / (T::SQRT_PI() * y);
+        c / two - c.recip()
+    };
+
+    let ln_y = y.ln();
+    let mut previous_step = T::infinity();
+    for _ in 0..100 {
+        let erfcx = r_erfcx(x);
+        let step = (erfcx.ln() - ln_y) / (two * x - two / (T::SQRT_PI() * erfcx));
+        x = x - step;
+        // The iterates increase monotonically, unless limited by rounding errors
+        if step.abs() <= T::epsilon() * x.abs() || step.abs() >= previous_step.abs() {
+            break;
+        }
+        previous_step = step;
+    }
+    x
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -323,4 +381,30 @@ mod tests {
             assert_almost_eq!(r_erfc_inv(2.0 - values[0]), -values[1], PRECISION);
         }
     }
+
+    #[test]
+    fn test_r_erfcx_inv() {
+        const KNOWN_VALUES: [[f64; 2]; 8] = [
+            // Values from mpmath (v 1.3.0)
+            [1e-6, 564189.58354687],
+            [0.01, 56.410097476729355],
+            [0.1, 5.554585892541129],
+            [0.5, 0.7690797710613142],
+            [0.9, 0.09627864776774965],
+            [1.5, -0.32258426187974604],
+            [2.0, -0.5151980774824834],
+            [100.0, -1.9785339942299513],
+        ];
+
+        for [y, expected] in KNOWN_VALUES {
+            assert_almost_eq!(r_erfcx_inv(y), expected, PRECISION * expected.abs());
+        }
+
+        assert_eq!(r_erfcx_inv(0.0), f64::INFINITY);
+        assert_eq!(r_erfcx_inv(1.0), 0.0);
+        assert_eq!(r_erfcx_inv(f64::INFINITY), f64::NEG_INFINITY);
+        for y in [1e-20, 1e-10, 0.3, 1e3] {
+            assert_almost_eq!(r_erfcx(r_erfcx_inv(y)), y, PRECISION * y);
+        }
+    }
 }
@@ -0,0 +1,175 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::expint::real_expint_impl::*;
+
+/// Exponential integrals for real-valued arguments.
+pub trait ExpInt {
+    /// Exponential integral $E_1(x)$.
+    /// $$
+    /// E_1(x) = \int_x^{\infty}\frac{e^{-t}}{t}dt
+    /// $$
+    /// for $x \geq 0$. Returns NaN for $x < 0$, where the integral is only defined as a principal value, see [expi] instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ExpInt;
+    /// assert!((1.0_f64.exp1() - 0.21938393439552029).abs() < 1e-16);
+    /// assert_eq!(0.0_f64.exp1(), f64::INFINITY);
+    /// ```
+    /// # Notes
+    /// Evaluated as $E_n(x)$ with $n = 1$, see [expn].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [expi]: crate::special::ExpInt::expi
+    /// [expn]: crate::special::ExpInt::expn
+    fn exp1(self) -> Self;
+
+    /// Exponential integral $\mathrm{Ei}(x)$.
+    /// $$
+    /// \mathrm{Ei}(x) = -\mathrm{P.V.}\int_{-x}^{\infty}\frac{e^{-t}}{t}dt
+    /// $$
+    /// where the integral is a Cauchy principal value for $x > 0$. For $x < 0$, $\mathrm{Ei}(x) = -E_1(-x)$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ExpInt;
+    /// assert!((1.0_f64.expi() - 1.8951178163559368).abs() < 1e-15);
+    /// assert!(((-1.0_f64).expi() + 0.21938393439552029).abs() < 1e-16);
+    /// assert_eq!(0.0_f64.expi(), f64::NEG_INFINITY);
+    /// ```
+    /// # Notes
+    /// For $x < 0$ we use [exp1]. Otherwise, we sum the power series
+    /// $$
+    /// \mathrm{Ei}(x) = \gamma + \ln x + \sum_{k=1}^{\infty}\frac{x^k}{k\,k!}
+    /// $$
+    /// which is recentered on the positive root $x_0 \approx 0.3725$ to retain the relative precision in its vicinity. For $x > -\ln\epsilon$, the asymptotic expansion
+    /// $$
+    /// \mathrm{Ei}(x) \sim \frac{e^x}{x}\sum_{k=0}^{\infty}\frac{k!}{x^k}
+    /// $$
+    /// is used instead.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [exp1]: crate::special::ExpInt::exp1
+    fn expi(self) -> Self;
+
+    /// Exponential integral $E_n(x)$ of integer order $n$.
+    /// $$
+    /// E_n(x) = \int_1^{\infty}\frac{e^{-xt}}{t^n}dt
+    /// $$
+    /// for $x \geq 0$. Returns NaN for $x < 0$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ExpInt;
+    /// assert!((1.0_f64.expn(2) - 0.14849550677592205).abs() < 1e-16);
+    /// assert!((0.5_f64.expn(5) - 0.13097731169586485).abs() < 1e-16);
+    /// assert_eq!(0.0_f64.expn(3), 0.5); // 1/(n-1)
+    /// ```
+    /// # Notes
+    /// Implementation follows that of [Numerical Recipes]. For $x > 1$, the continued fraction
+    /// $$
+    /// E_n(x) = e^{-x}\left(\frac{1}{x+n-}\frac{1\cdot n}{x+n+2-}\frac{2(n+1)}{x+n+4-}\cdots\right)
+    /// $$
+    /// is evaluated with the modified Lentz algorithm. Otherwise, we sum the power series
+    /// $$
+    /// E_n(x) = \frac{(-x)^{n-1}}{(n-1)!}\left(-\ln x + \psi(n)\right) - \sum_{k=0, k\neq n-1}^{\infty}\frac{(-x)^k}{(k-n+1)k!}
+    /// $$
+    /// where $\psi$ is the [digamma] function. To evaluate many orders at once, see [expn_all].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Numerical Recipes]: https://numerical.recipes/
+    /// [digamma]: crate::special::Gamma::digamma
+    /// [expn_all]: crate::special::ExpInt::expn_all
+    fn expn(self, n: u32) -> Self;
+
+    /// Generalized exponential integral $E_p(x)$ of real order $p$.
+    /// $$
+    /// E_p(x) = \int_1^{\infty}\frac{e^{-xt}}{t^p}dt = x^{p-1}\Gamma(1-p, x)
+    /// $$
+    /// for $x \geq 0$, where $\Gamma(a, x)$ is the upper incomplete gamma function. Returns NaN for $x < 0$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ExpInt;
+    /// assert!((1.0_f64.expint(0.5) - 0.27880558528066196).abs() < 1e-15);
+    /// assert!((2.0_f64.expint(-1.5) - 0.12911077108070337).abs() < 1e-15);
+    /// assert_eq!(1.0_f64.expint(2.0), 1.0_f64.expn(2));
+    /// ```
+    /// # Notes
+    /// Integer orders are evaluated with [expn]. For $p < 1/2$, we use the [upper incomplete gamma] function directly. Otherwise, the continued fraction of [expn] is used for $x > 1$. For $x \leq 1$, we sum the power series
+    /// $$
+    /// E_p(x) = x^{p-1}\Gamma(1-p) - \sum_{k=0}^{\infty}\frac{(-x)^k}{k!(k+1-p)}
+    /// $$
+    /// for an order $1/2 \leq p_0 < 3/2$ with $p - p_0$ an integer, where the terms are rearranged to avoid the cancellation close to $p_0 = 1$. Then, the upward recurrence
+    /// $$
+    /// E_{p+1}(x) = \frac{e^{-x} - xE_p(x)}{p}
+    /// $$
+    /// gives the requested order.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [expn]: crate::special::ExpInt::expn
+    /// [upper incomplete gamma]: crate::special::RealGamma::gamma_upper
+    fn expint(self, p: Self) -> Self;
+
+    /// Exponential integrals $E_n(x)$ for all orders $n = 0, 1, \ldots, n_{max}$.
+    ///
+    /// Returns a vector of length $n_{max} + 1$ where the $n$th element is $E_n(x)$. This is considerably cheaper than calling [expn] for each order.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ExpInt;
+    /// let values = 1.0_f64.expn_all(3);
+    /// assert_eq!(values.len(), 4);
+    /// for (n, value) in values.iter().enumerate() {
+    ///     assert!((value - 1.0_f64.expn(n as u32)).abs() < 1e-15);
+    /// }
+    /// ```
+    /// # Notes
+    /// Only the order $m$ closest to $x$ is evaluated directly with [expn]. The remaining orders follow from the recurrence
+    /// $$
+    /// nE_{n+1}(x) + xE_n(x) = e^{-x}
+    /// $$
+    /// applied upwards for $n > m$ and downwards for $n < m$, which are the stable directions.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [expn]: crate::special::ExpInt::expn
+    fn expn_all(self, n_max: u32) -> Vec<Self>
+    where
+        Self: Sized;
+}
+
+macro_rules! float_expint_impl {
+    ($($T: ty)*) => ($(
+        impl ExpInt for $T {
+            #[inline(always)]
+            fn exp1(self) -> Self {
+                r_exp1(self)
+            }
+
+            #[inline(always)]
+            fn expi(self) -> Self {
+                r_expi(self)
+            }
+
+            #[inline(always)]
+            fn expn(self, n: u32) -> Self {
+                r_expn(n, self)
+            }
+
+            #[inline(always)]
+            fn expint(self, p: Self) -> Self {
+                r_expint(p, self)
+            }
+
+            #[inline(always)]
+            fn expn_all(self, n_max: u32) -> Vec<Self> {
+                r_expn_all(n_max, self)
+            }
+        }
+    )*)
+}
+
+float_expint_impl! {f32 f64}
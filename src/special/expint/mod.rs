@@ -0,0 +1,16 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Exponential integrals.
+
+mod expint_trait;
+
+pub use expint_trait::*;
+
+mod r_expint;
+
+mod real_expint_impl {
+    pub(crate) use super::r_expint::*;
+}
@@ -0,0 +1,613 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::gamma::complex_gamma_impl::{LogGammaConsts, LogGammaTaylorCoeffs};
+use crate::special::gamma::real_gamma_impl::{lgam1p, RealGammaLnConsts};
+use crate::special::{Gamma, RealGamma};
+use crate::traits::FloatSciConst;
+use num_traits::{cast, Float};
+use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+/// Maximum number of terms for the series and continued fractions.
+const MAX_ITER: usize = 1000;
+
+/// Positive root of $\mathrm{Ei}(x)$, split into a high and low part.
+const EI_ROOT: [f64; 2] = [0.3725074107813666, 1.3140183414386028e-17];
+
+/// Continued fraction for the generalized exponential integral, see [DLMF 8.19.17](https://dlmf.nist.gov/8.19#E17).
+/// $$
+/// E_p(x) = e^{-x}\left(\frac{1}{x+p-}\frac{1\cdot p}{x+p+2-}\frac{2(p+1)}{x+p+4-}\cdots\right)
+/// $$
+/// Evaluated with the modified Lentz algorithm, which converges quickly for $x > 1$. A vanishing $x + p$, for $p = -x$, is replaced by a tiny number like the later denominators.
+fn expint_continued_fraction<T>(p: T, x: T) -> T
+where
+    T: Float,
+{
+    let two = T::one() + T::one();
+    let tiny = T::min_positive_value() / T::epsilon();
+
+    let mut b = x + p;
+    if b.abs() < tiny {
+        b = tiny;
+    }
+    let mut c = tiny.recip();
+    let mut d = b.recip();
+    let mut h = d;
+    for i in 1..MAX_ITER {
+        let i = cast::<usize, T>(i).unwrap();
+        let an = -i * (p - T::one() + i);
+        b = b + two;
+        d = an * d + b;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        d = d.recip();
+        c = b + an / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        let delta = c * d;
+        h = h * delta;
+        if (delta - T::one()).abs() < T::epsilon() {
+            break;
+        }
+    }
+    h * (-x).exp()
+}
+
+/// Exponential integral $E_n(x)$ implementation.
+///
+/// Implementation follows that of [Numerical Recipes] (section 6.3): the continued fraction is used for $x > 1$, and otherwise the power series
+/// $$
+/// E_n(x) = \frac{(-x)^{n-1}}{(n-1)!}\left(-\ln x + \psi(n)\right) - \sum_{k=0, k\neq n-1}^{\infty}\frac{(-x)^k}{(k-n+1)k!}
+/// $$
+///
+/// [Numerical Recipes]: https://numerical.recipes/
+pub(crate) fn r_expn<T>(n: u32, x: T) -> T
+where
+    T: Float + FloatSciConst + Gamma,
+{
+    if x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if n == 0 {
+        return (-x).exp() / x;
+    }
+    if x.is_zero() {
+        return if n == 1 {
+            T::infinity()
+        } else {
+            cast::<u32, T>(n - 1).unwrap().recip()
+        };
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+
+    let nf = cast::<u32, T>(n).unwrap();
+    if x > T::one() {
+        return expint_continued_fraction(nf, x);
+    }
+
+    let nm1 = n - 1;
+    let mut result = if nm1 != 0 {
+        cast::<u32, T>(nm1).unwrap().recip()
+    } else {
+        -x.ln() - T::GAMMA()
+    };
+    let mut fact = T::one();
+    for i in 1..MAX_ITER {
+        fact = fact * (-x / cast::<usize, T>(i).unwrap());
+        let term = if i != nm1 as usize {
+            -fact / (cast::<usize, T>(i).unwrap() - cast::<u32, T>(nm1).unwrap())
+        } else {
+            fact * (-x.ln() + nf.digamma())
+        };
+        result = result + term;
+        if term.abs() < result.abs() * T::epsilon() {
+            break;
+        }
+    }
+    result
+}
+
+/// Exponential integral $E_1(x)$ implementation.
+pub(crate) fn r_exp1<T>(x: T) -> T
+where
+    T: Float + FloatSciConst + Gamma,
+{
+    r_expn(1, x)
+}
+
+/// Exponential integral $\mathrm{Ei}(x)$ implementation.
+///
+/// For $x < 0$, we use $\mathrm{Ei}(x) = -E_1(-x)$. Otherwise, the power series
+/// $$
+/// \mathrm{Ei}(x) = \gamma + \ln x + \sum_{k=1}^{\infty}\frac{x^k}{k\,k!}
+/// $$
+/// is summed up to $x = -\ln\epsilon$, after which the asymptotic series
+/// $$
+/// \mathrm{Ei}(x) \sim \frac{e^x}{x}\sum_{k=0}^{\infty}\frac{k!}{x^k}
+/// $$
+/// is used. Close to the positive root $x_0$, the series is instead centered on the root to retain the relative precision,
+/// $$
+/// \mathrm{Ei}(x) = \ln\frac{x}{x_0} + \sum_{k=1}^{\infty}\frac{x^k - x_0^k}{k\,k!}
+/// $$
+pub(crate) fn r_expi<T>(x: T) -> T
+where
+    T: Float + FloatSciConst + Gamma,
+{
+    if x.is_nan() {
+        return x;
+    }
+    if x.is_zero() {
+        return T::neg_infinity();
+    }
+    if x < T::zero() {
+        return -r_exp1(-x);
+    }
+    if x.is_infinite() {
+        return x;
+    }
+
+    if x > -T::epsilon().ln() {
+        let mut sum = T::zero();
+        let mut term = T::one();
+        for k in 1..MAX_ITER {
+            let previous = term;
+            term = term * cast::<usize, T>(k).unwrap() / x;
+            if term < T::epsilon() {
+                break;
+            }
+            if term < previous {
+                sum = sum + term;
+            } else {
+                // The asymptotic series starts to diverge
+                sum = sum - previous;
+                break;
+            }
+        }
+        // Split the exponential to avoid a premature overflow
+        let half_exp = (x / (T::one() + T::one())).exp();
+        return half_exp * (half_exp / x) * (T::one() + sum);
+    }
+
+    if x > cast::<f64, T>(0.2).unwrap() && x < cast::<f64, T>(0.7).unwrap() {
+        let root = cast::<f64, T>(EI_ROOT[0]).unwrap();
+        let delta = (x - root) - cast::<f64, T>(EI_ROOT[1]).unwrap();
+
+        let mut sum = T::zero();
+        let mut diff = T::zero();
+        let mut root_power = T::one();
+        let mut fact = T::one();
+        for k in 1..MAX_ITER {
+            let kf = cast::<usize, T>(k).unwrap();
+            // x^k - x0^k = x(x^{k-1} - x0^{k-1}) + (x - x0)x0^{k-1}
+            diff = x * diff + delta * root_power;
+            root_power = root_power * root;
+            fact = fact * kf;
+            let term = diff / (kf * fact);
+            sum = sum + term;
+            if term.abs() < sum.abs() * T::epsilon() {
+                break;
+            }
+        }
+        return (delta / root).ln_1p() + sum;
+    }
+
+    let mut sum = T::zero();
+    let mut fact = T::one();
+    for k in 1..MAX_ITER {
+        let kf = cast::<usize, T>(k).unwrap();
+        fact = fact * x / kf;
+        let term = fact / kf;
+        sum = sum + term;
+        if term < sum * T::epsilon() {
+            break;
+        }
+    }
+    T::GAMMA() + x.ln() + sum
+}
+
+/// Power series of $E_p(x)$ for $1/2 \leq p < 3/2$ and $0 < x \leq 1$.
+///
+/// With $a = 1 - p$, we have $E_p(x) = x^{-a}\Gamma(a, x)$ and follow [DLMF 8.7.3](https://dlmf.nist.gov/8.7#E3),
+/// $$
+/// E_p(x) = x^{-a}\Gamma(a)\left(1 - \frac{x^a}{\Gamma(1+a)}\right) - \sum_{k=1}^{\infty}\frac{(-x)^k}{k!(k+a)}
+/// $$
+/// where the first term is evaluated with `exp_m1` and $\ln\Gamma(1+a)$, such that it has a finite limit as $p \to 1$.
+fn expint_series<T>(p: T, x: T) -> T
+where
+    T: Float
+        + FloatSciConst
+        + SubAssign
+        + MulAssign
+        + DivAssign
+        + AddAssign
+        + RealGammaLnConsts
+        + LogGammaTaylorCoeffs
+        + LogGammaConsts
+        + Gamma,
+{
+    let a = T::one() - p;
+
+    let mut sum = T::zero();
+    let mut fact = T::one();
+    for k in 1..MAX_ITER {
+        let kf = cast::<usize, T>(k).unwrap();
+        fact *= -x / kf;
+        let term = fact / (kf + a);
+        sum += term;
+        if term.abs() <= sum.abs() * T::epsilon() {
+            break;
+        }
+    }
+
+    let logx = x.ln();
+    -(a * logx - lgam1p(a)).exp_m1() * a.gamma() * (-a * logx).exp() - sum
+}
+
+/// Generalized exponential integral $E_p(x)$ implementation.
+///
+/// Integer orders are delegated to [r_expn], and the continued fraction is used for $x > 1$. Otherwise, for $p < 1/2$ we use the upper incomplete gamma function, $E_p(x) = x^{p-1}\Gamma(1-p, x)$, and for $p \geq 1/2$ the power series at $1/2 \leq p_0 < 3/2$ is followed by the upward recurrence
+/// $$
+/// E_{p+1}(x) = \frac{e^{-x} - xE_p(x)}{p}
+/// $$
+/// which is stable in this region.
+pub(crate) fn r_expint<T>(p: T, x: T) -> T
+where
+    T: Float
+        + FloatSciConst
+        + SubAssign
+        + MulAssign
+        + DivAssign
+        + AddAssign
+        + RealGammaLnConsts
+        + LogGammaTaylorCoeffs
+        + LogGammaConsts
+        + RealGamma,
+{
+    if p.is_nan() || x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return if p > T::one() {
+            (p - T::one()).recip()
+        } else {
+            T::infinity()
+        };
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+    if p == p.floor() && p >= T::zero() {
+        if let Some(n) = p.to_u32() {
+            return r_expn(n, x);
+        }
+    }
+
+    if x > T::one() {
+        return expint_continued_fraction(p, x);
+    }
+    let half = cast::<f64, T>(0.5).unwrap();
+    if p < half {
+        let a = T::one() - p;
+        return x.powf(-a) * a.gamma_upper(x);
+    }
+
+    let steps = (p - half).floor();
+    let mut q = p - steps;
+    let mut result = expint_series(q, x);
+    let exp_mx = (-x).exp();
+    while q < p {
+        result = (exp_mx - x * result) / q;
+        q += T::one();
+    }
+    result
+}
+
+/// Exponential integrals $E_0(x), E_1(x), \ldots, E_{n}(x)$ implementation.
+///
+/// Only $E_m(x)$ with $m \approx x$ is evaluated directly. The other orders follow from the recurrence
+/// $$
+/// nE_{n+1}(x) + xE_n(x) = e^{-x}
+/// $$
+/// which is stable upwards for $n > x$ and downwards for $n < x$.
+pub(crate) fn r_expn_all<T>(n_max: u32, x: T) -> Vec<T>
+where
+    T: Float + FloatSciConst + Gamma,
+{
+    let len = n_max as usize + 1;
+    if x.is_nan() || x < T::zero() {
+        return vec![T::nan(); len];
+    }
+    if x.is_zero() {
+        return (0..len)
+            .map(|n| {
+                if n < 2 {
+                    T::infinity()
+                } else {
+                    cast::<usize, T>(n - 1).unwrap().recip()
+                }
+            })
+            .collect();
+    }
+
+    let mut result = vec![T::zero(); len];
+    let exp_mx = (-x).exp();
+    result[0] = exp_mx / x;
+    if n_max == 0 {
+        return result;
+    }
+
+    let m = if x >= cast::<u32, T>(n_max).unwrap() {
+        n_max as usize
+    } else {
+        x.round().to_usize().unwrap().max(1)
+    };
+    result[m] = r_expn(m as u32, x);
+    for n in (1..m).rev() {
+        let nf = cast::<usize, T>(n).unwrap();
+        result[n] = (exp_mx - nf * result[n + 1]) / x;
+    }
+    for n in m..len - 1 {
+        let nf = cast::<usize, T>(n).unwrap();
+        result[n + 1] = (exp_mx - x * result[n]) / nf;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_EXP1: [[f64; 2]; 11] = [
+        [1e-10, 22.448635265138925],
+        [0.001, 6.331539364136149],
+        [0.1, 1.8229239584193906],
+        [0.5, 0.5597735947761608],
+        [1.0, 0.21938393439552029],
+        [1.5, 0.10001958240663265],
+        [2.0, 0.04890051070806112],
+        [5.0, 0.0011482955912753257],
+        [10.0, 4.156968929685325e-06],
+        [50.0, 3.783264029550459e-24],
+        [300.0, 1.71038427680451e-133],
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_EXPI: [[f64; 2]; 17] = [
+        [-50.0, -3.783264029550459e-24],
+        [-5.0, -0.0011482955912753257],
+        [-1.0, -0.21938393439552029],
+        [-0.1, -1.8229239584193906],
+        [1e-10, -22.448635264938925],
+        [0.001, -6.329539364025038],
+        [0.1, -1.6228128139692766],
+        [0.3, -0.30266853926582593],
+        [0.3725, -2.8874183188745963e-05],
+        [0.4, 0.10476521861932488],
+        [0.6, 0.7698812899373594],
+        [1.0, 1.8951178163559368],
+        [5.0, 40.18527535580318],
+        [20.0, 25615652.664056588],
+        [40.0, 6039718263611242.0],
+        [100.0, 2.71555274485388e+41],
+        [700.0, 1.4509787360525608e+301],
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_EXPN: [[f64; 3]; 20] = [
+        // n, x, E_n(x)
+        [2.0, 1e-08, 0.9999998115653491],
+        [2.0, 0.3, 0.46911522517896387],
+        [2.0, 1.0, 0.14849550677592205],
+        [2.0, 4.0, 0.0031982292493385545],
+        [2.0, 30.0, 2.9296693677373697e-15],
+        [3.0, 1e-08, 0.49999999000000095],
+        [3.0, 0.3, 0.30004182656401435],
+        [3.0, 1.0, 0.10969196719776014],
+        [3.0, 4.0, 0.002761360945689981],
+        [3.0, 30.0, 2.8430743281403273e-15],
+        [10.0, 1e-08, 0.11111110986111111],
+        [10.0, 0.3, 0.07935243728143845],
+        [10.0, 1.0, 0.0363939940314164],
+        [10.0, 4.0, 0.0013754761123174642],
+        [10.0, 30.0, 2.35358715054824e-15],
+        [50.0, 1e-08, 0.02040816305697279],
+        [50.0, 0.3, 0.015024846311309022],
+        [50.0, 1.0, 0.0073545894972313],
+        [50.0, 4.0, 0.00034507875154917805],
+        [50.0, 30.0, 1.1788259969346948e-15],
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_EXPINT: [[f64; 3]; 45] = [
+        // p, x, E_p(x)
+        [-3.7, 1e-05, 4.879840816769327e+24],
+        [-3.7, 0.2, 29755.142961200236],
+        [-3.7, 1.0, 15.3370576162143],
+        [-3.7, 3.0, 0.06817977648442013],
+        [-3.7, 40.0, 1.1672499028670035e-19],
+        [-0.5, 1e-05, 28024955.41532697],
+        [-0.5, 0.2, 9.316221865415043],
+        [-0.5, 1.0, 0.5072822338117733],
+        [-0.5, 3.0, 0.019035620703223125],
+        [-0.5, 40.0, 1.0752045844177696e-19],
+        [0.3, 1e-05, 4103.38281454757],
+        [0.3, 0.2, 2.6867353333389716],
+        [0.3, 1.0, 0.30999167873682115],
+        [0.3, 3.0, 0.015372469240628722],
+        [0.3, 40.0, 1.0543680090645233e-19],
+        [0.5, 1e-05, 558.4991283064395],
+        [0.5, 0.2, 2.0890272400100534],
+        [0.5, 1.0, 0.27880558528066196],
+        [0.5, 3.0, 0.014639587483610874],
+        [0.5, 40.0, 1.0492816475897855e-19],
+        [0.999, 1e-05, 10.996564334321071],
+        [0.999, 0.2, 1.2238114381318848],
+        [0.999, 1.0, 0.21948181320730278],
+        [0.999, 3.0, 0.013051260544143418],
+        [0.999, 40.0, 1.03679798708382e-19],
+        [1.001, 1e-05, 10.875328388795609],
+        [1.001, 0.2, 1.2214913085043781],
+        [1.001, 1.0, 0.21928612679072768],
+        [1.001, 3.0, 0.013045502754446466],
+        [1.001, 40.0, 1.0367485369730398e-19],
+        [1.5, 1e-05, 1.9888100175338708],
+        [1.5, 0.2, 0.8018506101519423],
+        [1.5, 1.0, 0.1781477117815607],
+        [1.5, 3.0, 0.01173661183406264],
+        [1.5, 40.0, 1.0245532986489458e-19],
+        [2.5, 1e-05, 0.666646741299883],
+        [2.5, 0.2, 0.4389070873650623],
+        [2.5, 1.0, 0.12648781959325442],
+        [2.5, 3.0, 0.009718155243784016],
+        [2.5, 40.0, 1.000940404638706e-19],
+        [7.3, 1e-05, 0.15872827194933375],
+        [7.3, 0.2, 0.12526854556087674],
+        [7.3, 1.0, 0.04937582517665902],
+        [7.3, 3.0, 0.005168428562424816],
+        [7.3, 40.0, 9.010116029703366e-20],
+    ];
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_EXPN_ALL: [(f64, [f64; 13]); 4] = [
+        (
+            0.01,
+            [
+                99.0049833749168, 4.037929576538114, 0.9496705379837869, 0.4902765641846651,
+                0.3283823560357738, 0.24669150254720257, 0.1975165837447392, 0.16467911131862012,
+                0.14120043466228313, 0.12357972867531815, 0.10986822627360165, 0.0988951151486432,
+                0.08991462569069833,
+            ],
+        ),
+        (
+            1.0,
+            [
+                0.36787944117144233, 0.21938393439552029, 0.14849550677592205, 0.10969196719776014,
+                0.08606249132456073, 0.0704542374617204, 0.059485040741944384,
+                0.051399066738249656, 0.04521148206188467, 0.040333494888694706,
+                0.0363939940314164, 0.033148544714002595, 0.030430081496130884,
+            ],
+        ),
+        (
+            7.5,
+            [
+                7.374458268637782e-05,
+                6.583089326708023e-05,
+                5.935267064473186e-05,
+                5.396967015617234e-05,
+                4.9437281325513674e-05,
+                4.557619005162025e-05,
+                4.2252588952136336e-05,
+                3.9364992167801846e-05,
+                3.6835275555617104e-05,
+                3.460247543508816e-05,
+                3.261842270940804e-05,
+                3.0844619982727325e-05,
+                2.9249974570670784e-05,
+            ],
+        ),
+        (
+            60.0,
+            [
+                1.4594184604494201e-28,
+                1.4358675656812567e-28,
+                1.4130536860897962e-28,
+                1.3909432307887193e-28,
+                1.3695045932140144e-28,
+                1.348708008531087e-28,
+                1.3285254230199982e-28,
+                1.3089303742942194e-28,
+                1.289897881330291e-28,
+                1.2714043433934682e-28,
+                1.253427447039679e-28,
+                1.235946080458446e-28,
+                1.2189402544962212e-28,
+            ],
+        ),
+    ];
+
+    #[test]
+    fn test_exp1() {
+        for [x, expected] in KNOWN_EXP1 {
+            assert_almost_eq!(r_exp1(x), expected, PRECISION * expected);
+        }
+        assert_eq!(r_exp1(0.0), f64::INFINITY);
+        assert_eq!(r_exp1(f64::INFINITY), 0.0);
+        assert!(r_exp1(-1.0).is_nan());
+        assert!(r_exp1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_expi() {
+        for [x, expected] in KNOWN_EXPI {
+            assert_almost_eq!(r_expi(x), expected, PRECISION * expected.abs());
+        }
+        assert_eq!(r_expi(0.0), f64::NEG_INFINITY);
+        assert_eq!(r_expi(f64::INFINITY), f64::INFINITY);
+        assert_eq!(r_expi(f64::NEG_INFINITY), 0.0);
+        assert!(r_expi(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_expn() {
+        for [n, x, expected] in KNOWN_EXPN {
+            assert_almost_eq!(r_expn(n as u32, x), expected, PRECISION * expected);
+        }
+        assert_eq!(r_expn(0, 2.0), (-2.0_f64).exp() / 2.0);
+        assert_eq!(r_expn(1, 0.0), f64::INFINITY);
+        assert_eq!(r_expn(5, 0.0), 0.25);
+        assert_eq!(r_expn(5, f64::INFINITY), 0.0);
+        assert!(r_expn(2, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_expint() {
+        for [p, x, expected] in KNOWN_EXPINT {
+            assert_almost_eq!(r_expint(p, x), expected, 10.0 * PRECISION * expected);
+        }
+        for [n, x, expected] in KNOWN_EXPN {
+            assert_eq!(r_expint(n, x), r_expn(n as u32, x));
+            assert_almost_eq!(r_expint(n, x), expected, PRECISION * expected);
+        }
+        // x + p = 0 at the start of the continued fraction
+        assert_almost_eq!(r_expint(-1.5, 1.5), 0.3376737526765283, PRECISION);
+        assert_almost_eq!(
+            r_expint(-20.25, 20.25),
+            5.016539531994254e-10,
+            PRECISION * 5.016539531994254e-10
+        );
+        assert_eq!(r_expint(2.5, 0.0), 1.0 / 1.5);
+        assert_eq!(r_expint(0.5, 0.0), f64::INFINITY);
+        assert!(r_expint(1.5, -1.0).is_nan());
+        assert!(r_expint(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_expn_all() {
+        for (x, expected) in KNOWN_EXPN_ALL {
+            let result = r_expn_all(12, x);
+            assert_eq!(result.len(), expected.len());
+            for (value, expected) in result.into_iter().zip(expected) {
+                assert_almost_eq!(value, expected, PRECISION * expected);
+            }
+        }
+        // Pivot at the largest order
+        let result = r_expn_all(3, 60.0);
+        assert_almost_eq!(result[3], 1.3909432307887193e-28, PRECISION * 1.4e-28);
+
+        assert_eq!(r_expn_all(0, 1.0), vec![(-1.0_f64).exp()]);
+        assert_eq!(
+            r_expn_all(3, 0.0),
+            vec![f64::INFINITY, f64::INFINITY, 1.0, 0.5]
+        );
+        assert!(r_expn_all(3, -1.0).into_iter().all(|v| v.is_nan()));
+    }
+}
@@ -0,0 +1,382 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::faddeeva::real_faddeeva_impl::w_im;
+use crate::special::Erf;
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// $\sin(x)/x$ given $\sin(x)$, using the Taylor series for small $x$.
+#[inline]
+fn sinc<T>(x: T, sinx: T) -> T
+where
+    T: Float,
+{
+    if x.abs() < cast::<f64, T>(1e-4).unwrap() {
+        T::one() - x * x / cast::<f64, T>(6.0).unwrap()
+    } else {
+        sinx / x
+    }
+}
+
+/// $\sinh(x)$ using the Taylor series, valid for small $x$.
+#[inline]
+fn sinh_taylor<T>(x: T) -> T
+where
+    T: Float,
+{
+    let x2 = x * x;
+    x * (T::one() + x2 * (cast::<f64, T>(1.0 / 6.0).unwrap() + x2 / cast::<f64, T>(120.0).unwrap()))
+}
+
+/// Continued fraction expansion of $w(z)$ for large $|z|$ in the upper half-plane.
+/// $$
+/// w(z) = \frac{i}{\sqrt{\pi}}\cfrac{1}{z - \cfrac{1/2}{z - \cfrac{1}{z - \cfrac{3/2}{z - \cdots}}}}
+/// $$
+/// Here `xs` is the real part and `ya` is the (non-negative) imaginary part. The number of terms is estimated from the fit of Johnson.
+fn faddeeva_continued_fraction<T>(xs: T, ya: T) -> Complex<T>
+where
+    T: Float + FloatConst,
+{
+    let c = |v: f64| cast::<f64, T>(v).unwrap();
+    let ispi = T::FRAC_2_SQRT_PI() / c(2.0);
+    let x = xs.abs();
+
+    if x + ya > c(1e7) {
+        // w(z) = i/sqrt(pi) / z, scaled to avoid overflow
+        if x > ya {
+            let yax = ya / xs;
+            let denom = ispi / (xs + yax * ya);
+            return Complex::new(denom * yax, denom);
+        }
+        if ya.is_infinite() {
+            return if x.is_nan() {
+                Complex::new(T::nan(), T::nan())
+            } else {
+                Complex::new(T::zero(), T::zero())
+            };
+        }
+        let xya = xs / ya;
+        let denom = ispi / (xya * xs + ya);
+        return Complex::new(denom, denom * xya);
+    }
+    if x + ya > c(4000.0) {
+        // w(z) = i/sqrt(pi) * z / (z^2 - 1/2)
+        let dr = xs * xs - ya * ya - c(0.5);
+        let di = c(2.0) * xs * ya;
+        let denom = ispi / (dr * dr + di * di);
+        return Complex::new(denom * (xs * di - ya * dr), denom * (xs * dr + ya * di));
+    }
+
+    let terms = (c(3.9) + c(11.398) / (c(0.08254) * x + c(0.1421) * ya + c(0.2023))).floor();
+    let mut wr = xs;
+    let mut wi = ya;
+    let mut nu = c(0.5) * (terms - T::one());
+    while nu > c(0.4) {
+        // w <- z - nu/w
+        let denom = nu / (wr * wr + wi * wi);
+        wr = xs - wr * denom;
+        wi = ya + wi * denom;
+        nu = nu - c(0.5);
+    }
+    let denom = ispi / (wr * wr + wi * wi);
+    Complex::new(denom * wi, denom * wr)
+}
+
+/// The Faddeeva function for complex-valued arguments.
+/// $$
+/// w(z) = e^{-z^2}\mathrm{erfc}(-iz)
+/// $$
+/// The implementation follows the [Faddeeva Package] of S. G. Johnson. For large $|z|$, a continued fraction expansion is used. Otherwise, Algorithm 916 of Zaghloul and Ali is used, which expresses $w(z)$ in terms of $\mathrm{erfcx}(\Im z)$ and a few rapidly converging sums. The parameter $a$ of the sums is chosen from the machine epsilon, such that the result is accurate to working precision.
+///
+/// [Faddeeva Package]: http://ab-initio.mit.edu/faddeeva
+pub(crate) fn c_faddeeva_w<T>(z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Erf,
+{
+    let c = |v: f64| cast::<f64, T>(v).unwrap();
+    let one = T::one();
+    let two = one + one;
+
+    let x = z.re.abs();
+    let y = z.im;
+    let ya = y.abs();
+
+    if ya > c(7.0) || (x > c(6.0) && (ya > c(0.1) || (x > c(8.0) && ya > c(1e-10)) || x > c(28.0)))
+    {
+        // Compute for -z if y < 0
+        let xs = if y < T::zero() { -z.re } else { z.re };
+        let result = faddeeva_continued_fraction(xs, ya);
+        if y < T::zero() {
+            // w(z) = 2 exp(-z^2) - w(-z), avoiding the overflow in exp(-z^2)
+            let exponent = Complex::new((ya - xs) * (xs + ya), two * xs * y);
+            return exponent.exp() * two - result;
+        }
+        return result;
+    }
+
+    if z.re.is_nan() || z.im.is_nan() {
+        return Complex::new(T::nan(), T::nan());
+    }
+    if y.is_zero() && x < c(0.2) {
+        // Avoid the cancellation in the sums for Dawson's integral
+        return Complex::new((-x * x).exp(), w_im(z.re));
+    }
+
+    // Parameters of Algorithm 916 for the working precision
+    let a = T::PI() / (-(T::epsilon() / two).ln()).sqrt();
+    let a2 = a * a;
+    let c_alg = two * a / T::PI();
+    let relerr = T::epsilon();
+
+    let mut sum1 = T::zero();
+    let mut sum2 = T::zero();
+    let mut sum3 = T::zero();
+    let mut sum4 = T::zero();
+    let mut sum5 = T::zero();
+
+    let result = if x < c(10.0) {
+        let expx2;
+
+        if x < c(5e-4) {
+            // Compute sum4 and sum5 together as sum5 - sum4
+            let x2 = x * x;
+            expx2 = one - x2 * (one - c(0.5) * x2);
+            let ax2 = two * a * x;
+            let exp2ax = one + ax2 * (one + ax2 * (c(0.5) + ax2 / c(6.0)));
+            let expm2ax = one - ax2 * (one - ax2 * (c(0.5) - ax2 / c(6.0)));
+            let mut prod2ax = one;
+            let mut prodm2ax = one;
+            let mut n = one;
+            loop {
+                let coef = (-a2 * n * n).exp() * expx2 / (a2 * n * n + y * y);
+                prod2ax = prod2ax * exp2ax;
+                prodm2ax = prodm2ax * expm2ax;
+                sum1 = sum1 + coef;
+                sum2 = sum2 + coef * prodm2ax;
+                sum3 = sum3 + coef * prod2ax;
+                // Really sum5 - sum4
+                sum5 = sum5 + coef * two * a * n * sinh_taylor(two * a * n * x);
+                if coef * prod2ax < relerr * sum3 {
+                    break;
+                }
+                n = n + one;
+            }
+        } else {
+            expx2 = (-x * x).exp();
+            let mut n = one;
+            loop {
+                // The exponentials are combined, since exp(-a^2 n^2) underflows before exp(2axn) overflows
+                let an = a * n;
+                let denom = an * an + y * y;
+                let coef = (-an * an).exp() * expx2 / denom;
+                let tm = (-(an + x) * (an + x)).exp() / denom;
+                let tp = (-(an - x) * (an - x)).exp() / denom;
+                sum1 = sum1 + coef;
+                sum2 = sum2 + tm;
+                sum4 = sum4 + tm * an;
+                sum3 = sum3 + tp;
+                sum5 = sum5 + tp * an;
+                // sum5 has the slowest decay
+                if tp * an < relerr * sum5 {
+                    break;
+                }
+                n = n + one;
+            }
+        }
+
+        // For y < -6, erfcx(y) = 2 exp(y^2) to working precision, so we avoid spurious overflow
+        let expx2erfcxy = if y > c(-6.0) {
+            expx2 * y.erfcx()
+        } else {
+            two * (y * y - x * x).exp()
+        };
+        if y > c(5.0) {
+            // The imaginary terms cancel
+            let sinxy = (x * y).sin();
+            Complex::new(
+                (expx2erfcxy - c_alg * y * sum1) * (two * x * y).cos()
+                    + c_alg * x * expx2 * sinxy * sinc(x * y, sinxy),
+                T::zero(),
+            )
+        } else {
+            let xs = z.re;
+            let sinxy = (xs * y).sin();
+            let sin2xy = (two * xs * y).sin();
+            let cos2xy = (two * xs * y).cos();
+            let coef1 = expx2erfcxy - c_alg * y * sum1;
+            let coef2 = c_alg * xs * expx2;
+            Complex::new(
+                coef1 * cos2xy + coef2 * sinxy * sinc(xs * y, sinxy),
+                coef2 * sinc(two * xs * y, sin2xy) - coef1 * sin2xy,
+            )
+        }
+    } else {
+        // Only sum3 and sum5 contribute. Since |y| < 1e-10 here, we only need the exp(-x^2) term.
+        let result = Complex::new((-x * x).exp(), T::zero());
+        // Sum in both directions, starting at n0
+        let n0 = (x / a + c(0.5)).floor();
+        let dx = a * n0 - x;
+        sum3 = (-dx * dx).exp() / (a2 * n0 * n0 + y * y);
+        sum5 = a * n0 * sum3;
+        let exp1 = (c(4.0) * a * dx).exp();
+        let mut exp1dn = one;
+        let mut dn = one;
+        let mut converged = false;
+        while n0 - dn > T::zero() {
+            let np = n0 + dn;
+            let nm = n0 - dn;
+            let mut tp = (-(a * dn + dx) * (a * dn + dx)).exp();
+            exp1dn = exp1dn * exp1;
+            let mut tm = tp * exp1dn;
+            tp = tp / (a2 * np * np + y * y);
+            tm = tm / (a2 * nm * nm + y * y);
+            sum3 = sum3 + tp + tm;
+            sum5 = sum5 + a * (np * tp + nm * tm);
+            if a * (np * tp + nm * tm) < relerr * sum5 {
+                converged = true;
+                break;
+            }
+            dn = dn + one;
+        }
+        while !converged {
+            // Only the n0 + dn terms remain
+            let np = n0 + dn;
+            let tp = (-(a * dn + dx) * (a * dn + dx)).exp() / (a2 * np * np + y * y);
+            sum3 = sum3 + tp;
+            sum5 = sum5 + a * np * tp;
+            converged = a * np * tp < relerr * sum5;
+            dn = dn + one;
+        }
+        result
+    };
+
+    result
+        + Complex::new(
+            c_alg / two * y * (sum2 + sum3),
+            (c_alg / two * (sum5 - sum4)).copysign(z.re),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    #[test]
+    fn test_c_faddeeva_w() {
+        const KNOWN_VALUES: [[Complex64; 2]; 11] = [
+            // Values from mpmath (v 1.3.0)
+            [
+                Complex { re: 1.0, im: 1.0 },
+                Complex {
+                    re: 0.3047442052569126,
+                    im: 0.20821893820283163,
+                },
+            ],
+            [
+                Complex { re: 0.5, im: -0.3 },
+                Complex {
+                    re: 1.0133165720153523,
+                    im: 0.8067757668882944,
+                },
+            ],
+            [
+                Complex { re: -2.0, im: 3.0 },
+                Complex {
+                    re: 0.13075746966984858,
+                    im: -0.08111265047745665,
+                },
+            ],
+            [
+                Complex { re: 7.0, im: 0.05 },
+                Complex {
+                    re: 0.000594264555732032,
+                    im: 0.08144312303074976,
+                },
+            ],
+            [
+                Complex {
+                    re: 10.0,
+                    im: 1e-11,
+                },
+                Complex {
+                    re: 5.728717562239308e-14,
+                    im: 0.0567053942328876,
+                },
+            ],
+            [
+                Complex {
+                    re: 0.0001,
+                    im: 2.0,
+                },
+                Complex {
+                    re: 0.2553956758924782,
+                    im: 1.0679646169888322e-05,
+                },
+            ],
+            [
+                Complex { re: 20.0, im: 30.0 },
+                Complex {
+                    re: 0.01302090842413885,
+                    im: 0.008673934748446674,
+                },
+            ],
+            [
+                Complex { re: -3.0, im: -2.0 },
+                Complex {
+                    re: -0.08133907992862736,
+                    im: -0.12108616246299844,
+                },
+            ],
+            [
+                Complex {
+                    re: 0.001,
+                    im: 0.001,
+                },
+                Complex {
+                    re: 0.9988716223354113,
+                    im: 0.0011263806715998664,
+                },
+            ],
+            [
+                Complex { re: 0.0, im: 5.5 },
+                Complex {
+                    re: 0.10096221839949909,
+                    im: 0.0,
+                },
+            ],
+            [
+                Complex { re: -6.5, im: 0.2 },
+                Complex {
+                    re: 0.0027688027836709505,
+                    im: -0.08777592387403016,
+                },
+            ],
+        ];
+
+        for [z, expected] in KNOWN_VALUES {
+            assert_almost_eq!(c_faddeeva_w(z), expected, PRECISION * expected.norm());
+            // w(-conj(z)) = conj(w(z))
+            assert_almost_eq!(
+                c_faddeeva_w(-z.conj()),
+                expected.conj(),
+                PRECISION * expected.norm()
+            );
+        }
+
+        assert_eq!(
+            c_faddeeva_w(Complex64::new(0.0, 0.0)),
+            Complex64::new(1.0, 0.0)
+        );
+        assert!(c_faddeeva_w(Complex64::new(f64::NAN, 1.0)).re.is_nan());
+        assert_eq!(
+            c_faddeeva_w(Complex64::new(1.0, f64::INFINITY)),
+            Complex64::new(0.0, 0.0)
+        );
+    }
+}
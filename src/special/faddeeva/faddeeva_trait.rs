@@ -0,0 +1,159 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::faddeeva::complex_faddeeva_impl::*;
+use crate::special::faddeeva::real_faddeeva_impl::*;
+
+use num_complex::Complex;
+
+/// The Faddeeva function for complex-valued arguments.
+pub trait Faddeeva {
+    /// The Faddeeva function.
+    /// $$
+    /// w(z) = e^{-z^2}\mathrm{erfc}(-iz)
+    /// $$
+    /// It is also known as the Kramp function or the plasma dispersion function (up to a scaling). It is an entire function, and is related to the [Complementary Error function] through $w(z) = \mathrm{erfcx}(-iz)$. See the [DLMF] or [wiki] pages for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Faddeeva;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(1.0, 1.0);
+    /// let expected = Complex64::new(0.3047442052569126, 0.20821893820283163);
+    /// assert!((z.faddeeva_w() - expected).norm() < 1e-14);
+    /// assert_eq!(Complex64::new(0.0, 0.0).faddeeva_w(), Complex64::new(1.0, 0.0));
+    /// ```
+    /// # Notes
+    /// The implementation follows the [Faddeeva Package] of S. G. Johnson. For large $|z|$, a continued fraction expansion is used. Otherwise, Algorithm 916 of Zaghloul and Ali expresses $w(z)$ in terms of $\mathrm{erfcx}(\Im z)$ and a few rapidly converging sums. For $\Im z < 0$, the identity $w(z) = 2e^{-z^2} - w(-z)$ is used, which overflows for large $|z|$.
+    ///
+    /// # References
+    /// - [Faddeeva Package]
+    /// - M. R. Zaghloul and A. N. Ali, "Algorithm 916: Computing the Faddeyeva and Voigt functions", ACM Trans. Math. Soft. 38(2), 15 (2011).
+    /// - [DLMF]
+    /// - [Wikipedia][wiki]
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Faddeeva Package]: http://ab-initio.mit.edu/faddeeva
+    /// [DLMF]: https://dlmf.nist.gov/7.2#E3
+    /// [wiki]: https://en.wikipedia.org/wiki/Faddeeva_function
+    /// [Complementary Error function]: crate::special::Erf::erfc
+    fn faddeeva_w(self) -> Self;
+}
+
+/// Functions related to the Faddeeva function that are only defined for real-valued arguments.
+pub trait RealFaddeeva {
+    /// Dawson's Integral.
+    /// $$
+    /// F(x) = e^{-x^2}\int_0^x e^{t^2}dt
+    /// $$
+    /// See the [DLMF] or [wiki] pages for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealFaddeeva;
+    /// assert!((1.0_f64.dawsn() - 0.5380795069127684).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.dawsn(), 0.0);
+    /// assert_eq!((-2.0_f64).dawsn(), -(2.0_f64.dawsn()));
+    /// ```
+    /// # Notes
+    /// Dawson's integral is computed from the imaginary part of the [Faddeeva function] along the real axis,
+    /// $$
+    /// F(x) = \frac{\sqrt{\pi}}{2}\Im w(x)
+    /// $$
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [DLMF]: https://dlmf.nist.gov/7.2#E5
+    /// [wiki]: https://en.wikipedia.org/wiki/Dawson_function
+    /// [Faddeeva function]: crate::special::Faddeeva::faddeeva_w
+    fn dawsn(self) -> Self;
+
+    /// The Imaginary Error Function.
+    /// $$
+    /// \mathrm{erfi}x = -i\mathrm{erf}(ix) = \frac{2}{\sqrt{\pi}}\int_0^x e^{t^2}dt
+    /// $$
+    /// See the [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealFaddeeva;
+    /// assert!((1.0_f64.erfi() - 1.6504257587975428).abs() < 1e-15);
+    /// assert_eq!(30.0_f64.erfi(), f64::INFINITY);
+    /// assert_eq!((-30.0_f64).erfi(), f64::NEG_INFINITY);
+    /// ```
+    /// # Notes
+    /// The imaginary error function is computed from [Dawson's integral] $F(x)$ as $\mathrm{erfi}x = \frac{2}{\sqrt{\pi}}e^{x^2}F(x)$.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [wiki]: https://en.wikipedia.org/wiki/Error_function#Imaginary_error_function
+    /// [Dawson's integral]: crate::special::RealFaddeeva::dawsn
+    fn erfi(self) -> Self;
+
+    /// The Voigt Profile.
+    /// $$
+    /// V(x;\sigma,\gamma) = \int_{-\infty}^{\infty} G(x';\sigma)L(x-x';\gamma)dx'
+    /// $$
+    /// The convolution of a Gaussian profile $G(x;\sigma)$ with standard deviation $\sigma$ and a Lorentzian profile $L(x;\gamma)$ with half-width at half-maximum $\gamma$,
+    /// $$
+    /// G(x;\sigma) = \frac{e^{-x^2/2\sigma^2}}{\sigma\sqrt{2\pi}}, \quad L(x;\gamma) = \frac{\gamma}{\pi(x^2+\gamma^2)}
+    /// $$
+    /// Both `sigma` and `gamma` must be non-negative, otherwise NaN is returned. When one of the widths is zero, the profile reduces to the other distribution. See the [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealFaddeeva;
+    /// assert!((0.0_f64.voigt_profile(1.0, 1.0) - 0.2087092805203677).abs() < 1e-15);
+    /// // Reduces to the Lorentzian profile
+    /// let pi = std::f64::consts::PI;
+    /// assert_eq!(1.0_f64.voigt_profile(0.0, 1.0), 1.0 / (2.0 * pi));
+    /// ```
+    /// # Notes
+    /// The profile is evaluated in terms of the [Faddeeva function] following [scipy],
+    /// $$
+    /// V(x;\sigma,\gamma) = \frac{\Re w(z)}{\sigma\sqrt{2\pi}}, \quad z = \frac{x+i\gamma}{\sigma\sqrt{2}}
+    /// $$
+    /// Since $w(z)$ is computed with a continued fraction for large $|z|$, the profile remains accurate far into the wings.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [wiki]: https://en.wikipedia.org/wiki/Voigt_profile
+    /// [scipy]: https://docs.scipy.org/doc/scipy/reference/generated/scipy.special.voigt_profile.html
+    /// [Faddeeva function]: crate::special::Faddeeva::faddeeva_w
+    fn voigt_profile(self, sigma: Self, gamma: Self) -> Self;
+}
+
+macro_rules! float_faddeeva_impl {
+    ($($T: ty)*) => ($(
+        impl RealFaddeeva for $T {
+            #[inline(always)]
+            fn dawsn(self) -> Self {
+                r_dawsn(self)
+            }
+
+            #[inline(always)]
+            fn erfi(self) -> Self {
+                r_erfi(self)
+            }
+
+            #[inline(always)]
+            fn voigt_profile(self, sigma: Self, gamma: Self) -> Self {
+                r_voigt_profile(self, sigma, gamma)
+            }
+        }
+    )*)
+}
+
+float_faddeeva_impl! {f32 f64}
+
+macro_rules! float_complexfaddeeva_impl {
+    ($($T: ty)*) => ($(
+        impl Faddeeva for Complex<$T> {
+            #[inline(always)]
+            fn faddeeva_w(self) -> Self {
+                c_faddeeva_w(self)
+            }
+        }
+    )*)
+}
+
+float_complexfaddeeva_impl! {f32 f64}
@@ -0,0 +1,20 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Various functions related to the Faddeeva function.
+
+mod faddeeva_trait;
+
+pub use faddeeva_trait::*;
+
+mod c_faddeeva;
+mod r_faddeeva;
+
+pub(crate) mod real_faddeeva_impl {
+    pub(crate) use super::r_faddeeva::*;
+}
+pub(crate) mod complex_faddeeva_impl {
+    pub(crate) use super::c_faddeeva::*;
+}
@@ -0,0 +1,212 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::erf::real_erf_impl::exp_sqr;
+use crate::special::faddeeva::complex_faddeeva_impl::c_faddeeva_w;
+use crate::special::Erf;
+use crate::traits::FloatSciConst;
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// Imaginary part of the Faddeeva function along the real axis.
+/// $$
+/// \Im w(x) = \frac{2}{\sqrt{\pi}}F(x)
+/// $$
+/// where $F(x)$ is Dawson's integral. Along the real axis, $\Re w(x) = e^{-x^2}$.
+///
+/// For small $|x|$, the sums of the general algorithm suffer from cancellation, so we instead use the Maclaurin series
+/// $$
+/// F(x) = \sum_{n=0}^{\infty} \frac{(-2)^n x^{2n+1}}{(2n+1)!!}
+/// $$
+pub(crate) fn w_im<T>(x: T) -> T
+where
+    T: Float + FloatConst + Erf,
+{
+    if x.abs() < cast::<f64, T>(0.2).unwrap() {
+        let two = T::one() + T::one();
+        let x2 = x * x;
+        let mut term = x;
+        let mut sum = x;
+        let mut n = T::one();
+        while term.abs() > T::epsilon() * sum.abs() {
+            term = term * -two * x2 / (two * n + T::one());
+            sum = sum + term;
+            n = n + T::one();
+        }
+        return T::FRAC_2_SQRT_PI() * sum;
+    }
+    c_faddeeva_w(Complex::new(x, T::zero())).im
+}
+
+/// Dawson's integral implementation.
+/// $$
+/// F(x) = \frac{\sqrt{\pi}}{2}\Im w(x)
+/// $$
+pub(crate) fn r_dawsn<T>(x: T) -> T
+where
+    T: Float + FloatSciConst + Erf,
+{
+    T::SQRT_PI() / (T::one() + T::one()) * w_im(x)
+}
+
+/// Imaginary error function implementation.
+/// $$
+/// \mathrm{erfi}(x) = e^{x^2}\Im w(x)
+/// $$
+/// The limit $|x| \to \infty$ gives the correctly signed infinity.
+pub(crate) fn r_erfi<T>(x: T) -> T
+where
+    T: Float + FloatConst + Erf,
+{
+    let exp_x2 = exp_sqr(x, false);
+    if exp_x2.is_infinite() {
+        return T::infinity().copysign(x);
+    }
+    exp_x2 * w_im(x)
+}
+
+/// Voigt profile implementation.
+///
+/// The convolution of a Gaussian and a Lorentzian profile is evaluated in terms of the Faddeeva function, following [scipy].
+/// $$
+/// V(x;\sigma,\gamma) = \frac{\Re w(z)}{\sigma\sqrt{2\pi}}, \quad z = \frac{x + i\gamma}{\sigma\sqrt{2}}
+/// $$
+/// When either width vanishes, the pure Lorentzian or Gaussian profile is returned.
+///
+/// [scipy]: https://github.com/scipy/scipy/blob/main/scipy/special/_faddeeva.cxx
+pub(crate) fn r_voigt_profile<T>(x: T, sigma: T, gamma: T) -> T
+where
+    T: Float + FloatSciConst + Erf,
+{
+    if x.is_nan() || sigma.is_nan() || gamma.is_nan() || sigma < T::zero() || gamma < T::zero() {
+        return T::nan();
+    }
+    let two = T::one() + T::one();
+
+    if sigma.is_zero() {
+        if gamma.is_zero() {
+            // Dirac delta function
+            return if x.is_zero() {
+                T::infinity()
+            } else {
+                T::zero()
+            };
+        }
+        return gamma / T::PI() / (x * x + gamma * gamma);
+    }
+    if gamma.is_zero() {
+        let t = x / sigma;
+        return (-t * t / two).exp() / (T::SQRT_TAU() * sigma);
+    }
+
+    let z = Complex::new(x, gamma) * (T::FRAC_1_SQRT_2() / sigma);
+    c_faddeeva_w(z).re / (T::SQRT_TAU() * sigma)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    #[test]
+    fn test_r_dawsn() {
+        const KNOWN_VALUES: [[f64; 2]; 10] = [
+            // Values from mpmath (v 1.3.0)
+            [0.001, 0.0009999993333336],
+            [0.1, 0.09933599239785286],
+            [0.5, 0.4244363835020223],
+            [0.924138873, 0.5410442246351816], // Maximum of Dawson's integral
+            [1.0, 0.5380795069127684],
+            [2.0, 0.30134038892379195],
+            [5.0, 0.10213407442427684],
+            [10.0, 0.05025384718759853],
+            [100.0, 0.005000250037509378],
+            [10000.0, 5.000000025e-05],
+        ];
+
+        for [x, expected] in KNOWN_VALUES {
+            assert_almost_eq!(r_dawsn(x), expected, PRECISION * expected);
+            assert_almost_eq!(r_dawsn(-x), -expected, PRECISION * expected);
+        }
+
+        assert_eq!(r_dawsn(0.0), 0.0);
+        assert_eq!(r_dawsn(f64::INFINITY), 0.0);
+        assert!(r_dawsn(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_r_erfi() {
+        const KNOWN_VALUES: [[f64; 2]; 8] = [
+            // Values from mpmath (v 1.3.0)
+            [0.001, 0.0011283795432220146],
+            [0.1, 0.1132151741695998],
+            [0.5, 0.614952094696511],
+            [1.0, 1.6504257587975428],
+            [2.0, 18.564802414575553],
+            [5.0, 8298273880.676804],
+            [10.0, 1.5243074227086696e+42],
+            [20.0, 1.4747975396287862e+172],
+        ];
+
+        for [x, expected] in KNOWN_VALUES {
+            assert_almost_eq!(r_erfi(x), expected, PRECISION * expected);
+            assert_almost_eq!(r_erfi(-x), -expected, PRECISION * expected);
+        }
+
+        assert_eq!(r_erfi(0.0), 0.0);
+        // Values from mpmath (v 1.3.0), approximately 2x/sqrt(pi)
+        for [x, expected] in [
+            [1e-10, 1.1283791670955126150e-10],
+            [1e-300, 1.1283791670955126022e-300],
+        ] {
+            assert_almost_eq!(r_erfi(x), expected, PRECISION * expected);
+            assert_almost_eq!(r_erfi(-x), -expected, PRECISION * expected);
+        }
+        assert_eq!(r_erfi(30.0), f64::INFINITY);
+        assert_eq!(r_erfi(-30.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_r_voigt_profile() {
+        const KNOWN_VALUES: [[f64; 4]; 8] = [
+            // Values from mpmath (v 1.3.0)
+            [0.0, 1.0, 1.0, 0.2087092805203677],
+            [1.0, 1.0, 1.0, 0.16579566268916646],
+            [-2.0, 0.5, 0.3, 0.029564246999758806],
+            [3.0, 0.1, 2.0, 0.04903742918156732],
+            [10.0, 1.0, 0.01, 3.2837310592837986e-05],
+            [100.0, 2.0, 0.5, 1.5934231731292917e-05],
+            [10000.0, 1.0, 1.0, 3.183098925499886e-09],
+            [0.5, 3.0, 0.001, 0.13111218474275438],
+        ];
+
+        for [x, sigma, gamma, expected] in KNOWN_VALUES {
+            assert_almost_eq!(
+                r_voigt_profile(x, sigma, gamma),
+                expected,
+                PRECISION * expected
+            );
+            assert_almost_eq!(
+                r_voigt_profile(-x, sigma, gamma),
+                expected,
+                PRECISION * expected
+            );
+        }
+
+        // Limiting Gaussian and Lorentzian profiles
+        let pi = std::f64::consts::PI;
+        assert_eq!(r_voigt_profile(0.0, 0.0, 1.0), 1.0 / pi);
+        assert_almost_eq!(
+            r_voigt_profile(1.0, 1.0, 0.0),
+            (-0.5_f64).exp() / (2.0 * pi).sqrt(),
+            PRECISION
+        );
+        assert_eq!(r_voigt_profile(0.0, 0.0, 0.0), f64::INFINITY);
+        assert_eq!(r_voigt_profile(1.0, 0.0, 0.0), 0.0);
+        assert!(r_voigt_profile(1.0, -1.0, 1.0).is_nan());
+        assert!(r_voigt_profile(1.0, 1.0, -1.0).is_nan());
+    }
+}
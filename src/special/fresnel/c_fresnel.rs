@@ -0,0 +1,183 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::erf::complex_erf_impl::c_erf;
+use crate::special::erf::real_erf_impl::RealErfConsts;
+use crate::special::fresnel::real_fresnel_impl::{r_fresnel, FresnelConsts};
+use crate::special::Erf;
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// Maximum number of terms in the power series.
+const MAX_ITER: usize = 100;
+
+/// Fresnel integrals for complex-valued arguments.
+///
+/// For $|z| \leq 1$, we sum the power series
+/// $$
+/// C(z) + iS(z) = \sum_{k=0}^{\infty}\frac{(i\pi/2)^k}{k!(2k+1)}z^{2k+1}
+/// $$
+/// and otherwise we use the error function ([DLMF 7.5.7](https://dlmf.nist.gov/7.5#E7))
+/// $$
+/// C(z) = \frac{1-i}{4}\left(\mathrm{erf}\zeta_+ + i\,\mathrm{erf}\zeta_-\right), \quad S(z) = \frac{1+i}{4}\left(\mathrm{erf}\zeta_+ - i\,\mathrm{erf}\zeta_-\right)
+/// $$
+/// with $\zeta_\pm = (1 \pm i)\sqrt{\pi}z/2$. The series avoids the cancellation in $S(z) \approx \pi z^3/6$ close to the origin.
+pub(crate) fn c_fresnel<T>(z: Complex<T>) -> (Complex<T>, Complex<T>)
+where
+    T: Float + FloatConst + FresnelConsts + Erf + RealErfConsts,
+{
+    if z.im.is_zero() {
+        let (s, c) = r_fresnel(z.re);
+        return (Complex::new(s, z.im), Complex::new(c, z.im));
+    }
+    if z.re.is_zero() {
+        // S(iy) = -iS(y) and C(iy) = iC(y)
+        let (s, c) = r_fresnel(z.im);
+        return (Complex::new(z.re, -s), Complex::new(z.re, c));
+    }
+
+    if z.norm_sqr() <= T::one() {
+        let w = z * z * T::FRAC_PI_2();
+        let mut power = z;
+        let mut s = Complex::new(T::zero(), T::zero());
+        let mut c = z;
+        for k in 1..MAX_ITER {
+            let kf = cast::<usize, T>(k).unwrap();
+            power = power * w / kf;
+            let term = power / (kf + kf + T::one());
+            match k % 4 {
+                0 => c = c + term,
+                1 => s = s + term,
+                2 => c = c - term,
+                _ => s = s - term,
+            }
+            if k > 1 && power.norm() <= T::epsilon() * s.norm().min(c.norm()) {
+                break;
+            }
+        }
+        return (s, c);
+    }
+
+    let half = cast::<f64, T>(0.5).unwrap();
+    let quarter = cast::<f64, T>(0.25).unwrap();
+    let scale = T::PI().sqrt() * half;
+    let erf_plus = c_erf(Complex::new(T::one(), T::one()) * z * scale);
+    let erf_minus = c_erf(Complex::new(T::one(), -T::one()) * z * scale);
+    let i = Complex::new(T::zero(), T::one());
+    let s = Complex::new(quarter, quarter) * (erf_plus - i * erf_minus);
+    let c = Complex::new(quarter, -quarter) * (erf_plus + i * erf_minus);
+    (s, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Columns are z, S(z) and C(z)
+    const KNOWN_VALUES: [[Complex64; 3]; 7] = [
+        // Values from mpmath (v 1.3.0)
+        [
+            Complex { re: 0.3, im: 0.2 },
+            Complex {
+                re: -0.0046712379549501,
+                im: 0.0241460421584878,
+            },
+            Complex {
+                re: 0.3014746276202385,
+                im: 0.19969654592682476,
+            },
+        ],
+        [
+            Complex { re: 0.5, im: -0.8 },
+            Complex {
+                re: -0.4792245228567121,
+                im: 0.0018872469960635036,
+            },
+            Complex {
+                re: 0.4205317430666184,
+                im: -0.9780190684041965,
+            },
+        ],
+        [
+            Complex { re: 1.0, im: 1.0 },
+            Complex {
+                re: -2.0618882191948407,
+                im: 2.0618882191948407,
+            },
+            Complex {
+                re: 2.555793778102439,
+                im: 2.555793778102439,
+            },
+        ],
+        [
+            Complex { re: -1.5, im: 0.4 },
+            Complex {
+                re: -1.1825796103490087,
+                im: -0.18752503760809067,
+            },
+            Complex {
+                re: -0.3183277780708262,
+                im: -0.6542025152292302,
+            },
+        ],
+        [
+            Complex { re: 2.0, im: 3.0 },
+            Complex {
+                re: -5815898.602940469,
+                im: -3788100.700182895,
+            },
+            Complex {
+                re: -3788100.2001828956,
+                im: 5815899.102940469,
+            },
+        ],
+        [
+            Complex { re: 0.1, im: 2.5 },
+            Complex {
+                re: 0.04174803988229091,
+                im: -0.65749086637408,
+            },
+            Complex {
+                re: -0.10241952806768641,
+                im: 0.44190210237216815,
+            },
+        ],
+        [
+            Complex { re: 6.0, im: 0.05 },
+            Complex {
+                re: 0.4216023127174851,
+                im: -8.383445092603043e-05,
+            },
+            Complex {
+                re: 0.49948048340573403,
+                im: 0.05773789535163094,
+            },
+        ],
+    ];
+
+    #[test]
+    fn test_c_fresnel() {
+        for [z, s, c] in KNOWN_VALUES {
+            let (result_s, result_c) = c_fresnel(z);
+            assert_almost_eq!(result_s, s, PRECISION * s.norm());
+            assert_almost_eq!(result_c, c, PRECISION * c.norm());
+
+            // Symmetric under conjugation
+            let (result_s, result_c) = c_fresnel(z.conj());
+            assert_almost_eq!(result_s, s.conj(), PRECISION * s.norm());
+            assert_almost_eq!(result_c, c.conj(), PRECISION * c.norm());
+        }
+
+        let (s, c) = c_fresnel(Complex64::new(0.7, 0.0));
+        assert_eq!((s.re, c.re), r_fresnel(0.7));
+        // S(iy) = -iS(y) and C(iy) = iC(y)
+        let (s, c) = c_fresnel(Complex64::new(0.0, 0.7));
+        assert_eq!(s, Complex64::new(0.0, -r_fresnel(0.7).0));
+        assert_eq!(c, Complex64::new(0.0, r_fresnel(0.7).1));
+    }
+}
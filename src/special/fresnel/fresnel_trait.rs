@@ -0,0 +1,81 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+use crate::special::fresnel::complex_fresnel_impl::*;
+use crate::special::fresnel::real_fresnel_impl::*;
+
+use num_complex::Complex;
+
+/// Fresnel integrals for both real and complex-valued arguments.
+pub trait Fresnel {
+    /// Fresnel integrals.
+    /// $$
+    /// S(z) = \int_0^z \sin\left(\frac{\pi t^2}{2}\right)dt, \quad C(z) = \int_0^z \cos\left(\frac{\pi t^2}{2}\right)dt
+    /// $$
+    /// Returns the tuple $(S(z), C(z))$. Both functions are odd and tend to $\pm 1/2$ as $x \to \pm\infty$ along the real axis. See the [DLMF] or [wiki] page for more details.
+    ///
+    /// # Examples
+    /// For real-valued inputs:
+    /// ```
+    /// use sci_rs::special::Fresnel;
+    /// let (s, c) = 1.0_f64.fresnel();
+    /// assert!((s - 0.43825914739035476).abs() < 1e-15);
+    /// assert!((c - 0.7798934003768229).abs() < 1e-15);
+    /// assert_eq!(f64::INFINITY.fresnel(), (0.5, 0.5));
+    /// ```
+    /// Also for complex-valued inputs:
+    /// ```
+    /// use sci_rs::special::Fresnel;
+    /// use num_complex::Complex64;
+    /// let (s, c) = Complex64::new(1.0, 1.0).fresnel();
+    /// println!("{} {}", s, c); // -2.0619 + 2.0619j, 2.5558 + 2.5558j
+    /// ```
+    /// # Notes
+    /// For real arguments, the implementation follows the structure of [cephes]. For $|x| \leq 1.6$, Chebyshev expansions of $S(x)/x^3$ and $C(x)/x$ in $x^4$ are used. Otherwise, we use the auxiliary functions $f$ and $g$,
+    /// $$
+    /// S(x) = \frac{1}{2} - f(x)\cos\left(\frac{\pi x^2}{2}\right) - g(x)\sin\left(\frac{\pi x^2}{2}\right), \quad C(x) = \frac{1}{2} + f(x)\sin\left(\frac{\pi x^2}{2}\right) - g(x)\cos\left(\frac{\pi x^2}{2}\right)
+    /// $$
+    /// which are approximated by Chebyshev expansions in $1/x^2$. The argument of the trigonometric functions is reduced exactly, such that the result is accurate for large $x$.
+    ///
+    /// For complex arguments with $|z| > 1$, we use the [error function],
+    /// $$
+    /// C(z) + iS(z) = \frac{1+i}{2}\mathrm{erf}\left(\frac{(1-i)\sqrt{\pi}}{2}z\right)
+    /// $$
+    /// and the power series otherwise.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [DLMF]: https://dlmf.nist.gov/7.2#iii
+    /// [wiki]: https://en.wikipedia.org/wiki/Fresnel_integral
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/fresnl.c
+    /// [error function]: crate::special::Erf::erf
+    fn fresnel(self) -> (Self, Self)
+    where
+        Self: Sized;
+}
+
+macro_rules! float_fresnel_impl {
+    ($($T: ty)*) => ($(
+        impl Fresnel for $T {
+            #[inline(always)]
+            fn fresnel(self) -> (Self, Self) {
+                r_fresnel(self)
+            }
+        }
+    )*)
+}
+
+float_fresnel_impl! {f32 f64}
+
+macro_rules! complex_fresnel_impl {
+    ($($T: ty)*) => ($(
+        impl Fresnel for Complex<$T> {
+            #[inline(always)]
+            fn fresnel(self) -> (Self, Self) {
+                c_fresnel(self)
+            }
+        }
+    )*)
+}
+
+complex_fresnel_impl! {f32 f64}
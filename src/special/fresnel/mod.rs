@@ -0,0 +1,20 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Fresnel integrals.
+
+mod fresnel_trait;
+
+pub use fresnel_trait::*;
+
+mod c_fresnel;
+mod r_fresnel;
+
+pub(crate) mod real_fresnel_impl {
+    pub(crate) use super::r_fresnel::*;
+}
+mod complex_fresnel_impl {
+    pub(crate) use super::c_fresnel::*;
+}
@@ -0,0 +1,244 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::eval_cheby;
+use num_traits::{cast, Float, FloatConst};
+
+/// Chebyshev coefficients for the Fresnel integrals, computed with mpmath.
+///
+/// `S_SMALL` and `C_SMALL` approximate $S(x)/x^3$ and $C(x)/x$ in $x^4$ on $\[0, 1.6\]$. The remaining tables approximate the auxiliary functions $\pi xf(x)$ and $\pi^2x^3g(x)$ in $1/x^2$ on $\[1.6, 4\]$ and $\[4, \infty)$.
+pub(crate) trait FresnelConsts: Sized {
+    const S_SMALL: [Self; 12];
+    const C_SMALL: [Self; 13];
+    const F_MID: [Self; 25];
+    const G_MID: [Self; 27];
+    const F_LARGE: [Self; 13];
+    const G_LARGE: [Self; 16];
+}
+
+macro_rules! impl_fresnelconsts {
+    ($($T: ty)*) => ($(
+        impl FresnelConsts for $T {
+            const S_SMALL: [Self; 12] = [
+                -1.0494270345038239e-17,
+                1.4188008517448966e-15,
+                -1.6028109554824476e-13,
+                1.4862338485609142e-11,
+                -1.1068235106036057e-09,
+                6.442546962604405e-08,
+                -2.830802868181572e-06,
+                8.966525954745101e-05,
+                -0.0019214582098450639,
+                0.025424557742876257,
+                -0.18188587850106117,
+                0.3142743195347836,
+            ];
+            const C_SMALL: [Self; 13] = [
+                1.0788395601770007e-18,
+                -1.5849094421108914e-16,
+                1.9601599749420958e-14,
+                -2.00773053966069e-12,
+                1.6696510396208076e-10,
+                -1.0999812843114444e-08,
+                5.564687021283882e-07,
+                -2.075044934605623e-05,
+                0.0005393127720408215,
+                -0.009010259972062552,
+                0.08537377549656906,
+                -0.37676220250161824,
+                0.5282931311708557,
+            ];
+            const F_MID: [Self; 25] = [
+                -1.2166750741606203e-18,
+                3.961866635441197e-18,
+                -9.745358526052777e-18,
+                8.628648236702254e-18,
+                9.115726935282e-17,
+                -7.99184736325283e-16,
+                4.328572084995258e-15,
+                -1.827784719932969e-14,
+                5.975173579661843e-14,
+                -1.1498814064999822e-13,
+                -2.6825062048861394e-13,
+                4.4168693255343716e-12,
+                -2.9363466138459423e-11,
+                1.3800636998512862e-10,
+                -4.334117670340608e-10,
+                8.376938010376633e-11,
+                1.1580545484223181e-08,
+                -1.0699079376717349e-07,
+                5.893575127626909e-07,
+                -1.3392524221799433e-06,
+                -1.5950568830861515e-05,
+                0.0002715351472790031,
+                -0.001984951415044922,
+                -0.01664838901000123,
+                0.9844504917648188,
+            ];
+            const G_MID: [Self; 27] = [
+                -2.0784051503399803e-18,
+                7.431030477734515e-18,
+                -2.2083089609601722e-17,
+                4.759185287228022e-17,
+                -1.4462228750520446e-17,
+                -5.873853676159399e-16,
+                4.304858593552292e-15,
+                -2.133110765428564e-14,
+                8.414081834499759e-14,
+                -2.6024185613818887e-13,
+                4.910712332235662e-13,
+                8.528721422541683e-13,
+                -1.4963481747965205e-11,
+                9.602897320171277e-11,
+                -4.3911961896712753e-10,
+                1.4342188891058627e-09,
+                -1.8305965382943945e-09,
+                -1.801686958397347e-08,
+                1.9096838801643977e-07,
+                -1.1097048771365207e-06,
+                3.889346877197393e-06,
+                2.3006300114743153e-06,
+                -0.00017017171638227934,
+                0.0015385617730925081,
+                -0.005706857475656582,
+                -0.06897791658196482,
+                0.9326383710196579,
+            ];
+            const F_LARGE: [Self; 13] = [
+                -6.133154687820358e-17,
+                4.2901806406325284e-16,
+                3.4199921051602987e-15,
+                -8.482498971714902e-14,
+                1.833118091114417e-13,
+                1.471155357460307e-11,
+                -1.7702803642325903e-10,
+                -3.0760762846143732e-09,
+                1.1010561234838029e-07,
+                9.646025054807544e-07,
+                -0.00014496664181739706,
+                -0.000586716473105585,
+                0.9995591017809972,
+            ];
+            const G_LARGE: [Self; 16] = [
+                -2.2798245252931478e-18,
+                1.4566320402615284e-17,
+                4.870474843490819e-17,
+                -1.4693262780827568e-15,
+                6.987463617173377e-15,
+                8.781667717086795e-14,
+                -1.4588782774569176e-12,
+                2.153976522460582e-13,
+                2.2363837840796374e-10,
+                -1.8864758986245635e-09,
+                -3.767062343003852e-08,
+                9.255952011438546e-07,
+                8.448552025947798e-06,
+                -0.0007115616445336337,
+                -0.0029066109893025795,
+                0.9978124380497931,
+            ];
+        }
+    )*)
+}
+
+impl_fresnelconsts! {f32 f64}
+
+/// Fresnel integrals implementation.
+///
+/// Follows the structure of [cephes]. For $x \leq 1.6$, we use $S(x) = x^3P(x^4)$ and $C(x) = xQ(x^4)$, where $P$ and $Q$ are Chebyshev expansions. Otherwise, we use the auxiliary functions $f$ and $g$ ([DLMF 7.5.3](https://dlmf.nist.gov/7.5#E3))
+/// $$
+/// S(x) = \frac{1}{2} - f(x)\cos\left(\frac{\pi x^2}{2}\right) - g(x)\sin\left(\frac{\pi x^2}{2}\right), \quad C(x) = \frac{1}{2} + f(x)\sin\left(\frac{\pi x^2}{2}\right) - g(x)\cos\left(\frac{\pi x^2}{2}\right)
+/// $$
+/// which are approximated by Chebyshev expansions in $1/x^2$. To retain the precision of the phase for large $x$, $x^2$ is split exactly into a high and low part with a fused multiply-add, and the high part is reduced modulo 4.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/fresnl.c
+pub(crate) fn r_fresnel<T>(x: T) -> (T, T)
+where
+    T: Float + FloatConst + FresnelConsts,
+{
+    if x.is_nan() {
+        return (x, x);
+    }
+    let sign = x.signum();
+    let x = x.abs();
+
+    let half = cast::<f64, T>(0.5).unwrap();
+    let two = T::one() + T::one();
+    let four = two + two;
+    if x > two / T::epsilon() {
+        // The oscillations are below the precision of 1/2
+        return (sign * half, sign * half);
+    }
+
+    let x2 = x * x;
+    if x <= cast::<f64, T>(1.6).unwrap() {
+        let t = x2 * x2 / cast::<f64, T>(3.2768).unwrap() - T::one();
+        let s = x * x2 * eval_cheby(t, &T::S_SMALL);
+        let c = x * eval_cheby(t, &T::C_SMALL);
+        return (sign * s, sign * c);
+    }
+
+    let (f, g) = if x <= four {
+        let t = (two / x2 - cast::<f64, T>(0.453125).unwrap()) / cast::<f64, T>(0.328125).unwrap();
+        (eval_cheby(t, &T::F_MID), eval_cheby(t, &T::G_MID))
+    } else {
+        let t = cast::<f64, T>(32.0).unwrap() / x2 - T::one();
+        (eval_cheby(t, &T::F_LARGE), eval_cheby(t, &T::G_LARGE))
+    };
+    let f = f / (T::PI() * x);
+    let g = g / (T::PI() * T::PI() * x * x2);
+
+    // pi x^2 / 2 has a period of 4 in x^2
+    let x2_lo = x.mul_add(x, -x2);
+    let (sin, cos) = (((x2 % four) + x2_lo) * T::FRAC_PI_2()).sin_cos();
+    let s = half - f * cos - g * sin;
+    let c = half + f * sin - g * cos;
+    (sign * s, sign * c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 3]; 13] = [
+        // x, S(x), C(x)
+        [1e-08, 5.235987755982989e-25, 1e-08],
+        [0.01, 5.235987746754931e-07, 0.009999999975325989],
+        [0.3, 0.014116998006576583, 0.2994009760520472],
+        [1.0, 0.43825914739035476, 0.7798934003768229],
+        [1.5, 0.6975049600820931, 0.4452611760398215],
+        [1.6, 0.6388876835093809, 0.36546168344048763],
+        [2.0, 0.34341567836369824, 0.48825340607534073],
+        [3.5, 0.41524801197243755, 0.5325724350280009],
+        [4.0, 0.42051575424692844, 0.4984260330381776],
+        [7.3, 0.5189473278581442, 0.5392680156584625],
+        [25.0, 0.4999935154694762, 0.5127323855397702],
+        [1000.5, 0.4997060669389542, 0.5001217509508101],
+        [1000000.0, 0.49999968169011383, 0.5],
+    ];
+
+    #[test]
+    fn test_r_fresnel() {
+        for [x, s, c] in KNOWN_VALUES {
+            let (result_s, result_c) = r_fresnel(x);
+            assert_almost_eq!(result_s, s, PRECISION * s);
+            assert_almost_eq!(result_c, c, PRECISION * c);
+
+            // Both integrals are odd
+            let (result_s, result_c) = r_fresnel(-x);
+            assert_almost_eq!(result_s, -s, PRECISION * s);
+            assert_almost_eq!(result_c, -c, PRECISION * c);
+        }
+
+        assert_eq!(r_fresnel(0.0), (0.0, 0.0));
+        assert_eq!(r_fresnel(f64::INFINITY), (0.5, 0.5));
+        assert_eq!(r_fresnel(f64::NEG_INFINITY), (-0.5, -0.5));
+        let (s, c) = r_fresnel(f64::NAN);
+        assert!(s.is_nan() && c.is_nan());
+    }
+}
@@ -0,0 +1,179 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::gamma::gamma_util::{digamma_asymptotic, is_gamma_pole};
+use crate::special::gamma::r_digamma::{r_digamma, RealDigammaConsts};
+use crate::traits::FloatSciConst;
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{cast, Float, FromPrimitive, Zero};
+use std::ops::{AddAssign, SubAssign};
+
+/// Complex implementation of the digamma function.
+/// $$
+/// \psi(z) = \frac{d}{dz}\ln\Gamma(z)
+/// $$
+/// Input can be a real-valued float, but output will always be complex.
+///
+/// For real-valued $z$ we defer to the real implementation. Otherwise, the reflection formula is used to move $z$ into the right half-plane and the recurrence relation is then used until $|z| \geq 16$, where the asymptotic series is accurate.
+///
+/// Based off of the SciPy implementation: https://github.com/scipy/scipy/blob/main/scipy/special/_digamma.pxd
+pub(crate) fn c_digamma<T>(z: T) -> Complex<<T as ComplexFloat>::Real>
+where
+    T: ComplexFloat,
+    <T as ComplexFloat>::Real:
+        FloatSciConst + FromPrimitive + AddAssign + SubAssign + RealDigammaConsts,
+{
+    complex_digamma(Complex::new(z.re(), z.im()))
+}
+
+fn complex_digamma<T>(mut z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatSciConst + FromPrimitive + AddAssign + SubAssign + RealDigammaConsts,
+{
+    let small_abs_z = cast::<f64, T>(16.0).unwrap();
+    let half = cast::<f64, T>(0.5).unwrap();
+
+    if is_gamma_pole(z) || z.is_nan() {
+        return Complex::new(T::nan(), T::nan());
+    }
+    if z.im().is_zero() {
+        return Complex::new(r_digamma(z.re()), T::zero());
+    }
+    if z.is_infinite() {
+        return z.ln();
+    }
+
+    let mut result = Complex::<T>::zero();
+    if z.re() < T::zero() && z.im().abs() < small_abs_z {
+        // Reflection formula. The cotangent is periodic, so first reduce the real part.
+        let reduced = Complex::new(z.re().fract(), z.im()) * T::PI();
+        result = result - reduced.cos() / reduced.sin() * T::PI();
+        z = Complex::new(T::one(), T::zero()) - z;
+    }
+
+    if z.norm() < half {
+        // Step away from the pole at the origin
+        result = result - z.inv();
+        z = z + T::one();
+    }
+
+    if z.norm() >= small_abs_z {
+        return result + digamma_asymptotic(z);
+    }
+
+    let n = cast::<T, usize>(small_abs_z - z.norm()).unwrap() + 1;
+    for _ in 0..n {
+        result = result - z.inv();
+        z = z + T::one();
+    }
+    result + digamma_asymptotic(z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    #[test]
+    fn test_c_digamma() {
+        const COMPLEX_KNOWN_VALUES: [[Complex64; 2]; 11] = [
+            // Values from mpmath (v 1.3.0)
+            [
+                Complex { re: 1.0, im: 1.0 },
+                Complex {
+                    re: 0.094650320622476977272,
+                    im: 1.0766740474685811741,
+                },
+            ],
+            [
+                Complex { re: 0.5, im: -2.0 },
+                Complex {
+                    re: 0.68218669934942426814,
+                    im: -1.5707853710239763245,
+                },
+            ],
+            [
+                Complex { re: -3.5, im: 0.5 },
+                Complex {
+                    re: 1.3965080219699073014,
+                    im: 2.7575825969005915157,
+                },
+            ],
+            [
+                Complex { re: -0.2, im: 0.1 },
+                Complex {
+                    re: 3.0568354787073620931,
+                    im: 2.2274234769278975545,
+                },
+            ],
+            [
+                Complex { re: 0.1, im: 0.3 },
+                Complex {
+                    re: -1.3449043177260836901,
+                    im: 3.4109897194650701836,
+                },
+            ],
+            [
+                Complex { re: 10.0, im: 20.0 },
+                Complex {
+                    re: 3.0974040398479920216,
+                    im: 1.1272820831385623569,
+                },
+            ],
+            [
+                Complex { re: -30.0, im: 4.0 },
+                Complex {
+                    re: 3.4262959396732779285,
+                    im: 3.0112006961907252204,
+                },
+            ],
+            [
+                Complex { re: -5.0, im: 40.0 },
+                Complex {
+                    re: 3.6982197158364573346,
+                    im: 1.7074463888687307528,
+                },
+            ],
+            [
+                Complex { re: 2.0, im: 1e-5 },
+                Complex {
+                    re: 0.42278433511867282971,
+                    im: 6.4493406683999416586e-6,
+                },
+            ],
+            [
+                Complex { re: 0.3, im: -15.9 },
+                Complex {
+                    re: 2.7662333636729895634,
+                    im: -1.5833784305172445302,
+                },
+            ],
+            [
+                Complex {
+                    re: 100.0,
+                    im: -100.0,
+                },
+                Complex {
+                    re: 4.9492437762472306894,
+                    im: -0.78790233006411448025,
+                },
+            ],
+        ];
+
+        for values in COMPLEX_KNOWN_VALUES {
+            assert_almost_eq!(c_digamma(values[0]), values[1], PRECISION);
+        }
+
+        assert_almost_eq!(
+            c_digamma(-2.3),
+            Complex::new(3.3173231575618227391, 0.0),
+            PRECISION
+        );
+        assert!(c_digamma(Complex::new(-2.0, 0.0)).is_nan());
+        assert!(c_digamma(0.0).is_nan());
+    }
+}
@@ -0,0 +1,128 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::gamma::c_digamma::c_digamma;
+use crate::special::gamma::gamma_util::{is_gamma_pole, polygamma_prefactor};
+use crate::special::gamma::r_digamma::RealDigammaConsts;
+use crate::special::zeta::zeta_util::hurwitz_zeta_em;
+use crate::traits::FloatSciConst;
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, FromPrimitive, Zero};
+use std::ops::{AddAssign, SubAssign};
+
+/// Complex implementation of the polygamma function.
+/// $$
+/// \psi^{(n)}(z) = (-1)^{n+1} n! \zeta(n+1, z)
+/// $$
+/// Input can be a real-valued float, but output will always be complex.
+pub(crate) fn c_polygamma<T>(n: u32, z: T) -> Complex<<T as ComplexFloat>::Real>
+where
+    T: ComplexFloat,
+    <T as ComplexFloat>::Real:
+        FloatSciConst + FromPrimitive + AddAssign + SubAssign + RealDigammaConsts,
+{
+    if n == 0 {
+        return c_digamma(z);
+    }
+    complex_polygamma(n, Complex::new(z.re(), z.im()))
+}
+
+fn complex_polygamma<T>(n: u32, z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatSciConst + FromPrimitive,
+{
+    if z.is_nan() {
+        return Complex::new(T::nan(), T::nan());
+    }
+    if z.is_infinite() {
+        return if z.re() == T::infinity() {
+            Complex::zero()
+        } else {
+            Complex::new(T::nan(), T::nan())
+        };
+    }
+    if is_gamma_pole(z) {
+        return if n & 1 == 1 {
+            Complex::new(T::infinity(), T::zero())
+        } else {
+            Complex::new(T::nan(), T::nan())
+        };
+    }
+
+    hurwitz_zeta_em(T::from_u32(n + 1).unwrap(), z) * polygamma_prefactor::<T>(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    #[test]
+    fn test_c_polygamma() {
+        const COMPLEX_KNOWN_VALUES: [(u32, Complex64, Complex64); 6] = [
+            // Values from mpmath (v 1.3.0)
+            (
+                1,
+                Complex { re: 1.0, im: 1.0 },
+                Complex {
+                    re: 0.4630000966227637863,
+                    im: -0.79423354275931886558,
+                },
+            ),
+            (
+                3,
+                Complex { re: 1.0, im: 1.0 },
+                Complex {
+                    re: -1.5228744489534959778,
+                    im: -0.31728657866196065842,
+                },
+            ),
+            (
+                2,
+                Complex { re: -2.5, im: 0.5 },
+                Complex {
+                    re: -0.10005272999521029025,
+                    im: 9.0001346960077116958,
+                },
+            ),
+            (
+                1,
+                Complex { re: 0.2, im: -3.0 },
+                Complex {
+                    re: -0.033963462016766507707,
+                    im: 0.33305081048064318598,
+                },
+            ),
+            (
+                5,
+                Complex { re: 10.0, im: 20.0 },
+                Complex {
+                    re: 3.6003417369902742537e-6,
+                    im: 2.7280690795384906907e-6,
+                },
+            ),
+            (
+                1,
+                Complex { re: -4.3, im: 12.0 },
+                Complex {
+                    re: -0.028770806586780731033,
+                    im: -0.071855119650891559116,
+                },
+            ),
+        ];
+        for (n, z, expected) in COMPLEX_KNOWN_VALUES {
+            assert_almost_eq!(
+                c_polygamma(n, z) / expected,
+                Complex::new(1.0, 0.0),
+                PRECISION
+            );
+        }
+
+        assert_eq!(c_polygamma(1, Complex64::new(-2.0, 0.0)).re, f64::INFINITY);
+        assert!(c_polygamma(2, Complex64::new(0.0, 0.0)).is_nan());
+    }
+}
@@ -168,6 +168,100 @@ pub trait Gamma {
     /// [Gamma]: crate::special::Gamma::gamma()
     /// [cephes implementation]: https://github.com/scipy/scipy/blob/46081a85c3a6ca4c45610f4207abf791985e17e0/scipy/special/cephes/rgamma.c
     fn rgamma(self) -> Self;
+
+    /// Digamma function, the logarithmic derivative of the [Gamma] function.
+    /// $$
+    /// \psi(z) = \frac{d}{dz}\ln\Gamma(z) = \frac{\Gamma'(z)}{\Gamma(z)}
+    /// $$
+    /// The digamma function has poles at the non-positive integers, where we return `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// assert!((1.0_f64.digamma() + 0.5772156649015329).abs() < 1e-15); // -Euler-Mascheroni constant
+    /// assert!(0.0_f64.digamma().is_nan());
+    /// assert!((-2.0_f32).digamma().is_nan());
+    /// ```
+    /// For complex-valued arguments:
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// use num_complex::Complex64;
+    /// let z = Complex64{re: 1.0, im: 1.0};
+    /// println!("{}", z.digamma()); // 0.09465 + 1.077j
+    /// ```
+    ///
+    /// # Notes
+    /// For real-valued arguments, the implementation is based on the [cephes implementation] in SciPy. Negative values are reflected with
+    /// $$
+    /// \psi(1-x) - \psi(x) = \pi\cot(\pi x)
+    /// $$
+    /// and the recurrence relation $\psi(x+1) = \psi(x) + 1/x$ is used to move the argument into the interval $\[1,2\]$, where a rational approximation from [Boost] is used. For $x \geq 10$, we instead use the asymptotic series
+    /// $$
+    /// \psi(x) \sim \ln x - \frac{1}{2x} - \sum^{\infty}\_{k=1} \frac{B_{2k}}{2k x^{2k}}
+    /// $$
+    /// where $B_{2k}$ are the [Bernoulli] numbers.
+    ///
+    /// For complex-valued arguments, we follow the SciPy implementation and use the reflection formula for $\Re(z) < 0$ and then the recurrence relation until $|z| \geq 16$ where the asymptotic series is used.
+    ///
+    /// [Gamma]: crate::special::Gamma::gamma()
+    /// [Bernoulli]: crate::special::Bernoulli::bernoulli_b2n()
+    /// [cephes implementation]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/psi.c
+    /// [Boost]: https://www.boost.org/doc/libs/1_82_0/boost/math/special_functions/digamma.hpp
+    fn digamma(self) -> Self;
+
+    /// Polygamma function of order $n$, the $(n+1)$th derivative of the log of the [Gamma] function.
+    /// $$
+    /// \psi^{(n)}(z) = \frac{d^{n+1}}{dz^{n+1}}\ln\Gamma(z) = (-1)^{n+1} n! \zeta(n+1, z)
+    /// $$
+    /// where $\zeta(s, z)$ is the Hurwitz zeta function. For $n=0$, this is the [digamma] function.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// assert_eq!(2.5_f64.polygamma(0), 2.5_f64.digamma());
+    /// let pi = std::f64::consts::PI;
+    /// assert!((1.0_f64.polygamma(1) - pi * pi / 6.0).abs() < 1e-15); // zeta(2)
+    /// assert!((1.0_f64.polygamma(2) + 2.0 * 1.2020569031595942).abs() < 1e-14); // -2 zeta(3)
+    /// ```
+    /// For complex-valued arguments:
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// use num_complex::Complex64;
+    /// let z = Complex64{re: 1.0, im: 1.0};
+    /// println!("{}", z.polygamma(3)); // -1.523 - 0.3173j
+    /// ```
+    ///
+    /// # Notes
+    /// Like SciPy, the function is evaluated through the Hurwitz zeta function, which is computed from a direct sum of the first terms followed by the Euler-Maclaurin summation formula. The prefactor $n!$ is computed with [CheckedFactorial] when possible.
+    ///
+    /// At the poles $z = 0, -1, -2, \ldots$ we return $+\infty$ for odd $n$, since the function diverges to $+\infty$ from both sides, and `NaN` otherwise.
+    ///
+    /// For $\Re(z) < 0$ there is no reflection step: the terms $(z+k)^{-(n+1)}$ on either side of each pole enter the sum with opposite signs for even $n$ and cancel.
+    /// The absolute error is then about $\epsilon\\, n! \sum_k |z+k|^{-(n+1)}$, so all significant digits may be lost when the result is small next to the terms closest to the poles.
+    /// For instance $\psi^{(10)}(-50.5) \approx -3.04 \times 10^{-12}$, whereas the terms nearest to the poles contribute about $\pm 7 \times 10^{9}$ each.
+    ///
+    /// [Gamma]: crate::special::Gamma::gamma()
+    /// [digamma]: crate::special::Gamma::digamma()
+    /// [CheckedFactorial]: crate::special::CheckedFactorial
+    fn polygamma(self, n: u32) -> Self;
+
+    /// Trigamma function, the second derivative of the log of the [Gamma] function.
+    /// $$
+    /// \psi^{(1)}(z) = \frac{d^2}{dz^2}\ln\Gamma(z) = \zeta(2, z)
+    /// $$
+    /// This is equivalent to [polygamma] with $n=1$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Gamma;
+    /// let pi = std::f64::consts::PI;
+    /// assert!((0.5_f64.trigamma() - pi * pi / 2.0).abs() < 1e-14);
+    /// assert_eq!(0.0_f64.trigamma(), f64::INFINITY);
+    /// ```
+    ///
+    /// [Gamma]: crate::special::Gamma::gamma()
+    /// [polygamma]: crate::special::Gamma::polygamma()
+    fn trigamma(self) -> Self;
 }
 
 /// Gamma related functions which only make sense, or are only currently supported for real-valued arguments
@@ -222,9 +316,144 @@ pub trait RealGamma: Gamma {
 
     /// Regularized lower incomplete gamma function.
     /// $$
-    /// \gamma(s,x) = \frac{1}{\Gamma(s)}\int^x_0 t^{s-1}e^{-t}dt
+    /// P(a,x) = \frac{1}{\Gamma(a)}\int^x_0 t^{a-1}e^{-t}dt
     /// $$
+    /// where $a > 0$ and $x \geq 0$. It is the cumulative distribution function of the Gamma distribution with shape $a$ and unit scale. See the [dlmf] or [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealGamma;
+    /// assert_eq!(1.0.gammainc(0.0), 0.0);
+    /// assert!((1.0_f64.gammainc(2.0) - (1.0 - (-2.0_f64).exp())).abs() < 1e-15); // P(1, x) = 1 - e^{-x}
+    /// assert!((0.5_f64.gammainc(2.0) - 0.9544997361036416).abs() < 1e-15);
+    /// ```
+    ///
+    /// # Notes
+    /// The implementation is based on the [cephes implementation] in SciPy (v 1.10.1). When $a \approx x$ and $a$ is large, the uniform asymptotic expansion of Temme is used. Otherwise, either the power series for $P(a,x)$ or the continued fraction for $Q(a,x) = 1 - P(a,x)$ is used, depending on which converges faster.
+    ///
+    /// Panics if either $a$ or $x$ is negative.
+    ///
+    /// # References
+    /// - [dlmf]
+    /// - [wiki]
+    /// - [cephes implementation]
+    /// - Temme, N. M. (1979). "The Asymptotic Expansion of the Incomplete Gamma Functions". SIAM Journal on Mathematical Analysis. 10 (4): 757–766.
+    ///
+    /// [dlmf]: https://dlmf.nist.gov/8.2
+    /// [wiki]: https://en.wikipedia.org/wiki/Incomplete_gamma_function
+    /// [cephes implementation]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/igam.c
     fn gammainc(self, x: Self) -> Self;
+
+    /// Regularized upper incomplete gamma function.
+    /// $$
+    /// Q(a,x) = \frac{1}{\Gamma(a)}\int^{\infty}_x t^{a-1}e^{-t}dt = 1 - P(a,x)
+    /// $$
+    /// where $a > 0$, $x \geq 0$ and $P(a,x)$ is the regularized lower incomplete gamma function ([gammainc]). It is the survival function of the Gamma distribution with shape $a$ and unit scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealGamma;
+    /// assert_eq!(1.0.gammaincc(0.0), 1.0);
+    /// assert!((1.0_f64.gammaincc(2.0) - (-2.0_f64).exp()).abs() < 1e-15); // Q(1, x) = e^{-x}
+    /// ```
+    /// Computing the upper function directly does not lose precision in the tail, unlike `1 - P(a,x)`.
+    /// ```
+    /// use sci_rs::special::RealGamma;
+    /// let q = 0.1_f64.gammaincc(100.0);
+    /// assert!(((q - 6.142676307812301e-47) / q).abs() < 1e-13);
+    /// assert_eq!(1.0 - 0.1_f64.gammainc(100.0), 0.0);
+    /// ```
+    ///
+    /// # Notes
+    /// The implementation is based on the [cephes implementation] in SciPy (v 1.10.1). Like [gammainc], the uniform asymptotic expansion of Temme is used for large $a \approx x$. Otherwise, $Q(a,x)$ is computed from its continued fraction or series expansion, except in the region where $P(a,x)$ is small and $1-P(a,x)$ does not lose any precision.
+    ///
+    /// Panics if either $a$ or $x$ is negative.
+    ///
+    /// [gammainc]: crate::special::RealGamma::gammainc
+    /// [cephes implementation]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/igam.c
+    fn gammaincc(self, x: Self) -> Self;
+
+    /// Lower incomplete gamma function.
+    /// $$
+    /// \gamma(a,x) = \int^x_0 t^{a-1}e^{-t}dt = \Gamma(a)P(a,x)
+    /// $$
+    /// where $a > 0$, $x \geq 0$ and $P(a,x)$ is the regularized lower incomplete gamma function ([gammainc]).
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealGamma;
+    /// assert_eq!(2.0.gamma_lower(0.0), 0.0);
+    /// assert!((3.0_f64.gamma_lower(f64::INFINITY) - 2.0).abs() < 1e-15); // Gamma(3) = 2!
+    /// ```
+    ///
+    /// # Notes
+    /// For $x < a$, the power series is scaled by $x^ae^{-x}$ directly, such that the result does not underflow with $P(a,x)$. Otherwise, the product is formed, through logarithms when $\Gamma(a)$ overflows.
+    ///
+    /// [gammainc]: crate::special::RealGamma::gammainc
+    fn gamma_lower(self, x: Self) -> Self;
+
+    /// Upper incomplete gamma function.
+    /// $$
+    /// \Gamma(a,x) = \int^{\infty}_x t^{a-1}e^{-t}dt = \Gamma(a)Q(a,x)
+    /// $$
+    /// where $a > 0$, $x \geq 0$ and $Q(a,x)$ is the regularized upper incomplete gamma function ([gammaincc]).
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealGamma;
+    /// assert_eq!(2.0.gamma_upper(0.0), 1.0); // Gamma(2) = 1!
+    /// assert!((1.0_f64.gamma_upper(3.0) - (-3.0_f64).exp()).abs() < 1e-15);
+    /// ```
+    ///
+    /// # Notes
+    /// For $x \geq a$, the continued fraction is scaled by $x^ae^{-x}$ directly, such that the result does not underflow with $Q(a,x)$. Otherwise, the product is formed, through logarithms when $\Gamma(a)$ overflows.
+    ///
+    /// [gammaincc]: crate::special::RealGamma::gammaincc
+    fn gamma_upper(self, x: Self) -> Self;
+
+    /// Inverse of the regularized lower incomplete gamma function.
+    ///
+    /// Returns $x \geq 0$ such that $P(a,x) = p$ where $a \geq 0$, $0 \leq p \leq 1$ and $P(a,x)$ is the regularized lower incomplete gamma function ([gammainc]).
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealGamma;
+    /// assert_eq!(2.0.gammaincinv(0.0), 0.0);
+    /// assert_eq!(2.0.gammaincinv(1.0), f64::INFINITY);
+    /// let x = 3.0_f64.gammaincinv(0.4);
+    /// assert!((3.0_f64.gammainc(x) - 0.4).abs() < 1e-15);
+    /// ```
+    ///
+    /// # Notes
+    /// The implementation is based on the [cephes implementation] in SciPy (v 1.10.1). An initial approximation is found from the methods of DiDonato and Morris, which is then refined with a few iterations of Halley's method.
+    ///
+    /// Panics if $a$ is negative or $p$ lies outside of $\[0,1\]$.
+    ///
+    /// # References
+    /// - DiDonato, A. R., and Morris, A. H. (1986). Computation of the incomplete gamma function ratios and their inverse. ACM Transactions on Mathematical Software, 12(4), 377-393.
+    /// - [cephes implementation]
+    ///
+    /// [gammainc]: crate::special::RealGamma::gammainc
+    /// [cephes implementation]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/igami.c
+    fn gammaincinv(self, p: Self) -> Self;
+
+    /// Inverse of the regularized upper incomplete gamma function.
+    ///
+    /// Returns $x \geq 0$ such that $Q(a,x) = q$ where $a \geq 0$, $0 \leq q \leq 1$ and $Q(a,x)$ is the regularized upper incomplete gamma function ([gammaincc]).
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::RealGamma;
+    /// assert_eq!(2.0.gammainccinv(1.0), 0.0);
+    /// assert!((1.0_f64.gammainccinv(0.5) - 2.0_f64.ln()).abs() < 1e-15); // Q(1, x) = e^{-x}
+    /// ```
+    ///
+    /// # Notes
+    /// See [gammaincinv] for details on the implementation. Panics if $a$ is negative or $q$ lies outside of $\[0,1\]$.
+    ///
+    /// [gammaincc]: crate::special::RealGamma::gammaincc
+    /// [gammaincinv]: crate::special::RealGamma::gammaincinv
+    fn gammainccinv(self, q: Self) -> Self;
 }
 
 macro_rules! float_gamma_impl {
@@ -252,6 +481,21 @@ macro_rules! float_gamma_impl {
             fn rgamma(self) -> Self {
                 r_rgamma(self)
             }
+
+            #[inline(always)]
+            fn digamma(self) -> Self {
+                r_digamma(self)
+            }
+
+            #[inline(always)]
+            fn polygamma(self, n: u32) -> Self {
+                r_polygamma(n, self)
+            }
+
+            #[inline(always)]
+            fn trigamma(self) -> Self {
+                r_polygamma(1, self)
+            }
         }
 
         impl RealGamma for $T {
@@ -266,8 +510,33 @@ macro_rules! float_gamma_impl {
             }
 
             #[inline(always)]
-            fn gammainc(self, s: Self) -> Self {
-                r_gammainc(self, s)
+            fn gammainc(self, x: Self) -> Self {
+                r_gammainc(self, x)
+            }
+
+            #[inline(always)]
+            fn gammaincc(self, x: Self) -> Self {
+                r_gammaincc(self, x)
+            }
+
+            #[inline(always)]
+            fn gamma_lower(self, x: Self) -> Self {
+                r_gamma_lower(self, x)
+            }
+
+            #[inline(always)]
+            fn gamma_upper(self, x: Self) -> Self {
+                r_gamma_upper(self, x)
+            }
+
+            #[inline(always)]
+            fn gammaincinv(self, p: Self) -> Self {
+                r_gammaincinv(self, p)
+            }
+
+            #[inline(always)]
+            fn gammainccinv(self, q: Self) -> Self {
+                r_gammainccinv(self, q)
             }
         }
     )*)
@@ -297,6 +566,21 @@ macro_rules! float_complexgamma_impl {
             fn rgamma(self) -> Self {
                 c_rgamma(self)
             }
+
+            #[inline(always)]
+            fn digamma(self) -> Self {
+                c_digamma(self)
+            }
+
+            #[inline(always)]
+            fn polygamma(self, n: u32) -> Self {
+                c_polygamma(n, self)
+            }
+
+            #[inline(always)]
+            fn trigamma(self) -> Self {
+                c_polygamma(1, self)
+            }
         }
     )*)
 }
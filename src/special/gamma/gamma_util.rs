@@ -4,10 +4,11 @@
 //**********************************************************************
 
 use crate::special::eval_poly;
+use crate::special::{Bernoulli, CheckedFactorial, Factorial};
 use crate::traits::FloatSciConst;
 
 use num_complex::ComplexFloat;
-use num_traits::{Float, FloatConst, Zero};
+use num_traits::{Float, FloatConst, FromPrimitive, Zero};
 use std::ops::{Add, Div, Mul};
 
 /// Determines if $z$ is at a pole of the Gamma function (0, -1, -2, etc).
@@ -129,6 +130,53 @@ where
     q + rz * eval_poly(rzz, &T::Real::LNGAMMA_STIRLING_COEFFS)
 }
 
+/// Asymptotic expansion of the digamma function for large $|z|$
+/// $$
+/// \psi(z) \sim \ln z - \frac{1}{2z} - \sum^{\infty}\_{k=1} \frac{B_{2k}}{2k z^{2k}}
+/// $$
+/// where the $B_{2k}$ are Bernoulli numbers. The series is truncated after $B_{16}$, which is sufficient for $|z| \geq 10$. See the [dlmf] for more details.
+///
+/// [dlmf]: https://dlmf.nist.gov/5.11#E2
+pub(crate) fn digamma_asymptotic<T>(z: T) -> T
+where
+    T: ComplexFloat + Mul<<T as ComplexFloat>::Real, Output = T>,
+    <T as ComplexFloat>::Real: FromPrimitive,
+{
+    const NUM_TERMS: u64 = 8;
+
+    let b2n = NUM_TERMS.bernoulli_b2n::<T::Real>();
+    let rzz = (z * z).recip();
+
+    let mut series = T::zero();
+    for (k, &b) in b2n.iter().enumerate().skip(1).rev() {
+        series = (series + T::one() * (b / T::Real::from_usize(2 * k).unwrap())) * rzz;
+    }
+
+    z.ln() - (z + z).recip() - series
+}
+
+/// Computes the prefactor of the polygamma function in terms of the Hurwitz zeta function
+/// $$
+/// \psi^{(n)}(z) = (-1)^{n+1} n! \zeta(n+1, z)
+/// $$
+/// for $n \geq 1$.
+pub(crate) fn polygamma_prefactor<T>(n: u32) -> T
+where
+    T: Float + FromPrimitive,
+{
+    let factorial = match (n as u128).checked_factorial() {
+        Some(f) => T::from_u128(f).unwrap(),
+        None => (35..=n).fold(T::from_u128(34_u128.factorial()).unwrap(), |acc, i| {
+            acc * T::from_u32(i).unwrap()
+        }),
+    };
+    if n & 1 == 0 {
+        -factorial
+    } else {
+        factorial
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -10,22 +10,32 @@ pub(crate) mod gamma_util;
 
 pub use gamma_trait::*;
 
+mod c_digamma;
 mod c_gamma;
+mod c_polygamma;
+mod r_digamma;
 mod r_gamma;
 mod r_gammainc;
+mod r_gammaincinv;
 mod r_gammasgn;
 mod r_lgamma;
 mod r_poch;
+mod r_polygamma;
 mod r_rgamma;
 
-mod real_gamma_impl {
+pub(crate) mod real_gamma_impl {
+    pub(crate) use super::r_digamma::*;
     pub(crate) use super::r_gamma::*;
     pub(crate) use super::r_gammainc::*;
+    pub(crate) use super::r_gammaincinv::*;
     pub(crate) use super::r_gammasgn::*;
     pub(crate) use super::r_lgamma::*;
     pub(crate) use super::r_poch::*;
+    pub(crate) use super::r_polygamma::*;
     pub(crate) use super::r_rgamma::*;
 }
-mod complex_gamma_impl {
+pub(crate) mod complex_gamma_impl {
+    pub(crate) use super::c_digamma::*;
     pub(crate) use super::c_gamma::*;
+    pub(crate) use super::c_polygamma::*;
 }
@@ -0,0 +1,173 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::gamma::gamma_util::{digamma_asymptotic, is_gamma_pole};
+use crate::special::tools::eval_poly;
+use crate::traits::FloatSciConst;
+use num_traits::{cast, Float, FromPrimitive};
+use std::ops::{AddAssign, SubAssign};
+
+pub(crate) trait RealDigammaConsts: Sized {
+    const MIN_USE_ASYMPTOTIC: Self;
+    const MAX_HARMONIC: Self;
+    const Y: Self;
+    const ROOT1: Self;
+    const ROOT2: Self;
+    const ROOT3: Self;
+    const P: [Self; 6];
+    const Q: [Self; 7];
+}
+
+macro_rules! impl_realdigammaconsts {
+    ($($T: ty)*) => ($(
+        impl RealDigammaConsts for $T {
+            const MIN_USE_ASYMPTOTIC: Self = 10.0;
+            const MAX_HARMONIC: Self = 10.0;
+            const Y: Self = 0.99558162689208984;
+            // The positive root of digamma split into three parts to retain precision
+            const ROOT1: Self = 1569415565.0 / 1073741824.0;
+            const ROOT2: Self = (381566830.0 / 1073741824.0) / 1073741824.0;
+            const ROOT3: Self = 0.9016312093258695918615325266959189453125e-19;
+            const P: [Self; 6] = [
+                -0.0020713321167745952,
+                -0.045251321448739056,
+                -0.28919126444774784,
+                -0.65031853770896507,
+                -0.32555031186804491,
+                0.25479851061131551,
+            ];
+            const Q: [Self; 7] = [
+                -0.55789841321675513e-6,
+                0.0021284987017821144,
+                0.054151797245674225,
+                0.43593529692665969,
+                1.4606242909763515,
+                2.0767117023730469,
+                1.0,
+            ];
+        }
+)*)
+}
+
+impl_realdigammaconsts! {f32 f64}
+
+/// Rational approximation of digamma on the interval $[1,2]$.
+///
+/// The approximation is of the form $\psi(x) = g Y + g R(x-1)$ where $g = x - x_0$ and $x_0$ is the positive root of the digamma function. Taken from [Boost].
+///
+/// [Boost]: https://www.boost.org/doc/libs/1_82_0/boost/math/special_functions/digamma.hpp
+fn digamma_1_2<T>(x: T) -> T
+where
+    T: Float + SubAssign + RealDigammaConsts,
+{
+    let mut g = x - T::ROOT1;
+    g -= T::ROOT2;
+    g -= T::ROOT3;
+    let r = eval_poly(x - T::one(), &T::P) / eval_poly(x - T::one(), &T::Q);
+    g * T::Y + g * r
+}
+
+/// Digamma function for real-valued arguments.
+/// $$
+/// \psi(x) = \frac{d}{dx}\ln\Gamma(x)
+/// $$
+/// Implementation follows the [cephes library] in SciPy.
+///
+/// [cephes library]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/psi.c
+pub(crate) fn r_digamma<T>(x: T) -> T
+where
+    T: Float + FloatSciConst + FromPrimitive + AddAssign + SubAssign + RealDigammaConsts,
+{
+    if x.is_nan() || x == T::infinity() {
+        return x;
+    }
+    if x == T::neg_infinity() || is_gamma_pole(x) {
+        return T::nan();
+    }
+
+    let mut x = x;
+    let mut result = T::zero();
+    if x < T::zero() {
+        // Reflection formula with argument reduction before evaluating tan(pi * x)
+        result = -T::PI() / (T::PI() * x.fract()).tan();
+        x = T::one() - x;
+    }
+
+    if x <= T::MAX_HARMONIC && x == x.floor() {
+        let n = cast::<T, usize>(x).unwrap();
+        for i in 1..n {
+            result += cast::<usize, T>(i).unwrap().recip();
+        }
+        return result - T::GAMMA();
+    }
+
+    // Move x into the interval [1, 2] with the recurrence relation
+    if x < T::one() {
+        result -= x.recip();
+        x += T::one();
+    } else if x < T::MIN_USE_ASYMPTOTIC {
+        while x > T::one() + T::one() {
+            x -= T::one();
+            result += x.recip();
+        }
+    }
+
+    if T::one() <= x && x <= T::one() + T::one() {
+        return result + digamma_1_2(x);
+    }
+
+    result + digamma_asymptotic(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1e-14;
+
+    #[test]
+    fn test_r_digamma() {
+        const KNOWN_VALUES: [[f64; 2]; 16] = [
+            // Values from mpmath (v 1.3.0)
+            [1.0, -0.57721566490153286061],
+            [2.0, 0.42278433509846713939],
+            [10.0, 2.2517525890667211076],
+            [0.5, -1.9635100260214234794],
+            [1.5, 0.036489973978576520559],
+            [1.4616321449683623413, 3.6133324523718882433e-20],
+            [3.7, 1.1671535393615114409],
+            [0.01, -100.56088545786867242],
+            [9.99, 2.250700372831201122],
+            [25.3, 3.2109113801825358832],
+            [1000.0, 6.9072551956488120521],
+            [1e10, 23.02585092989045684],
+            [-0.5, 0.036489973978576520559],
+            [-2.3, 3.3173231575618227391],
+            [-10.9, -7.2348944535815246367],
+            [-100.1, 14.279984365172156266],
+        ];
+
+        for values in KNOWN_VALUES {
+            assert_almost_eq!(
+                r_digamma(values[0]),
+                values[1],
+                PRECISION * values[1].abs().max(1.0)
+            );
+            // Compare against the f64 result since rounding the argument matters near the poles
+            let expected = r_digamma(values[0] as f32 as f64);
+            assert_almost_eq!(
+                r_digamma(values[0] as f32),
+                expected as f32,
+                1e-5 * expected.abs().max(1.0) as f32
+            );
+        }
+
+        assert!(r_digamma(0.0).is_nan());
+        assert!(r_digamma(-3.0).is_nan());
+        assert!(r_digamma(f64::NEG_INFINITY).is_nan());
+        assert!(r_digamma(f64::NAN).is_nan());
+        assert_eq!(r_digamma(f64::INFINITY), f64::INFINITY);
+    }
+}
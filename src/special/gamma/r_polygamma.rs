@@ -0,0 +1,99 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::gamma::gamma_util::{is_gamma_pole, polygamma_prefactor};
+use crate::special::gamma::r_digamma::{r_digamma, RealDigammaConsts};
+use crate::special::zeta::zeta_util::hurwitz_zeta_em;
+use crate::traits::FloatSciConst;
+use num_traits::{Float, FromPrimitive};
+use std::ops::{AddAssign, SubAssign};
+
+/// Polygamma function for real-valued arguments.
+/// $$
+/// \psi^{(n)}(x) = (-1)^{n+1} n! \zeta(n+1, x)
+/// $$
+/// where $\zeta(s, x)$ is the Hurwitz zeta function. For $n=0$, this is the digamma function.
+pub(crate) fn r_polygamma<T>(n: u32, x: T) -> T
+where
+    T: Float + FloatSciConst + FromPrimitive + AddAssign + SubAssign + RealDigammaConsts,
+{
+    if n == 0 {
+        return r_digamma(x);
+    }
+    if x.is_nan() || x == T::neg_infinity() {
+        return T::nan();
+    }
+    if x == T::infinity() {
+        return T::zero();
+    }
+    if is_gamma_pole(x) {
+        // For odd n the function tends to +inf from both sides of the pole
+        return if n & 1 == 1 { T::infinity() } else { T::nan() };
+    }
+
+    polygamma_prefactor::<T>(n) * hurwitz_zeta_em(T::from_u32(n + 1).unwrap(), x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1e-14;
+
+    #[test]
+    fn test_r_polygamma() {
+        const KNOWN_VALUES: [(u32, f64, f64); 14] = [
+            // Values from mpmath (v 1.3.0)
+            (1, 1.0, 1.6449340668482264365),
+            (1, 0.5, 4.9348022005446793094),
+            (1, 10.0, 0.10516633568168574612),
+            (2, 3.3, -0.12375118526494272562),
+            (3, 0.1, 60004.512876790253384),
+            (1, -0.5, 8.9348022005446793094),
+            (2, -2.7, -68.932939023263798761),
+            (5, 7.25, 0.001667357519228255994),
+            (10, 1.5, -42108.858768975491797),
+            (20, 0.8, -2.6377577487428457028e+20),
+            (40, 2.0, -3.7103533597532281515e+35),
+            (1, 100000.0, 0.000010000050000166666667),
+            (4, 50.0, -9.9903982092274504454e-7),
+            (1, -10.3, 14.98688706572263782),
+        ];
+
+        for (n, x, expected) in KNOWN_VALUES {
+            assert_almost_eq!(r_polygamma(n, x) / expected, 1.0, PRECISION);
+            if n <= 20 {
+                // n! overflows f32 for larger orders
+                let expected = r_polygamma(n, x as f32 as f64);
+                assert_almost_eq!(r_polygamma(n, x as f32) / expected as f32, 1.0, 1e-4);
+            }
+        }
+
+        assert_eq!(r_polygamma(0, 2.5), r_digamma(2.5));
+        assert_eq!(r_polygamma(1, 0.0), f64::INFINITY);
+        assert_eq!(r_polygamma(3, -4.0), f64::INFINITY);
+        assert!(r_polygamma(2, -1.0).is_nan());
+        assert_eq!(r_polygamma(2, f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_r_polygamma_negative_cancellation() {
+        // Values from mpmath (v 1.3.0): (n, x, polygamma(n, x), n! * sum_k |x + k|^{-(n+1)})
+        const KNOWN_VALUES: [(u32, f64, f64, f64); 3] = [
+            (2, -50.5, -0.00038443056696236073347, 33.657200647805311578),
+            (10, -50.5, -3.0429628212571390821e-12, 14863649017.717537951),
+            (3, -20.25, 1558.5452329438901963, 1558.5452329072251940),
+        ];
+
+        // The error is bounded by the size of the terms around the poles, not by the result
+        for (n, x, expected, scale) in KNOWN_VALUES {
+            let err = (r_polygamma(n, x) - expected).abs();
+            assert!(
+                err <= 64.0 * f64::EPSILON * scale,
+                "n = {n}, x = {x}: {err}"
+            );
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::hypergeometric::r_hyp1f1::MAX_ITER;
+use crate::special::hypergeometric::r_hyp2f1::{
+    hyp2f1_series, hyp2f1_special, hyp2f1_transformation, hyp2f1_transformed,
+};
+use crate::special::{Gamma, RealGamma};
+use num_complex::Complex;
+use num_traits::{cast, Float, FloatConst};
+
+/// Largest magnitude of the transformed argument for which the linear transformations are used directly.
+const MAX_ARGUMENT: f64 = 0.8;
+
+/// Magnitude of the starting point of the analytic continuation in [c_hyp2f1], where the power series is summed.
+const CONTINUATION_START: f64 = 0.7;
+
+/// Length of each step of the analytic continuation in [c_hyp2f1], relative to the radius of convergence.
+const CONTINUATION_STEP: f64 = 0.5;
+
+/// Taylor expansion of $F(a, b; c; z)$ and its derivative around $z_0$. The coefficients $c_n$ of $(z - z_0)^n$ follow from the hypergeometric differential equation, [DLMF 15.10.1](https://dlmf.nist.gov/15.10#E1),
+/// $$
+/// z(1-z)\frac{d^2w}{dz^2} + \left(c - (a+b+1)z\right)\frac{dw}{dz} - abw = 0
+/// $$
+/// as the three-term recurrence
+/// $$
+/// z_0(1-z_0)(n+1)(n+2)c_{n+2} = -(n+1)\left((1-2z_0)n + c - (a+b+1)z_0\right)c_{n+1} + \left(n(n-1) + (a+b+1)n + ab\right)c_n
+/// $$
+/// starting from $c_0 = F(a, b; c; z_0)$ and $c_1 = F'(a, b; c; z_0)$. The series converges for $|z - z_0| < \min(|z_0|, |1 - z_0|)$.
+fn hyp2f1_taylor<T>(
+    a: T,
+    b: T,
+    c: T,
+    z0: Complex<T>,
+    value: Complex<T>,
+    derivative: Complex<T>,
+    z: Complex<T>,
+) -> (Complex<T>, Complex<T>)
+where
+    T: Float,
+{
+    let one = T::one();
+    let two = one + one;
+    let t = z - z0;
+    let p0 = z0 * (-z0 + one);
+    let p1 = -z0 * two + one;
+    let q0 = -z0 * (a + b + one) + c;
+    let q1 = a + b + one;
+
+    // u_n = c_n t^n, such that the derivative is the sum of n u_n / t
+    let mut u0 = value;
+    let mut u1 = derivative * t;
+    let mut sum = u0 + u1;
+    let mut sum_derivative = u1;
+    let mut n = T::zero();
+    for _ in 0..MAX_ITER {
+        let u2 = -((p1 * n + q0) * u1 * t * (n + one)
+            - u0 * t * t * (n * (n - one) + q1 * n + a * b))
+            / (p0 * (n + one) * (n + two));
+        sum = sum + u2;
+        sum_derivative = sum_derivative + u2 * (n + two);
+        let small = |u: Complex<T>| u.norm() <= T::epsilon() * sum.norm();
+        if small(u1) && small(u2) {
+            break;
+        }
+        u0 = u1;
+        u1 = u2;
+        n = n + one;
+    }
+    (sum, sum_derivative / t)
+}
+
+/// Gauss hypergeometric function implementation for complex arguments.
+///
+/// After the special cases in [hyp2f1_special], the linear transformations in [hyp2f1_transformed] are applied. On the branch cut $z \in (1, \infty)$, the function is continuous from below, which is enforced by an imaginary part of $-\epsilon^2z$, far below the precision of the result.
+///
+/// Near $z = e^{\pm i\pi/3}$, none of the transformations gives a small argument. If the smallest transformed argument exceeds $0.8$, $F(a, b; c; z)$ is therefore continued analytically along the ray from the origin, starting from the power series at $|z_0| = 0.7$, with the Taylor series in [hyp2f1_taylor]. Each step covers half of the radius of convergence, $\min(|z_k|, |1 - z_k|)$.
+pub(crate) fn c_hyp2f1<T>(a: T, b: T, c: T, z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatConst + Gamma + RealGamma,
+{
+    if let Some(value) = hyp2f1_special(a, b, c, z) {
+        return value;
+    }
+    let one = T::one();
+    let mut z = z;
+    if z.im.is_zero() && z.re > one {
+        z.im = -z.re * T::epsilon() * T::epsilon();
+    }
+    if hyp2f1_transformation(z).1 <= cast::<f64, T>(MAX_ARGUMENT).unwrap() {
+        return hyp2f1_transformed(a, b, c, z);
+    }
+
+    let step = cast::<f64, T>(CONTINUATION_STEP).unwrap();
+    let mut zk = z * (cast::<f64, T>(CONTINUATION_START).unwrap() / z.norm());
+    let mut value = hyp2f1_series(a, b, c, zk);
+    let mut derivative = hyp2f1_series(a + one, b + one, c + one, zk) * (a * b / c);
+    for _ in 0..MAX_ITER {
+        let distance = (z - zk).norm();
+        let length = zk.norm().min((-zk + one).norm()) * step;
+        let next = if distance <= length {
+            z
+        } else {
+            zk + (z - zk) * (length / distance)
+        };
+        (value, derivative) = hyp2f1_taylor(a, b, c, zk, value, derivative, next);
+        if next == z {
+            break;
+        }
+        zk = next;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::special::hypergeometric::r_hyp2f1::r_hyp2f1;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    const fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [(f64, f64, f64, Complex64, Complex64); 10] = [
+        // a, b, c, z, value
+        (
+            0.5,
+            1.5,
+            2.5,
+            c(0.3, 0.4),
+            c(1.0633111322641267, 0.15862202254898028),
+        ),
+        (
+            1.0,
+            1.0,
+            2.0,
+            c(2.0, 0.0),
+            c(-7.728260843496756e-53, -std::f64::consts::FRAC_PI_2),
+        ),
+        (
+            2.5,
+            -1.3,
+            1.7,
+            c(0.5, 0.866),
+            c(-0.24022922661250876, -1.3901006766878847),
+        ),
+        (
+            0.3,
+            0.7,
+            1.0,
+            c(-3.0, 2.0),
+            c(0.70998219309134, 0.0796096144197482),
+        ),
+        (
+            1.5,
+            2.5,
+            4.0,
+            c(1.0, 1.0),
+            c(0.11649736139421002, 1.244441956248364),
+        ),
+        (
+            2.0,
+            3.0,
+            5.0,
+            c(10.0, -5.0),
+            c(-0.005826052243126396, 0.05708807379596317),
+        ),
+        (
+            0.5,
+            1.5,
+            2.0,
+            c(0.6, -0.8),
+            c(0.984409570031802, -0.4284957255849223),
+        ),
+        (
+            -1.5,
+            0.5,
+            0.3,
+            c(1.5, 0.0),
+            c(-0.3327383923456778, -0.833863412704799),
+        ),
+        (
+            3.0,
+            1.0,
+            4.0,
+            c(0.0, -2.0),
+            c(0.33481923082721604, -0.44823039141860616),
+        ),
+        (
+            0.25,
+            0.75,
+            1.5,
+            c(1.2, 0.3),
+            c(1.177383837178443, 0.23331602524852937),
+        ),
+    ];
+
+    #[test]
+    fn test_c_hyp2f1() {
+        for (a, b, c, z, value) in KNOWN_VALUES {
+            assert_almost_eq!(c_hyp2f1(a, b, c, z), value, PRECISION * value.norm());
+        }
+
+        // Agrees with the real-valued implementation below the branch point
+        assert_eq!(
+            c_hyp2f1(0.5, 1.5, 2.5, c(-3.0, 0.0)),
+            c(r_hyp2f1(0.5, 1.5, 2.5, -3.0), 0.0)
+        );
+        // Continuous from below on the branch cut, independent of the sign of zero
+        assert_eq!(
+            c_hyp2f1(0.5, 1.5, 2.5, c(3.0, -0.0)),
+            c_hyp2f1(0.5, 1.5, 2.5, c(3.0, 0.0))
+        );
+
+        assert!(c_hyp2f1(1.0, 2.0, -3.0, c(0.5, 0.5)).is_nan());
+        assert!(c_hyp2f1(f64::NAN, 2.0, 3.0, c(0.5, 0.5)).is_nan());
+    }
+}
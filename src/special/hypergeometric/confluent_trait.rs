@@ -0,0 +1,135 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::hypergeometric::real_hypergeometric_impl::*;
+
+/// Confluent hypergeometric functions for real-valued arguments and parameters.
+pub trait ConfluentHypergeometric {
+    /// Confluent hypergeometric function of the first kind, or Kummer's function.
+    /// $$
+    /// M(a, b, x) = {}_1F_1(a;b;x) = \sum_{k=0}^{\infty}\frac{(a)_k}{(b)_k}\frac{x^k}{k!}
+    /// $$
+    /// where $(a)_k$ is the [Pochhammer symbol][poch]. It is a solution of Kummer's equation
+    /// $$
+    /// x\frac{d^2w}{dx^2} + (b - x)\frac{dw}{dx} - aw = 0
+    /// $$
+    /// See the [DLMF] or [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ConfluentHypergeometric;
+    /// assert_eq!(2.0_f64.hyp1f1(1.0, 1.0), 2.0_f64.exp()); // M(a, a, x) = e^x
+    /// assert!((1.5_f64.hyp1f1(0.5, 2.5) - 1.4312239940398133).abs() < 1e-15);
+    /// assert!(((-10.0_f64).hyp1f1(0.5, 2.5) - 0.3993559364666981).abs() < 1e-15);
+    /// ```
+    ///
+    /// # Notes
+    /// $M(a, b, x)$ is undefined for $b = 0, -1, -2, \ldots$ unless the series terminates before the pole, i.e. for $a = 0, -1, \ldots, b + 1$, and NaN is returned otherwise.
+    ///
+    /// For $x < 0$, Kummer's transformation
+    /// $$
+    /// M(a, b, x) = e^xM(b-a, b, -x)
+    /// $$
+    /// is applied. For $x \geq 25$, the asymptotic expansion of [DLMF 13.7.2](https://dlmf.nist.gov/13.7#E2) is attempted, with the prefactors normalized by the reciprocal Gamma function such that the terms vanish at its poles. Otherwise, or if the expansion does not converge, the power series is summed. Its terms alternate in sign if $b - a < 0$ after the transformation, such that accuracy is lost for large $a - b$ and $|x|$.
+    ///
+    /// # References
+    /// - [DLMF]
+    /// - [wiki]
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [DLMF]: https://dlmf.nist.gov/13.2
+    /// [wiki]: https://en.wikipedia.org/wiki/Confluent_hypergeometric_function
+    /// [poch]: crate::special::RealGamma::poch
+    fn hyp1f1(self, a: Self, b: Self) -> Self;
+
+    /// Confluent hypergeometric function of the second kind, or Tricomi's function.
+    ///
+    /// $U(a, b, x)$ is the solution of Kummer's equation with $U(a, b, x) \sim x^{-a}$ as $x \to \infty$. For non-integer $b$,
+    /// $$
+    /// U(a, b, x) = \frac{\Gamma(1-b)}{\Gamma(a-b+1)}M(a, b, x) + \frac{\Gamma(b-1)}{\Gamma(a)}x^{1-b}M(a-b+1, 2-b, x)
+    /// $$
+    /// and the limit is taken for integer $b$. See the [DLMF] or [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ConfluentHypergeometric;
+    /// assert!((2.0_f64.hyperu(1.0, 2.0) - 0.5).abs() < 1e-15); // U(1, 2, x) = 1/x
+    /// assert!((1.5_f64.hyperu(0.5, 2.5) - 1.0886621079036347).abs() < 1e-15);
+    /// assert!((-1.0_f64).hyperu(0.5, 2.5).is_nan());
+    /// ```
+    ///
+    /// # Notes
+    /// $U(a, b, x)$ is complex for $x < 0$, where NaN is returned. If $a$ or $a - b + 1$ is a non-positive integer, the function is a polynomial in $x$ (up to a factor $x^{1-b}$), which is summed directly.
+    ///
+    /// For $x \geq 25$, the asymptotic expansion [DLMF 13.7.3](https://dlmf.nist.gov/13.7#E3) is attempted. Otherwise, Kummer's transformation
+    /// $$
+    /// U(a, b, x) = x^{1-b}U(a-b+1, 2-b, x)
+    /// $$
+    /// ensures $b \geq 1$. For small $x$, or if $b$ is large compared to $2a + x$, the power series above, or its logarithmic limit [DLMF 13.2.9](https://dlmf.nist.gov/13.2#E9) for integer $b$, is summed. Otherwise, the ratio $U(a+1, b, x)/U(a, b, x)$ is computed from the continued fraction of the recurrence in $a$, for which $U$ is the minimal solution, and combined with the Wronskian of $M$ and $U$.
+    ///
+    /// # References
+    /// - [DLMF]
+    /// - [wiki]
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [DLMF]: https://dlmf.nist.gov/13.2
+    /// [wiki]: https://en.wikipedia.org/wiki/Confluent_hypergeometric_function
+    fn hyperu(self, a: Self, b: Self) -> Self;
+
+    /// Confluent hypergeometric limit function.
+    /// $$
+    /// {}_0F_1(;b;x) = \sum_{k=0}^{\infty}\frac{1}{(b)_k}\frac{x^k}{k!} = \lim_{a\to\infty}M\left(a, b, \frac{x}{a}\right)
+    /// $$
+    /// See the [DLMF] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::ConfluentHypergeometric;
+    /// assert!((1.5_f64.hyp0f1(0.5) - (2.0 * 1.5_f64.sqrt()).cosh()).abs() < 1e-14); // cosh(2√x)
+    /// assert!(((-40.0_f64).hyp0f1(2.5) - (-0.018563349070437905)).abs() < 1e-15);
+    /// ```
+    ///
+    /// # Notes
+    /// For $|x| \leq |b| + 1$, the power series is summed. Otherwise, the function is related to the Bessel functions,
+    /// $$
+    /// {}_0F_1(;b;x) = \begin{cases}
+    /// \Gamma(b)x^{(1-b)/2}I_{b-1}(2\sqrt{x}) & x > 0 \\\\
+    /// \Gamma(b)(-x)^{(1-b)/2}J_{b-1}(2\sqrt{-x}) & x < 0
+    /// \end{cases}
+    /// $$
+    /// as in [SciPy]. For $b = 0, -1, -2, \ldots$, NaN is returned.
+    ///
+    /// # References
+    /// - [DLMF]
+    /// - [SciPy]
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [DLMF]: https://dlmf.nist.gov/16.2
+    /// [SciPy]: https://github.com/scipy/scipy/blob/main/scipy/special/_hyp0f1.pxd
+    fn hyp0f1(self, b: Self) -> Self;
+}
+
+macro_rules! float_confluent_impl {
+    ($($T: ty)*) => ($(
+        impl ConfluentHypergeometric for $T {
+            #[inline(always)]
+            fn hyp1f1(self, a: Self, b: Self) -> Self {
+                r_hyp1f1(a, b, self)
+            }
+
+            #[inline(always)]
+            fn hyperu(self, a: Self, b: Self) -> Self {
+                r_hyperu(a, b, self)
+            }
+
+            #[inline(always)]
+            fn hyp0f1(self, b: Self) -> Self {
+                r_hyp0f1(b, self)
+            }
+        }
+    )*)
+}
+
+float_confluent_impl! {f32 f64}
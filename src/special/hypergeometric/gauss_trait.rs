@@ -0,0 +1,96 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::hypergeometric::complex_hypergeometric_impl::*;
+use crate::special::hypergeometric::real_hypergeometric_impl::*;
+
+use num_complex::Complex;
+
+/// Gauss hypergeometric function for real parameters and both real and complex-valued arguments.
+pub trait GaussHypergeometric {
+    /// Real type of the parameters $a$, $b$ and $c$.
+    type Real;
+
+    /// Gauss hypergeometric function.
+    /// $$
+    /// F(a, b; c; z) = {}_2F_1(a, b; c; z) = \sum_{k=0}^{\infty}\frac{(a)_k(b)_k}{(c)_k}\frac{z^k}{k!}
+    /// $$
+    /// where $(a)_k$ is the [Pochhammer symbol][poch]. The series converges for $|z| < 1$ and is continued analytically to the complex plane cut along $z \in [1, \infty)$. It is a solution of the hypergeometric differential equation
+    /// $$
+    /// z(1-z)\frac{d^2w}{dz^2} + \left(c - (a+b+1)z\right)\frac{dw}{dz} - abw = 0
+    /// $$
+    /// See the [DLMF] or [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::GaussHypergeometric;
+    /// assert!((0.5_f64.hyp2f1(1.0, 1.0, 2.0) - 4.0_f64.ln()).abs() < 1e-15); // -ln(1-z)/z
+    /// assert!((0.3_f64.hyp2f1(0.5, 1.5, 2.5) - 1.108062551056932).abs() < 1e-15);
+    /// assert!((-3.0_f64).hyp2f1(0.5, 1.5, 2.0).is_finite());
+    /// assert!(2.0_f64.hyp2f1(0.5, 1.5, 2.5).is_nan());
+    /// ```
+    /// For complex-valued arguments:
+    /// ```
+    /// use sci_rs::special::GaussHypergeometric;
+    /// use num_complex::Complex64;
+    /// let z = Complex64::new(2.0, 0.0);
+    /// println!("{}", z.hyp2f1(1.0, 1.0, 2.0)); // 0 - 1.5708i, -ln(1-z)/z below the branch cut
+    /// ```
+    ///
+    /// # Notes
+    /// $F(a, b; c; z)$ is undefined for $c = 0, -1, -2, \ldots$ unless the series terminates before the pole, i.e. for $a$ or $b = 0, -1, \ldots, c + 1$, and NaN is returned otherwise. If $a$ or $b$ is a non-positive integer, the function is a polynomial which is summed directly. At $z = 1$, Gauss's summation theorem is used for $c - a - b > 0$, and infinity is returned otherwise.
+    ///
+    /// Otherwise, $z$ is mapped to one of
+    /// $$
+    /// z, \quad \frac{z}{z-1}, \quad 1-z, \quad \frac{1}{1-z}, \quad \frac{1}{z}, \quad 1 - \frac{1}{z}
+    /// $$
+    /// by the linear transformations of [DLMF 15.8](https://dlmf.nist.gov/15.8), choosing the one of smallest magnitude, where the power series is summed. The Gamma functions in the connection coefficients are normalized by the reciprocal Gamma function, such that terms vanish at its poles. When $c - a - b$ or $b - a$ is an integer, the connection formulas are replaced by their limits, which involve logarithms and the digamma function, [DLMF 15.8.8](https://dlmf.nist.gov/15.8#E8) and [DLMF 15.8.10](https://dlmf.nist.gov/15.8#E10). Close to such integers, the two terms cancel and accuracy is lost.
+    ///
+    /// For real $x > 1$ the function is complex, and NaN is returned unless the series terminates. For complex arguments on the branch cut, the function is continuous from below, as in [mpmath]. Near $z = e^{\pm i\pi/3}$, where no transformation gives an argument smaller than $0.8$, the Taylor series about a nearby point is summed, with coefficients from the differential equation.
+    ///
+    /// Parameters within a relative distance of about $10^{-13}$ of an integer are treated as integers in the connection formulas. For large parameters and $|z|$ close to one, the connection formulas suffer from cancellation, and only about 10 significant digits remain for parameters of order 10. Near its zeros, a terminating polynomial is only accurate to an absolute error relative to its largest term.
+    ///
+    /// # References
+    /// - [DLMF]
+    /// - [wiki]
+    /// - [mpmath]
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [DLMF]: https://dlmf.nist.gov/15.2
+    /// [wiki]: https://en.wikipedia.org/wiki/Hypergeometric_function
+    /// [mpmath]: https://mpmath.org/doc/current/functions/hypergeometric.html#hyp2f1
+    /// [poch]: crate::special::RealGamma::poch
+    fn hyp2f1(self, a: Self::Real, b: Self::Real, c: Self::Real) -> Self;
+}
+
+macro_rules! float_gauss_impl {
+    ($($T: ty)*) => ($(
+        impl GaussHypergeometric for $T {
+            type Real = $T;
+
+            #[inline(always)]
+            fn hyp2f1(self, a: Self, b: Self, c: Self) -> Self {
+                r_hyp2f1(a, b, c, self)
+            }
+        }
+    )*)
+}
+
+float_gauss_impl! {f32 f64}
+
+macro_rules! complex_gauss_impl {
+    ($($T: ty)*) => ($(
+        impl GaussHypergeometric for Complex<$T> {
+            type Real = $T;
+
+            #[inline(always)]
+            fn hyp2f1(self, a: $T, b: $T, c: $T) -> Self {
+                c_hyp2f1(a, b, c, self)
+            }
+        }
+    )*)
+}
+
+complex_gauss_impl! {f32 f64}
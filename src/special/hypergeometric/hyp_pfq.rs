@@ -0,0 +1,252 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::gamma::gamma_util::is_gamma_pole;
+use crate::special::hypergeometric::r_hyp1f1::MAX_ITER;
+use num_traits::{Float, One, Zero};
+use std::fmt;
+
+/// Reasons why the generalized hypergeometric series in [hyp_pfq] cannot be evaluated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HypPfqError {
+    /// The series diverges for all $z \neq 0$, since $p > q + 1$ and it does not terminate.
+    Divergent,
+    /// The series does not converge, since $p = q + 1$, $|z| \geq 1$ and it does not terminate.
+    OutsideUnitDisk,
+    /// A lower parameter is a non-positive integer, and the series does not terminate before the pole.
+    Pole,
+    /// The series has not converged within the maximum number of terms, or its partial sums overflow.
+    NotConverged,
+}
+
+impl fmt::Display for HypPfqError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            HypPfqError::Divergent => "series diverges since p > q + 1",
+            HypPfqError::OutsideUnitDisk => "series diverges since p = q + 1 and |z| >= 1",
+            HypPfqError::Pole => "lower parameter is a non-positive integer",
+            HypPfqError::NotConverged => "series has not converged",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for HypPfqError {}
+
+/// Generalized hypergeometric function.
+/// $$
+/// {}_pF_q(a_1, \ldots, a_p; b_1, \ldots, b_q; z) = \sum_{k=0}^{\infty}\frac{(a_1)_k\cdots(a_p)_k}{(b_1)_k\cdots(b_q)_k}\frac{z^k}{k!}
+/// $$
+/// where $(a)_k$ is the [Pochhammer symbol][poch]. The parameters and the argument are either all real or all complex. Returns the value together with an estimate of its absolute error. See the [DLMF] or [wiki] page for more details.
+///
+/// # Examples
+/// ```
+/// use sci_rs::special::hyp_pfq;
+/// let (value, error) = hyp_pfq(&[], &[], 1.5_f64).unwrap(); // e^z
+/// assert!((value - 1.5_f64.exp()).abs() < 1e-15 * value);
+/// assert!(error < 1e-14);
+/// let (value, _) = hyp_pfq(&[1.0, 2.0, 3.0], &[4.0, 5.0], 0.5_f64).unwrap();
+/// assert!((value - 1.189874754256423).abs() < 1e-15);
+/// assert!(hyp_pfq(&[1.0, 1.0], &[2.0], 1.5_f64).is_err());
+/// ```
+/// For complex-valued parameters and arguments:
+/// ```
+/// use sci_rs::special::hyp_pfq;
+/// use num_complex::Complex64;
+/// let a = [Complex64::new(1.0, 2.0), Complex64::new(0.5, 0.0)];
+/// let b = [Complex64::new(3.0, -1.0)];
+/// let (value, error) = hyp_pfq(&a, &b, Complex64::new(0.4, 0.2)).unwrap();
+/// println!("{} +/- {}", value, error); // 0.91705 + 0.13085i
+/// ```
+///
+/// # Notes
+/// The power series is summed directly. It terminates if one of the $a_i$ is a non-positive integer, which includes the case $a_i = b_j = -n$, where the summation stops before the vanishing denominator. Otherwise, the series converges for all $z$ if $p \leq q$, and for $|z| < 1$ if $p = q + 1$. For $p > q + 1$ it diverges for all $z \neq 0$.
+///
+/// The summation stops once the real parts of all $a_i + k$ and $b_j + k$ are positive, the ratio $r$ of consecutive terms is less than one, and the geometric bound $|t_k|r/(1-r)$ on the remainder is negligible. For $p = q + 1$, $r$ is bounded below by $|z|$, its limit for $k \to \infty$, such that the series converges slowly for $|z|$ close to one. The error estimate is the sum of this remainder and the rounding error $\epsilon\sum_k|t_k|$, which dominates if the terms cancel, e.g. for large negative $z$.
+///
+/// # Errors
+/// - [HypPfqError::Divergent] if $p > q + 1$, $z \neq 0$ and the series does not terminate.
+/// - [HypPfqError::OutsideUnitDisk] if $p = q + 1$, $|z| \geq 1$ and the series does not terminate.
+/// - [HypPfqError::Pole] if a $b_j$ is a non-positive integer and the series does not terminate before it.
+/// - [HypPfqError::NotConverged] if the series has not converged within 10000 terms, or its partial sums overflow.
+///
+/// # References
+/// - [DLMF]
+/// - [wiki]
+/// - [mpmath]
+///
+/// [comment]: <> (Reference hyperlinks)
+/// [DLMF]: https://dlmf.nist.gov/16.2
+/// [wiki]: https://en.wikipedia.org/wiki/Generalized_hypergeometric_function
+/// [mpmath]: https://mpmath.org/doc/current/functions/hypergeometric.html#hyper
+/// [poch]: crate::special::RealGamma::poch
+pub fn hyp_pfq<T>(a: &[T], b: &[T], z: T) -> Result<(T, T::Real), HypPfqError>
+where
+    T: num_complex::ComplexFloat,
+{
+    if z.is_nan() || a.iter().chain(b).any(|x| x.is_nan()) {
+        let nan = T::Real::nan();
+        return Ok((T::from(nan).unwrap(), nan));
+    }
+    let terminating = a.iter().any(|&x| is_gamma_pole(x));
+    if !terminating && !z.is_zero() {
+        if a.len() > b.len() + 1 {
+            return Err(HypPfqError::Divergent);
+        }
+        if a.len() == b.len() + 1 && z.abs() >= T::Real::one() {
+            return Err(HypPfqError::OutsideUnitDisk);
+        }
+    }
+
+    let one = T::one();
+    let epsilon = T::Real::epsilon();
+    // Numerator and denominator of the ratio t_{k+1}/t_k, without z
+    let factors = |k: T| {
+        let numerator = a.iter().fold(one, |n, &x| n * (x + k));
+        let denominator = b.iter().fold(k + one, |d, &x| d * (x + k));
+        (numerator, denominator)
+    };
+
+    let mut term = one;
+    let mut sum = one;
+    let mut magnitude = T::Real::one();
+    let mut k = T::zero();
+    let (mut numerator, mut denominator) = factors(k);
+    for _ in 0..MAX_ITER {
+        if numerator.is_zero() {
+            return Ok((sum, epsilon * magnitude));
+        }
+        if denominator.is_zero() {
+            return Err(HypPfqError::Pole);
+        }
+        term = term * z * numerator / denominator;
+        sum = sum + term;
+        magnitude = magnitude + term.abs();
+        if !sum.is_finite() {
+            return Err(HypPfqError::NotConverged);
+        }
+        k = k + one;
+        (numerator, denominator) = factors(k);
+        if denominator.is_zero() || a.iter().chain(b).any(|&x| (x + k).re() <= T::Real::zero()) {
+            continue;
+        }
+        let mut ratio = (z * numerator / denominator).abs();
+        if a.len() == b.len() + 1 {
+            ratio = ratio.max(z.abs());
+        }
+        if ratio < T::Real::one() {
+            let remainder = term.abs() * ratio / (T::Real::one() - ratio);
+            if remainder <= epsilon * sum.abs() {
+                return Ok((sum, epsilon * magnitude + remainder));
+            }
+        }
+    }
+    Err(HypPfqError::NotConverged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [(&[f64], &[f64], f64, f64); 9] = [
+        // a, b, z, value
+        (&[], &[], 1.5, 4.4816890703380645),
+        (&[2.0], &[], 0.3, 2.0408163265306123),
+        (&[1.0, 2.0, 3.0], &[4.0, 5.0], 0.5, 1.189874754256423),
+        (&[0.5, 1.5], &[2.5, 3.5], -4.0, 0.7673908926404331),
+        (&[0.5], &[1.5, 2.5], -10.0, 0.3735990247481783),
+        (&[-3.0, 2.0, 1.5], &[4.0, 0.5], 2.0, -1.2),
+        (&[-2.0, 1.5], &[], -3.0, 43.75),
+        (
+            &[0.25, 1.5, 2.0],
+            &[3.5, 1.25, 0.5],
+            25.0,
+            909663347.5783528,
+        ),
+        (
+            &[1.0, 1.0, 1.0, 1.0],
+            &[2.0, 2.0, 2.0],
+            -0.9,
+            0.9095980017159598,
+        ),
+    ];
+
+    const fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    // Values from mpmath (v 1.3.0)
+    const COMPLEX_KNOWN_VALUES: [(&[Complex64], &[Complex64], Complex64, Complex64); 3] = [
+        // a, b, z, value
+        (
+            &[c(1.0, 2.0), c(0.5, 0.0)],
+            &[c(3.0, -1.0)],
+            c(0.4, 0.2),
+            c(0.9170527369904145, 0.13084827415133266),
+        ),
+        (
+            &[c(0.5, 0.0), c(1.5, 0.0), c(2.0, 0.0)],
+            &[c(2.5, 0.0), c(3.0, 0.0)],
+            c(0.3, -0.6),
+            c(1.0268687788983673, -0.14121538358535934),
+        ),
+        (
+            &[c(1.5, 0.0)],
+            &[c(2.5, 0.0), c(0.5, 0.0)],
+            c(-5.0, 3.0),
+            c(-0.9112364027631474, -0.5775416978649581),
+        ),
+    ];
+
+    #[test]
+    fn test_hyp_pfq() {
+        for (a, b, z, value) in KNOWN_VALUES {
+            let (result, error) = hyp_pfq(a, b, z).unwrap();
+            assert_almost_eq!(result, value, PRECISION * value.abs());
+            assert!(error <= PRECISION * value.abs());
+        }
+        for (a, b, z, value) in COMPLEX_KNOWN_VALUES {
+            let (result, error) = hyp_pfq(a, b, z).unwrap();
+            assert_almost_eq!(result, value, PRECISION * value.norm());
+            assert!(error <= PRECISION * value.norm());
+        }
+
+        // Cancellation is reflected in the error estimate
+        let (result, error) = hyp_pfq(&[], &[], -30.0).unwrap();
+        assert!((result - (-30.0_f64).exp()).abs() <= error);
+        assert!(error > 1.0e-6);
+
+        assert_eq!(
+            hyp_pfq(&[-2.0], &[-2.0], 3.0),
+            Ok((8.5, 8.5 * f64::EPSILON))
+        );
+        assert_eq!(hyp_pfq(&[1.0, 2.0], &[], 0.0), Ok((1.0, f64::EPSILON)));
+        assert!(hyp_pfq(&[f64::NAN], &[1.0], 0.5).unwrap().0.is_nan());
+    }
+
+    #[test]
+    fn test_hyp_pfq_errors() {
+        assert_eq!(hyp_pfq(&[1.0, 1.0], &[], 0.1), Err(HypPfqError::Divergent));
+        assert_eq!(
+            hyp_pfq(&[1.0, 1.0], &[2.0], -1.0),
+            Err(HypPfqError::OutsideUnitDisk)
+        );
+        assert_eq!(
+            hyp_pfq(&[c(1.0, 0.0), c(1.0, 0.0)], &[c(2.0, 0.0)], c(0.0, 1.5)),
+            Err(HypPfqError::OutsideUnitDisk)
+        );
+        assert_eq!(hyp_pfq(&[1.0], &[-2.0], 0.5), Err(HypPfqError::Pole));
+        assert_eq!(hyp_pfq(&[-3.0], &[-2.0], 0.5), Err(HypPfqError::Pole));
+        assert_eq!(hyp_pfq(&[], &[], -1.0e5), Err(HypPfqError::NotConverged));
+        assert_eq!(
+            hyp_pfq(&[1.0, 1.0], &[2.0], 0.9999),
+            Err(HypPfqError::NotConverged)
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Confluent, Gauss and generalized hypergeometric functions.
+
+mod confluent_trait;
+mod gauss_trait;
+mod hyp_pfq;
+
+pub use confluent_trait::*;
+pub use gauss_trait::*;
+pub use hyp_pfq::*;
+
+mod c_hyp2f1;
+mod r_hyp0f1;
+mod r_hyp1f1;
+mod r_hyp2f1;
+mod r_hyperu;
+
+pub(crate) mod real_hypergeometric_impl {
+    pub(crate) use super::r_hyp0f1::*;
+    pub(crate) use super::r_hyp1f1::*;
+    pub(crate) use super::r_hyp2f1::*;
+    pub(crate) use super::r_hyperu::*;
+}
+pub(crate) mod complex_hypergeometric_impl {
+    pub(crate) use super::c_hyp2f1::*;
+}
@@ -0,0 +1,101 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::gamma::gamma_util::is_gamma_pole;
+use crate::special::hypergeometric::r_hyp1f1::MAX_ITER;
+use crate::special::{Bessel, Gamma, ModifiedBessel, RealGamma};
+use num_traits::{cast, Float, FloatConst};
+
+/// Power series of the confluent hypergeometric limit function, see [DLMF 16.2.1](https://dlmf.nist.gov/16.2#E1),
+/// $$
+/// {}_0F_1(;b;x) = \sum_{k=0}^{\infty}\frac{1}{(b)_k}\frac{x^k}{k!}
+/// $$
+fn hyp0f1_series<T>(b: T, x: T) -> T
+where
+    T: Float,
+{
+    let half = cast::<f64, T>(0.5).unwrap();
+    let mut term = T::one();
+    let mut sum = T::one();
+    let mut k = T::zero();
+    for _ in 0..MAX_ITER {
+        term = term * x / ((b + k) * (k + T::one()));
+        sum = sum + term;
+        k = k + T::one();
+        let ratio = (x / ((b + k) * (k + T::one()))).abs();
+        if term.abs() <= T::epsilon() * sum.abs() && b + k > T::zero() && ratio < half {
+            break;
+        }
+    }
+    sum
+}
+
+/// Confluent hypergeometric limit function implementation.
+///
+/// For $|x| \leq |b| + 1$, the power series is summed. Otherwise, the relations to the Bessel functions are used, see [DLMF 10.16.9](https://dlmf.nist.gov/10.16#E9) and [DLMF 10.39.9](https://dlmf.nist.gov/10.39#E9),
+/// $$
+/// {}_0F_1(;b;x) = \begin{cases}
+/// \Gamma(b)x^{(1-b)/2}I_{b-1}(2\sqrt{x}) & x > 0 \\\\
+/// \Gamma(b)(-x)^{(1-b)/2}J_{b-1}(2\sqrt{-x}) & x < 0
+/// \end{cases}
+/// $$
+/// The prefactors are combined in logarithmic form if they overflow or underflow separately. For $b = 0, -1, -2, \ldots$, NaN is returned.
+pub(crate) fn r_hyp0f1<T>(b: T, x: T) -> T
+where
+    T: Float + FloatConst + Gamma + RealGamma + Bessel<Real = T> + ModifiedBessel<Real = T>,
+{
+    if b.is_nan() || x.is_nan() || is_gamma_pole(b) {
+        return T::nan();
+    }
+    if x.is_zero() {
+        return T::one();
+    }
+    let one = T::one();
+    if x.abs() <= b.abs() + one {
+        return hyp0f1_series(b, x);
+    }
+
+    let root = x.abs().sqrt();
+    let (bessel, exponent) = if x > T::zero() {
+        ((root + root).ive(b - one), root + root)
+    } else {
+        ((root + root).jv(b - one), T::zero())
+    };
+    let direct = b.gamma() * root.powf(one - b) * exponent.exp() * bessel;
+    if direct.is_finite() && !direct.is_zero() || bessel.is_zero() {
+        return direct;
+    }
+    let log = b.lgamma() + (one - b) * root.ln() + exponent + bessel.abs().ln();
+    b.gammasgn() * bessel.signum() * log.exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 3]; 8] = [
+        // b, x, value
+        [0.5, 0.3, 1.6624521205607885],
+        [1.5, -2.0, 0.10891980905843206],
+        [-1.5, 4.0, 63.792472506260076],
+        [2.5, -40.0, -0.018563349070437905],
+        [1.0, 100.0, 43558282.559553534],
+        [3.3, -250.0, -0.0003764964975808097],
+        [0.2, -0.7, -1.581708852012882],
+        [40.0, 30.0, 2.102860168433602],
+    ];
+
+    #[test]
+    fn test_r_hyp0f1() {
+        for [b, x, value] in KNOWN_VALUES {
+            assert_almost_eq!(r_hyp0f1(b, x), value, PRECISION * value.abs());
+        }
+        assert!(r_hyp0f1(-2.0, 0.5).is_nan());
+        assert_eq!(r_hyp0f1(0.5, 0.0), 1.0);
+    }
+}
@@ -0,0 +1,196 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::bessel::bessel_util::cos_pi;
+use crate::special::gamma::gamma_util::is_gamma_pole;
+use crate::special::{Gamma, RealGamma};
+use num_traits::{cast, Float, FloatConst};
+
+/// Maximum number of terms in the power series and the asymptotic expansions.
+pub(crate) const MAX_ITER: usize = 10000;
+
+/// Smallest $x$ for which the asymptotic expansion is attempted.
+pub(crate) const ASYMPTOTIC: f64 = 25.0;
+
+/// Power series of Kummer's function, see [DLMF 13.2.2](https://dlmf.nist.gov/13.2#E2),
+/// $$
+/// M(a, b, x) = \sum_{k=0}^{\infty}\frac{(a)_k}{(b)_k}\frac{x^k}{k!}
+/// $$
+/// The summation only stops once the terms are decreasing, since they may grow again after a small term for $|x| > 1$. For $a = 0, -1, -2, \ldots$, the series terminates.
+pub(crate) fn hyp1f1_series<T>(a: T, b: T, x: T) -> T
+where
+    T: Float,
+{
+    let half = cast::<f64, T>(0.5).unwrap();
+    let mut term = T::one();
+    let mut sum = T::one();
+    let mut k = T::zero();
+    for _ in 0..MAX_ITER {
+        term = term * (a + k) / (b + k) * x / (k + T::one());
+        sum = sum + term;
+        if term.is_zero() {
+            break;
+        }
+        k = k + T::one();
+        let ratio = ((a + k) / (b + k) * x / (k + T::one())).abs();
+        if term.abs() <= T::epsilon() * sum.abs() && b + k > T::zero() && ratio < half {
+            break;
+        }
+    }
+    sum
+}
+
+/// Sums the asymptotic series
+/// $$
+/// \sum_{k=0}^{\infty}\frac{(p)_k(q)_k}{k!}y^{-k}
+/// $$
+/// up to its smallest term. Returns the sum and the magnitude of the last term added, which is zero if the series terminates.
+pub(crate) fn asymptotic_sum<T>(p: T, q: T, y: T) -> (T, T)
+where
+    T: Float,
+{
+    let mut term = T::one();
+    let mut sum = T::one();
+    let mut k = T::zero();
+    for _ in 0..MAX_ITER {
+        let next = term * (p + k) * (q + k) / ((k + T::one()) * y);
+        if next.is_zero() {
+            return (sum, T::zero());
+        }
+        if next.abs() > term.abs() {
+            break;
+        }
+        term = next;
+        sum = sum + term;
+        if term.abs() <= T::epsilon() * sum.abs() {
+            break;
+        }
+        k = k + T::one();
+    }
+    (sum, term.abs())
+}
+
+/// Computes $\Gamma(b)e^{s}/\Gamma(c)$ without intermediate overflow, which is zero when $c$ is a pole of the Gamma function.
+pub(crate) fn gamma_ratio_exp<T>(b: T, c: T, s: T) -> T
+where
+    T: Float + Gamma + RealGamma,
+{
+    let rc = c.rgamma();
+    if rc.is_zero() {
+        return T::zero();
+    }
+    let direct = b.gamma() * rc * s.exp();
+    if direct.is_finite() && !direct.is_zero() {
+        return direct;
+    }
+    b.gammasgn() * c.gammasgn() * (b.lgamma() - c.lgamma() + s).exp()
+}
+
+/// Asymptotic expansion of $e^{s}M(a, b, x)$ for large $x > 0$, see [DLMF 13.7.2](https://dlmf.nist.gov/13.7#E2),
+/// $$
+/// M(a, b, x) \sim \frac{\Gamma(b)}{\Gamma(a)}e^xx^{a-b}\sum_{k=0}^{\infty}\frac{(b-a)_k(1-a)_k}{k!}x^{-k} + \frac{\Gamma(b)}{\Gamma(b-a)}\cos(\pi a)x^{-a}\sum_{k=0}^{\infty}\frac{(a)_k(a-b+1)_k}{k!}(-x)^{-k}
+/// $$
+/// On the positive real axis, the second term is the average of its values on either side of the Stokes line. The factor $e^s$ is included in the exponents to allow for the Kummer transformation of large negative arguments. Returns `None` if the truncation error is not negligible.
+fn hyp1f1_asymptotic<T>(a: T, b: T, x: T, s: T) -> Option<T>
+where
+    T: Float + FloatConst + Gamma + RealGamma,
+{
+    let ln_x = x.ln();
+    let (s1, e1) = asymptotic_sum(b - a, T::one() - a, x);
+    let (s2, e2) = asymptotic_sum(a, a - b + T::one(), -x);
+    let p1 = gamma_ratio_exp(b, a, x + s + (a - b) * ln_x);
+    let p2 = gamma_ratio_exp(b, b - a, s - a * ln_x) * cos_pi(a);
+    let (t1, t2) = (p1 * s1, p2 * s2);
+    let error = (p1 * e1).abs() + (p2 * e2).abs();
+    if error > T::epsilon() * (t1.abs() + t2.abs()) {
+        return None;
+    }
+    Some(t1 + t2)
+}
+
+/// Evaluates $e^{s}M(a, b, x)$ for $x > 0$ with the asymptotic expansion for $x \geq 25$, or the power series otherwise.
+fn hyp1f1_positive<T>(a: T, b: T, x: T, s: T) -> T
+where
+    T: Float + FloatConst + Gamma + RealGamma,
+{
+    if x >= cast::<f64, T>(ASYMPTOTIC).unwrap() {
+        if let Some(value) = hyp1f1_asymptotic(a, b, x, s) {
+            return value;
+        }
+    }
+    let series = hyp1f1_series(a, b, x);
+    if s.is_zero() {
+        series
+    } else {
+        s.exp() * series
+    }
+}
+
+/// Confluent hypergeometric function of the first kind implementation.
+///
+/// For $b = 0, -1, -2, \ldots$, $M(a, b, x)$ is only defined if the series terminates before the pole, i.e. $a = 0, -1, \ldots, b + 1$, and NaN is returned otherwise. Negative arguments use Kummer's transformation, [DLMF 13.2.39](https://dlmf.nist.gov/13.2#E39),
+/// $$
+/// M(a, b, x) = e^xM(b-a, b, -x)
+/// $$
+/// such that the power series has terms of a single sign for $0 < a < b$.
+pub(crate) fn r_hyp1f1<T>(a: T, b: T, x: T) -> T
+where
+    T: Float + FloatConst + Gamma + RealGamma,
+{
+    if a.is_nan() || b.is_nan() || x.is_nan() {
+        return T::nan();
+    }
+    let terminates = is_gamma_pole(a);
+    if is_gamma_pole(b) && !(terminates && a > b) {
+        return T::nan();
+    }
+    if x.is_zero() || a.is_zero() {
+        return T::one();
+    }
+    if a == b {
+        return x.exp();
+    }
+    if terminates {
+        return hyp1f1_series(a, b, x);
+    }
+    if x < T::zero() {
+        return hyp1f1_positive(b - a, b, -x, x);
+    }
+    hyp1f1_positive(a, b, x, T::zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 4]; 12] = [
+        // a, b, x, value
+        [0.5, 1.5, 0.3, 1.1096822789280745],
+        [-2.5, 3.0, 1.2, 0.21576265365638111],
+        [1.0, 2.0, -3.0, 0.3167376438773787],
+        [3.7, 1.3, -0.8, -0.05809318503728923],
+        [0.5, 2.5, -10.0, 0.3993559364666981],
+        [2.0, 5.5, 10.0, 237.2368584620257],
+        [0.3, -1.7, 2.0, 14.840205106255674],
+        [-3.0, 2.5, 6.0, 0.6571428571428571],
+        [1.5, 2.0, 30.0, 2182965772121.806],
+        [2.5, 1.5, -50.0, -6.236291175083334e-21],
+        [0.7, 3.2, 120.0, 1.5537333825287176e+47],
+        [-4.5, -2.5, -2.0, -69.4668199867361],
+    ];
+
+    #[test]
+    fn test_r_hyp1f1() {
+        for [a, b, x, value] in KNOWN_VALUES {
+            assert_almost_eq!(r_hyp1f1(a, b, x), value, PRECISION * value.abs());
+        }
+        assert!(r_hyp1f1(1.0, -2.0, 0.5).is_nan());
+        assert_eq!(r_hyp1f1(-1.0, -2.0, 0.5), 1.25);
+        assert_eq!(r_hyp1f1(0.5, 1.5, 0.0), 1.0);
+    }
+}
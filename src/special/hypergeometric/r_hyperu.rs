@@ -0,0 +1,272 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::gamma::gamma_util::is_gamma_pole;
+use crate::special::hypergeometric::r_hyp1f1::{
+    asymptotic_sum, hyp1f1_series, r_hyp1f1, ASYMPTOTIC, MAX_ITER,
+};
+use crate::special::{Gamma, RealGamma};
+use num_traits::{cast, Float, FloatConst};
+
+/// Finite sum of $U(-m, b, x)$ for $m = 0, 1, 2, \ldots$, see [DLMF 13.2.7](https://dlmf.nist.gov/13.2#E7),
+/// $$
+/// U(-m, b, x) = (-1)^m\sum_{k=0}^{m}\frac{(-m)_k(b+k)_{m-k}}{k!}x^k
+/// $$
+/// which is also defined for $b = 0, -1, -2, \ldots$.
+fn hyperu_polynomial<T>(a: T, b: T, x: T) -> T
+where
+    T: Float,
+{
+    let m = (-a).to_usize().unwrap();
+    // Pochhammer (b+k)_{m-k}, from k = m downwards
+    let mut poch = T::one();
+    let mut term = T::one();
+    let mut sum = T::zero();
+    let mut coefficients = vec![T::one(); m + 1];
+    for k in (0..m).rev() {
+        poch = poch * (b + T::from(k).unwrap());
+        coefficients[k] = poch;
+    }
+    for (k, coefficient) in coefficients.iter().enumerate() {
+        sum = sum + term * *coefficient;
+        let k = T::from(k).unwrap();
+        term = term * (a + k) * x / (k + T::one());
+    }
+    if m.is_multiple_of(2) {
+        sum
+    } else {
+        -sum
+    }
+}
+
+/// Power series for non-integer $b$, see [DLMF 13.2.42](https://dlmf.nist.gov/13.2#E42),
+/// $$
+/// U(a, b, x) = \frac{\Gamma(1-b)}{\Gamma(a-b+1)}M(a, b, x) + \frac{\Gamma(b-1)}{\Gamma(a)}x^{1-b}M(a-b+1, 2-b, x)
+/// $$
+/// This suffers from cancellation for $b$ close to an integer.
+fn hyperu_series<T>(a: T, b: T, x: T) -> T
+where
+    T: Float + Gamma,
+{
+    let one = T::one();
+    let first = (one - b).gamma() * (a - b + one).rgamma() * hyp1f1_series(a, b, x);
+    let second = (b - one).gamma()
+        * a.rgamma()
+        * x.powf(one - b)
+        * hyp1f1_series(a - b + one, one + one - b, x);
+    first + second
+}
+
+/// Power series for integer $b = n + 1$ with $n = 0, 1, 2, \ldots$, see [DLMF 13.2.9](https://dlmf.nist.gov/13.2#E9),
+/// $$
+/// U(a, n+1, x) = \frac{(-1)^{n+1}}{n!\Gamma(a-n)}\sum_{k=0}^{\infty}\frac{(a)_k}{(n+1)_kk!}x^k\left(\ln x + \psi(a+k) - \psi(1+k) - \psi(n+k+1)\right) + \frac{1}{\Gamma(a)}\sum_{k=1}^{n}\frac{(k-1)!(1-a+k)_{n-k}}{(n-k)!}x^{-k}
+/// $$
+/// which is the limit of [hyperu_series] as $b$ approaches an integer.
+fn hyperu_log_series<T>(a: T, b: T, x: T) -> T
+where
+    T: Float + Gamma,
+{
+    let one = T::one();
+    let n = (b - one).to_usize().unwrap();
+    let nf = b - one;
+
+    let mut sum = T::zero();
+    let prefactor = (a - nf).rgamma() * (nf + one).rgamma();
+    if !prefactor.is_zero() {
+        let ln_x = x.ln();
+        let mut psi_a = a.digamma();
+        let mut psi_1 = one.digamma();
+        let mut psi_n = b.digamma();
+        let mut term = one;
+        let mut k = T::zero();
+        for _ in 0..MAX_ITER {
+            let value = term * (ln_x + psi_a - psi_1 - psi_n);
+            sum = sum + value;
+            if value.abs() <= T::epsilon() * sum.abs() && k > x {
+                break;
+            }
+            term = term * (a + k) / (b + k) * x / (k + one);
+            psi_a = psi_a + (a + k).recip();
+            psi_1 = psi_1 + (k + one).recip();
+            psi_n = psi_n + (b + k).recip();
+            k = k + one;
+        }
+        sum = sum * prefactor;
+        if n.is_multiple_of(2) {
+            sum = -sum;
+        }
+    }
+
+    // Finite sum, from k = n downwards
+    let mut finite = T::zero();
+    let mut coefficient = a.rgamma() * nf.gamma();
+    for k in (1..=n).rev() {
+        let kf = T::from(k).unwrap();
+        if k < n {
+            coefficient = coefficient * (one - a + kf) / (kf * (nf - kf));
+        }
+        finite = finite + coefficient * x.powi(-(k as i32));
+    }
+    sum + finite
+}
+
+/// Evaluates $U(a, b, x)$ for $x \geq 1$ from the Wronskian, [DLMF 13.2.34](https://dlmf.nist.gov/13.2#E34), combined with the derivatives in [DLMF 13.3.15](https://dlmf.nist.gov/13.3#E15) and [DLMF 13.3.22](https://dlmf.nist.gov/13.3#E22),
+/// $$
+/// U(a, b, x) = \frac{x^{1-b}e^x}{\Gamma(a+1)\left(\mathbf{M}(a+1, b, x) - (a-b+1)\rho\mathbf{M}(a, b, x)\right)}
+/// $$
+/// where $\mathbf{M}(a, b, x) = M(a, b, x)/\Gamma(b)$ and $\rho = U(a+1, b, x)/U(a, b, x)$. Since $U(a+k, b, x)$ is the minimal solution of the recurrence, [DLMF 13.3.7](https://dlmf.nist.gov/13.3#E7),
+/// $$
+/// U(a-1, b, x) + (b - 2a - x)U(a, b, x) + a(a-b+1)U(a+1, b, x) = 0
+/// $$
+/// the ratio $\rho$ is given by a convergent continued fraction, which is evaluated with the modified Lentz method.
+fn hyperu_wronskian<T>(a: T, b: T, x: T) -> T
+where
+    T: Float + FloatConst + Gamma + RealGamma,
+{
+    let one = T::one();
+    let two = one + one;
+    let tiny = T::min_positive_value() / T::epsilon();
+
+    // rho = 1/(beta_0 - alpha_0/(beta_1 - alpha_1/(beta_2 - ...)))
+    let beta = |k: T| two * (a + k + one) + x - b;
+    let alpha = |k: T| (a + k + one) * (a + k + two - b);
+    let mut f = beta(T::zero());
+    if f.is_zero() {
+        f = tiny;
+    }
+    let mut c = f;
+    let mut d = T::zero();
+    let mut k = T::zero();
+    for _ in 0..MAX_ITER {
+        let numerator = -alpha(k);
+        k = k + one;
+        d = beta(k) + numerator * d;
+        if d.is_zero() {
+            d = tiny;
+        }
+        c = beta(k) + numerator / c;
+        if c.is_zero() {
+            c = tiny;
+        }
+        d = d.recip();
+        let delta = c * d;
+        f = f * delta;
+        if (delta - one).abs() <= T::epsilon() {
+            break;
+        }
+    }
+    let rho = f.recip();
+
+    let rb = b.rgamma();
+    let m0 = r_hyp1f1(a, b, x) * rb;
+    let m1 = r_hyp1f1(a + one, b, x) * rb;
+    x.powf(one - b) * x.exp() * (a + one).rgamma() / (m1 - (a - b + one) * rho * m0)
+}
+
+/// Asymptotic expansion for large $x$, see [DLMF 13.7.3](https://dlmf.nist.gov/13.7#E3),
+/// $$
+/// U(a, b, x) \sim x^{-a}\sum_{k=0}^{\infty}\frac{(a)_k(a-b+1)_k}{k!}(-x)^{-k}
+/// $$
+/// Returns `None` if the truncation error is not negligible.
+fn hyperu_asymptotic<T>(a: T, b: T, x: T) -> Option<T>
+where
+    T: Float,
+{
+    let (sum, error) = asymptotic_sum(a, a - b + T::one(), -x);
+    if error > T::epsilon() * sum.abs() {
+        return None;
+    }
+    Some(x.powf(-a) * sum)
+}
+
+/// Confluent hypergeometric function of the second kind implementation.
+///
+/// $U(a, b, x)$ is complex for $x < 0$, in which case NaN is returned. If $a$ or $a - b + 1$ is a non-positive integer, $U(a, b, x)$ is a polynomial, see [hyperu_polynomial]. Otherwise, the asymptotic expansion is attempted for $x \geq 25$. For $b < 1$, Kummer's transformation, [DLMF 13.2.40](https://dlmf.nist.gov/13.2#E40),
+/// $$
+/// U(a, b, x) = x^{1-b}U(a-b+1, 2-b, x)
+/// $$
+/// is applied first. The continued fraction in [hyperu_wronskian] suffers from cancellation if $b$ is large compared to $2a + x$, and the power series if $ax$ is large. The Wronskian is therefore used for $b \leq \max(2a, 0) + x + 2$ if $x \geq 1$ or $ax \geq 2$, and the power series otherwise.
+pub(crate) fn r_hyperu<T>(a: T, b: T, x: T) -> T
+where
+    T: Float + FloatConst + Gamma + RealGamma,
+{
+    if a.is_nan() || b.is_nan() || x.is_nan() || x < T::zero() {
+        return T::nan();
+    }
+    let one = T::one();
+    if a.is_zero() {
+        return one;
+    }
+    if is_gamma_pole(a) {
+        return hyperu_polynomial(a, b, x);
+    }
+    if is_gamma_pole(a - b + one) {
+        return x.powf(one - b) * hyperu_polynomial(a - b + one, one + one - b, x);
+    }
+    if x.is_zero() {
+        return if b < one {
+            (one - b).gamma() * (a - b + one).rgamma()
+        } else {
+            T::infinity()
+        };
+    }
+    if x.is_infinite() {
+        return T::zero();
+    }
+    if x >= cast::<f64, T>(ASYMPTOTIC).unwrap() {
+        if let Some(value) = hyperu_asymptotic(a, b, x) {
+            return value;
+        }
+    }
+
+    let (a, b, prefactor) = if b < one {
+        (a - b + one, one + one - b, x.powf(one - b))
+    } else {
+        (a, b, one)
+    };
+    let two = one + one;
+    let wronskian = (x >= one || a * x >= two) && b <= (a + a).max(T::zero()) + x + two;
+    let value = if wronskian {
+        hyperu_wronskian(a, b, x)
+    } else if b == b.floor() {
+        hyperu_log_series(a, b, x)
+    } else {
+        hyperu_series(a, b, x)
+    };
+    prefactor * value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-13;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 4]; 12] = [
+        // a, b, x, value
+        [0.5, 1.5, 0.3, 1.8257418583505538],
+        [2.0, 2.0, 0.05, 17.405569650239386],
+        [1.5, 3.0, 0.7, 2.926990841692556],
+        [-2.5, 1.0, 0.4, 2.125584892212107],
+        [0.3, -1.7, 2.0, 0.630313691432332],
+        [1.0, 1.0, 3.0, 0.2620837402553185],
+        [3.7, 2.5, 12.0, 5.8980875875214595e-05],
+        [-3.0, 2.5, 6.0, -25.875],
+        [2.5, -0.5, 0.0, 0.14770448757545968],
+        [1.5, 0.5, 40.0, 0.003681326967075088],
+        [0.2, 4.0, 20.0, 0.5655148068137517],
+        [25.5, 1.5, 1.5, 4.8900052924552786e-30],
+    ];
+
+    #[test]
+    fn test_r_hyperu() {
+        for [a, b, x, value] in KNOWN_VALUES {
+            assert_almost_eq!(r_hyperu(a, b, x), value, PRECISION * value.abs());
+        }
+        assert!(r_hyperu(0.5, 1.5, -1.0).is_nan());
+        assert_eq!(r_hyperu(-1.0, 2.5, 3.0), 0.5);
+    }
+}
@@ -9,20 +9,49 @@
 //! - Factorial, double factorial, and $k$-factorial
 //! - Combinatorics (choice and permutations)
 //! - Gamma and related functions
+//! - Bessel functions of the first and second kind, modified and spherical Bessel functions
+//! - Airy functions
+//! - Confluent hypergeometric functions
+//! - Gauss and generalized hypergeometric functions
+//! - Exponential integrals
+//! - Sine and cosine integrals, and their hyperbolic counterparts
+//! - Error function, Faddeeva function and related functions
+//! - Fresnel integrals
+//! - Standard normal distribution functions
+//! - Owen's T function and the bivariate normal distribution
+//! - Riemann and Hurwitz zeta functions
 mod bernoulli;
+mod bessel;
 mod beta;
 mod combinatorics;
 mod erf;
+mod expint;
 mod factorial;
+mod faddeeva;
+mod fresnel;
 mod gamma;
+mod hypergeometric;
+mod ndtr;
+mod owens_t;
+mod sici;
 mod tools;
+mod zeta;
 mod zigzag;
 
 pub use bernoulli::*;
+pub use bessel::*;
 pub use beta::*;
 pub use combinatorics::*;
 pub use erf::*;
+pub use expint::*;
 pub use factorial::*;
+pub use faddeeva::*;
+pub use fresnel::*;
 pub use gamma::*;
+pub use hypergeometric::*;
+pub use ndtr::*;
+pub use owens_t::*;
+pub use sici::*;
 pub(crate) use tools::*;
+pub use zeta::*;
 pub use zigzag::*;
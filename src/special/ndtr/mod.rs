@@ -0,0 +1,16 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Standard normal distribution functions.
+
+mod ndtr_trait;
+
+pub use ndtr_trait::*;
+
+mod r_ndtr;
+
+mod real_ndtr_impl {
+    pub(crate) use super::r_ndtr::*;
+}
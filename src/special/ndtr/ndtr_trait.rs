@@ -0,0 +1,124 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::ndtr::real_ndtr_impl::*;
+
+/// Cumulative distribution function of the standard normal distribution and related functions for real-valued arguments.
+pub trait Ndtr {
+    /// The standard normal cumulative distribution function.
+    /// $$
+    /// \Phi(x) = \frac{1}{\sqrt{2\pi}}\int_{-\infty}^x e^{-t^2/2}dt = \frac{1}{2}\mathrm{erfc}\left(-\frac{x}{\sqrt{2}}\right)
+    /// $$
+    /// Returns the area under the standard Gaussian probability density function from $-\infty$ to $x$. See the [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Ndtr;
+    /// assert_eq!(0.0_f64.ndtr(), 0.5);
+    /// assert!((1.5_f64.ndtr() - 0.9331927987311419).abs() < 1e-15);
+    /// assert!(((-10.0_f64).ndtr() - 7.619853024160527e-24).abs() < 1e-37);
+    /// ```
+    /// # Notes
+    /// The function is evaluated in terms of the [Error function] for $|x| < 1$ and the [Complementary Error function] otherwise, such that the relative precision is retained in the lower tail.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [wiki]: https://en.wikipedia.org/wiki/Normal_distribution#Cumulative_distribution_function
+    /// [Error function]: crate::special::Erf::erf
+    /// [Complementary Error function]: crate::special::Erf::erfc
+    fn ndtr(self) -> Self;
+
+    /// Natural log of the standard normal cumulative distribution function.
+    /// $$
+    /// \ln\Phi(x)
+    /// $$
+    /// Unlike `x.ndtr().ln()`, the result is accurate for $x \ll 0$, where $\Phi(x)$ underflows, as well as for $x \gg 0$, where $\Phi(x)$ rounds to 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Ndtr;
+    /// assert!(((-10.0_f64).log_ndtr() + 53.23128515051247).abs() < 1e-13);
+    /// assert!(((-40.0_f64).log_ndtr() + 804.6084420137538).abs() < 1e-12);
+    /// assert!((10.0_f64.log_ndtr() + 7.619853024160527e-24).abs() < 1e-37);
+    /// ```
+    /// # Notes
+    /// For $x < -1$, we use the [Scaled Complementary Error function] to avoid underflow,
+    /// $$
+    /// \ln\Phi(x) = \ln\left(\frac{1}{2}\mathrm{erfcx}\left(-\frac{x}{\sqrt{2}}\right)\right) - \frac{x^2}{2}
+    /// $$
+    /// Otherwise, $\ln\Phi(x) = \ln\left(1 - \frac{1}{2}\mathrm{erfc}(x/\sqrt{2})\right)$ is evaluated with `ln_1p`. This follows the implementation in [scipy].
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Scaled Complementary Error function]: crate::special::Erf::erfcx
+    /// [scipy]: https://docs.scipy.org/doc/scipy/reference/generated/scipy.special.log_ndtr.html
+    fn log_ndtr(self) -> Self;
+
+    /// Inverse of the standard normal cumulative distribution function.
+    /// $$
+    /// \Phi(x) = p
+    /// $$
+    /// Returns the value $x$ such that $\Phi(x) = p$, also known as the probit function. The valid domain is $0 \leq p \leq 1$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Ndtr;
+    /// assert_eq!(0.5_f64.ndtri(), 0.0);
+    /// assert!((0.975_f64.ndtri() - 1.959963984540054).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.ndtri(), f64::NEG_INFINITY);
+    /// ```
+    /// # Notes
+    /// We use $\Phi^{-1}(p) = -\sqrt{2}\,\mathrm{erfc}^{-1}(2p)$, where the [Inverse Complementary Error function] retains the relative precision for small $p$.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Inverse Complementary Error function]: crate::special::RealErf::erfc_inv
+    fn ndtri(self) -> Self;
+
+    /// Inverse of the natural log of the standard normal cumulative distribution function.
+    /// $$
+    /// \ln\Phi(x) = y
+    /// $$
+    /// Returns the value $x$ such that $\ln\Phi(x) = y$, which is accurate even when $e^y$ underflows. The valid domain is $y \leq 0$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Ndtr;
+    /// assert!(((-1000.0_f64).ndtri_exp() + 44.61574773196940).abs() < 1e-13);
+    /// assert!(((-3.0_f64).log_ndtr().ndtri_exp() + 3.0).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.ndtri_exp(), f64::INFINITY);
+    /// ```
+    /// # Notes
+    /// For $y \geq -2$, we use the [Inverse of the normal CDF] on $e^y$, or on $1-e^y$ evaluated through `exp_m1` when $y$ is close to zero. For smaller $y$, the equation $\ln\Phi(x) = y$ is solved with Newton's method, which converges monotonically since $\ln\Phi$ is concave.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Inverse of the normal CDF]: crate::special::Ndtr::ndtri
+    fn ndtri_exp(self) -> Self;
+}
+
+macro_rules! float_ndtr_impl {
+    ($($T: ty)*) => ($(
+        impl Ndtr for $T {
+            #[inline(always)]
+            fn ndtr(self) -> Self {
+                r_ndtr(self)
+            }
+
+            #[inline(always)]
+            fn log_ndtr(self) -> Self {
+                r_log_ndtr(self)
+            }
+
+            #[inline(always)]
+            fn ndtri(self) -> Self {
+                r_ndtri(self)
+            }
+
+            #[inline(always)]
+            fn ndtri_exp(self) -> Self {
+                r_ndtri_exp(self)
+            }
+        }
+    )*)
+}
+
+float_ndtr_impl! {f32 f64}
@@ -0,0 +1,219 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::{Erf, RealErf};
+use num_traits::{cast, Float, FloatConst};
+
+/// Standard normal cumulative distribution function implementation.
+/// $$
+/// \Phi(x) = \frac{1}{2}\mathrm{erfc}\left(-\frac{x}{\sqrt{2}}\right)
+/// $$
+/// For $|x| < 1$ the Error function is used instead, and for $x > 1$ we use $1 - \frac{1}{2}\mathrm{erfc}(x/\sqrt{2})$, such that each region avoids cancellation.
+pub(crate) fn r_ndtr<T>(x: T) -> T
+where
+    T: Float + FloatConst + Erf,
+{
+    let half = cast::<f64, T>(0.5).unwrap();
+    let t = x * T::FRAC_1_SQRT_2();
+    if x.abs() < T::one() {
+        half + half * t.erf()
+    } else if x > T::zero() {
+        T::one() - half * t.erfc()
+    } else {
+        half * (-t).erfc()
+    }
+}
+
+/// Natural log of the standard normal cumulative distribution function implementation.
+///
+/// For $x < -1$, the scaled complementary error function is used to avoid underflow,
+/// $$
+/// \ln\Phi(x) = \ln\left(\frac{1}{2}\mathrm{erfcx}(-t)\right) - t^2, \quad t = \frac{x}{\sqrt{2}}
+/// $$
+/// Otherwise, we use $\ln\Phi(x) = \ln\left(1 - \frac{1}{2}\mathrm{erfc}(t)\right)$ through `ln_1p`, which is accurate for large positive $x$; the relative error there is dominated by the rounding of $t$, about $x^2\\epsilon$. This follows the implementation of [scipy].
+///
+/// [scipy]: https://github.com/scipy/scipy/blob/main/scipy/special/_faddeeva.cxx
+pub(crate) fn r_log_ndtr<T>(x: T) -> T
+where
+    T: Float + FloatConst + Erf,
+{
+    let half = cast::<f64, T>(0.5).unwrap();
+    let t = x * T::FRAC_1_SQRT_2();
+    if x < -T::one() {
+        (half * (-t).erfcx()).ln() - t * t
+    } else {
+        (-half * t.erfc()).ln_1p()
+    }
+}
+
+/// Inverse of the standard normal cumulative distribution function implementation.
+/// $$
+/// \Phi^{-1}(p) = -\sqrt{2}\,\mathrm{erfc}^{-1}(2p)
+/// $$
+/// The inverse complementary error function retains full relative precision for small $p$, while for $p > 1/2$ it is evaluated in terms of $1-p$, which is exact.
+pub(crate) fn r_ndtri<T>(p: T) -> T
+where
+    T: Float + FloatConst + RealErf,
+{
+    -T::SQRT_2() * (p + p).erfc_inv()
+}
+
+/// Inverse of the natural log of the standard normal cumulative distribution function implementation.
+///
+/// Returns $x$ such that $\ln\Phi(x) = y$. Close to $y=0$, we use $\Phi^{-1}(e^y) = -\Phi^{-1}(-\mathrm{expm1}(y))$, while for moderate $y$ the inverse is evaluated directly from $\Phi^{-1}(e^y)$. For $y < -2$, $e^y$ may underflow, so we instead solve $\ln\Phi(x) = y$ with Newton's method, where
+/// $$
+/// \frac{d}{dx}\ln\Phi(x) = \sqrt{\frac{2}{\pi}}\frac{1}{\mathrm{erfcx}(-x/\sqrt{2})}
+/// $$
+/// Since $\ln\Phi$ is concave and increasing, the iteration converges monotonically after the first step.
+pub(crate) fn r_ndtri_exp<T>(y: T) -> T
+where
+    T: Float + FloatConst + RealErf,
+{
+    let two = T::one() + T::one();
+    if y == T::neg_infinity() {
+        return y;
+    }
+    if y > (-(-two).exp()).ln_1p() {
+        return -r_ndtri(-y.exp_m1());
+    }
+    if y >= -two {
+        return r_ndtri(y.exp());
+    }
+
+    // ln(Phi(x)) ~ -x^2/2 for x -> -infinity
+    let mut x = -(-two * y).sqrt();
+    let sqrt_2_pi = T::FRAC_2_PI().sqrt();
+    let mut previous_step = T::infinity();
+    for _ in 0..100 {
+        let step = (r_log_ndtr(x) - y) * (-x * T::FRAC_1_SQRT_2()).erfcx() / sqrt_2_pi;
+        x = x - step;
+        if step.abs() <= T::epsilon() * x.abs() || step.abs() >= previous_step.abs() {
+            break;
+        }
+        previous_step = step;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    #[test]
+    fn test_r_ndtr() {
+        const KNOWN_VALUES: [[f64; 2]; 8] = [
+            // Values from mpmath (v 1.3.0)
+            [-20.0, 2.7536241186062337e-89],
+            [-5.0, 2.866515718791939e-07],
+            [-1.5, 0.06680720126885807],
+            [-0.5, 0.3085375387259869],
+            [0.3, 0.6179114221889527],
+            [1.0, 0.8413447460685429],
+            [2.5, 0.9937903346742238],
+            [6.0, 0.9999999990134123],
+        ];
+
+        for [x, expected] in KNOWN_VALUES {
+            // The rounding error of x/sqrt(2) is amplified in the lower tail
+            assert_almost_eq!(r_ndtr(x), expected, PRECISION * expected * (1.0 + x * x));
+        }
+
+        assert_eq!(r_ndtr(0.0), 0.5);
+        assert_eq!(r_ndtr(f64::INFINITY), 1.0);
+        assert_eq!(r_ndtr(f64::NEG_INFINITY), 0.0);
+        assert!(r_ndtr(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_r_log_ndtr() {
+        const KNOWN_VALUES: [[f64; 2]; 8] = [
+            // Values from mpmath (v 1.3.0)
+            [-100000.0, -5000000012.431864],
+            [-100.0, -5005.524208694205],
+            [-20.0, -203.91715537109727],
+            [-3.0, -6.607726221510349],
+            [-1.0, -1.8410216450092636],
+            [0.5, -0.3689464152886564],
+            [3.0, -0.0013508099647481938],
+            [10.0, -7.619853024160525e-24],
+        ];
+
+        for [x, expected] in KNOWN_VALUES {
+            assert_almost_eq!(r_log_ndtr(x), expected, PRECISION * expected.abs());
+        }
+
+        // log(ncdf(x)) in mpmath needs more than 40 digits here, so these are ln_1p(-erfc(x/sqrt(2))/2)
+        const UPPER_TAIL: [[f64; 2]; 6] = [
+            // Values from mpmath (v 1.3.0)
+            [9.0, -1.1285884059538406e-19],
+            [11.0, -1.9106595744986757e-28],
+            [12.0, -1.776482112077679e-33],
+            [13.0, -6.1171643995498797e-39],
+            [20.0, -2.7536241186062337e-89],
+            [37.0, -5.7255712225245768e-300],
+        ];
+
+        for [x, expected] in UPPER_TAIL {
+            // The rounding error of x/sqrt(2) is amplified by x^2
+            assert_almost_eq!(
+                r_log_ndtr(x),
+                expected,
+                2.0 * f64::EPSILON * x * x * expected.abs()
+            );
+        }
+
+        assert_eq!(r_log_ndtr(f64::INFINITY), 0.0);
+        assert_eq!(r_log_ndtr(f64::NEG_INFINITY), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_r_ndtri() {
+        const KNOWN_VALUES: [[f64; 2]; 8] = [
+            // Values from mpmath (v 1.3.0)
+            [1e-300, -37.0470962993612],
+            [1e-10, -6.361340902404057],
+            [0.001, -3.0902323061678136],
+            [0.1, -1.2815515655446004],
+            [0.4, -0.2533471031357997],
+            [0.6, 0.2533471031357997],
+            [0.9, 1.2815515655446006],
+            [0.999999, 4.753424308817087],
+        ];
+
+        for [p, expected] in KNOWN_VALUES {
+            assert_almost_eq!(r_ndtri(p), expected, PRECISION * expected.abs());
+        }
+
+        assert_eq!(r_ndtri(0.0), f64::NEG_INFINITY);
+        assert_eq!(r_ndtri(0.5), 0.0);
+        assert_eq!(r_ndtri(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_r_ndtri_exp() {
+        const KNOWN_VALUES: [[f64; 2]; 7] = [
+            // Values from mpmath (v 1.3.0)
+            [-100000.0, -447.1978936785251],
+            [-1000.0, -44.6157477319694],
+            [-50.0, -9.674825283612357],
+            [-2.5, -1.3911828143255685],
+            [-1.0, -0.33747496376420244],
+            [-0.1, 1.3096177994584932],
+            [-1e-10, 6.361340902411735],
+        ];
+
+        for [y, expected] in KNOWN_VALUES {
+            assert_almost_eq!(r_ndtri_exp(y), expected, PRECISION * expected.abs());
+        }
+
+        assert_eq!(r_ndtri_exp(0.0), f64::INFINITY);
+        assert_eq!(r_ndtri_exp(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        for x in [-30.0, -5.0, 0.5, 3.0] {
+            assert_almost_eq!(r_ndtri_exp(r_log_ndtr(x)), x, PRECISION * x.abs());
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Owen's T function and the bivariate normal distribution.
+
+mod owens_t_trait;
+
+pub use owens_t_trait::*;
+
+mod r_bvnd;
+mod r_owens_t;
+
+mod real_owens_t_impl {
+    pub(crate) use super::r_bvnd::*;
+    pub(crate) use super::r_owens_t::*;
+}
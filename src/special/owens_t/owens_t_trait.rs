@@ -0,0 +1,79 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::owens_t::real_owens_t_impl::*;
+
+/// Owen's T function and the bivariate normal cumulative distribution function for real-valued arguments.
+pub trait OwensT {
+    /// Owen's T function.
+    /// $$
+    /// T(h, a) = \frac{1}{2\pi}\int_0^a \frac{e^{-h^2(1+x^2)/2}}{1+x^2}dx
+    /// $$
+    /// Gives the probability of the event $X > h$ and $0 < Y < aX$, where $X$ and $Y$ are independent standard normal random variables. See the [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::OwensT;
+    /// assert!((0.5_f64.owens_t(0.3) - 0.040786707344250106).abs() < 1e-16);
+    /// assert!((3.0_f64.owens_t(2.0) - 0.0006749490155352161).abs() < 1e-18);
+    /// assert_eq!(1.0_f64.owens_t(0.0), 0.0);
+    /// ```
+    /// # Notes
+    /// We use the algorithm of [Patefield and Tandy], which chooses between series expansions and Gauss-Legendre quadrature depending on $(h, a)$. The arguments are first reduced to $h \geq 0$ and $0 \leq a \leq 1$ with the symmetries
+    /// $$
+    /// T(-h, a) = T(h, a), \quad T(h, -a) = -T(h, a)
+    /// $$
+    /// and, for $a > 1$,
+    /// $$
+    /// T(h, a) = \frac{\Phi(h) + \Phi(ah)}{2} - \Phi(h)\Phi(ah) - T\left(ah, \frac{1}{a}\right) - \frac{1}{2}\[h < 0\]
+    /// $$
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [wiki]: https://en.wikipedia.org/wiki/Owen%27s_T_function
+    /// [Patefield and Tandy]: https://doi.org/10.18637/jss.v005.i05
+    fn owens_t(self, a: Self) -> Self;
+
+    /// Bivariate normal cumulative distribution function.
+    /// $$
+    /// \Phi_2(h, k; \rho) = \frac{1}{2\pi\sqrt{1-\rho^2}}\int_{-\infty}^h\int_{-\infty}^k \exp\left(-\frac{x^2 - 2\rho xy + y^2}{2(1-\rho^2)}\right)dy\,dx
+    /// $$
+    /// Returns the probability $P(X \leq h, Y \leq k)$, where $X$ and $Y$ are standard normal random variables with correlation coefficient $-1 \leq \rho \leq 1$. Returns NaN for $|\rho| > 1$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::OwensT;
+    /// assert!((0.0_f64.bvnd(0.0, 0.5) - 1.0 / 3.0).abs() < 1e-15);
+    /// assert!((1.0_f64.bvnd(-1.0, 0.3) - 0.14833820905742245).abs() < 1e-15);
+    /// assert!(((-2.0_f64).bvnd(-3.0, -0.7) - 2.3154176706053515e-12).abs() < 1e-17);
+    /// ```
+    /// # Notes
+    /// The function is evaluated in terms of [Owen's T function],
+    /// $$
+    /// \Phi_2(h, k; \rho) = \frac{\Phi(h) + \Phi(k)}{2} - T\left(h, \frac{k - \rho h}{h\sqrt{1-\rho^2}}\right) - T\left(k, \frac{h - \rho k}{k\sqrt{1-\rho^2}}\right) - \beta
+    /// $$
+    /// where $\beta = 1/2$ if $hk < 0$, or $hk = 0$ and $h + k < 0$, and $\beta = 0$ otherwise. The reflection $\Phi_2(h, k; \rho) = \Phi(h) - \Phi_2(h, -k; -\rho)$ is used when exactly one of the arguments is negative to reduce cancellation. The result is accurate to machine precision in the absolute sense, but the relative precision is lost for very small probabilities.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Owen's T function]: crate::special::OwensT::owens_t
+    fn bvnd(self, k: Self, rho: Self) -> Self;
+}
+
+macro_rules! float_owens_t_impl {
+    ($($T: ty)*) => ($(
+        impl OwensT for $T {
+            #[inline(always)]
+            fn owens_t(self, a: Self) -> Self {
+                r_owens_t(self, a)
+            }
+
+            #[inline(always)]
+            fn bvnd(self, k: Self, rho: Self) -> Self {
+                r_bvnd(self, k, rho)
+            }
+        }
+    )*)
+}
+
+float_owens_t_impl! {f32 f64}
@@ -0,0 +1,136 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::owens_t::real_owens_t_impl::r_owens_t;
+use crate::special::{Erf, Ndtr};
+use crate::traits::FloatSciConst;
+use num_traits::{cast, Float};
+
+/// Bivariate normal cumulative distribution function implementation.
+///
+/// The arguments are reflected such that at most one of $h$ and $k$ is positive before evaluating [bvnd_owens_t]. When exactly one is negative, we use
+/// $$
+/// \Phi_2(h, k; \rho) = \Phi(h) - \Phi_2(h, -k; -\rho)
+/// $$
+/// such that the terms of the sum are at most of order $\Phi(h)$, rather than of order one. The sum still cancels when both arguments are large and negative, so the result is accurate in the absolute, rather than the relative, sense.
+pub(crate) fn r_bvnd<T>(h: T, k: T, rho: T) -> T
+where
+    T: Float + FloatSciConst + Erf + Ndtr,
+{
+    if h.is_nan() || k.is_nan() || rho.is_nan() || rho.abs() > T::one() {
+        return T::nan();
+    }
+    if h == T::neg_infinity() || k == T::neg_infinity() {
+        return T::zero();
+    }
+    if h == T::infinity() {
+        return k.ndtr();
+    }
+    if k == T::infinity() {
+        return h.ndtr();
+    }
+    if rho.is_one() {
+        return h.min(k).ndtr();
+    }
+    if rho == -T::one() {
+        return if h + k <= T::zero() {
+            T::zero()
+        } else {
+            h.ndtr() - (-k).ndtr()
+        };
+    }
+
+    let result = if h < T::zero() && k >= T::zero() {
+        h.ndtr() - bvnd_owens_t(h, -k, -rho)
+    } else if k < T::zero() && h >= T::zero() {
+        k.ndtr() - bvnd_owens_t(-h, k, -rho)
+    } else {
+        bvnd_owens_t(h, k, rho)
+    };
+    result.max(T::zero()).min(T::one())
+}
+
+/// Bivariate normal cumulative distribution function in terms of Owen's T function.
+/// $$
+/// \Phi_2(h, k; \rho) = \frac{\Phi(h) + \Phi(k)}{2} - T\left(h, \frac{k - \rho h}{h\sqrt{1-\rho^2}}\right) - T\left(k, \frac{h - \rho k}{k\sqrt{1-\rho^2}}\right) - \beta
+/// $$
+/// where $\beta = 0$ if $hk > 0$, or $hk = 0$ and $h + k \geq 0$, and $\beta = 1/2$ otherwise. See [Owen] (1956) for details.
+///
+/// [Owen]: https://doi.org/10.1214/aoms/1177728074
+fn bvnd_owens_t<T>(h: T, k: T, rho: T) -> T
+where
+    T: Float + FloatSciConst + Erf + Ndtr,
+{
+    let quarter = cast::<f64, T>(0.25).unwrap();
+    if h.is_zero() && k.is_zero() {
+        return quarter + rho.asin() / T::TAU();
+    }
+
+    let s = ((T::one() - rho) * (T::one() + rho)).sqrt();
+    // T(0, a) = sign(a)/4 as |a| -> infinity
+    let t_h = if h.is_zero() {
+        quarter.copysign(k)
+    } else {
+        r_owens_t(h, (k - rho * h) / (h * s))
+    };
+    let t_k = if k.is_zero() {
+        quarter.copysign(h)
+    } else {
+        r_owens_t(k, (h - rho * k) / (k * s))
+    };
+
+    let half = quarter + quarter;
+    let beta = if h * k > T::zero() || (h * k == T::zero() && h + k >= T::zero()) {
+        T::zero()
+    } else {
+        half
+    };
+    half * (h.ndtr() + k.ndtr()) - t_h - t_k - beta
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-15;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 4]; 10] = [
+        // h, k, rho, bvnd
+        [0.0, 0.0, 0.5, 0.3333333333333333],
+        [1.0, -1.0, 0.3, 0.14833820905742245],
+        [-2.0, -3.0, -0.7, 2.3154176706053515e-12],
+        [0.5, 1.5, 0.9, 0.6909856723713372],
+        [-1.0, 2.0, -0.5, 0.14538903692094032],
+        [2.0, 0.0, 0.0, 0.4886249340259104],
+        [-0.2, -0.2, 0.9999, 0.41853405076410505],
+        [-3.0, -1.0, 0.4, 0.0008541505281094654],
+        [6.0, 6.0, -0.999, 0.9999999980268247],
+        [-8.0, 6.0, 0.4, 6.220960574271784e-16],
+    ];
+
+    #[test]
+    fn test_r_bvnd() {
+        for [h, k, rho, expected] in KNOWN_VALUES {
+            assert_almost_eq!(r_bvnd(h, k, rho), expected, PRECISION);
+            assert_almost_eq!(r_bvnd(k, h, rho), expected, PRECISION);
+        }
+    }
+
+    #[test]
+    fn test_r_bvnd_limits() {
+        // rho = 0 factorizes, and rho = +/-1 are the Frechet bounds
+        for [h, k] in [[-1.0, 0.5], [0.3, 2.0], [-2.0, -0.1]] {
+            let (ph, pk) = (h.ndtr(), k.ndtr());
+            assert_almost_eq!(r_bvnd(h, k, 0.0), ph * pk, PRECISION);
+            assert_eq!(r_bvnd(h, k, 1.0), ph.min(pk));
+            assert_almost_eq!(r_bvnd(h, k, -1.0), (ph + pk - 1.0).max(0.0), PRECISION);
+        }
+        assert_eq!(r_bvnd(f64::NEG_INFINITY, 1.0, 0.5), 0.0);
+        assert_eq!(r_bvnd(f64::INFINITY, 1.0, 0.5), 1.0_f64.ndtr());
+        assert!(r_bvnd(0.0, 0.0, 1.5).is_nan());
+        assert!(r_bvnd(f64::NAN, 0.0, 0.5).is_nan());
+    }
+}
@@ -0,0 +1,18 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Sine and cosine integrals, and their hyperbolic counterparts.
+
+mod sici_trait;
+
+pub use sici_trait::*;
+
+mod r_shichi;
+mod r_sici;
+
+mod real_sici_impl {
+    pub(crate) use super::r_shichi::*;
+    pub(crate) use super::r_sici::*;
+}
@@ -0,0 +1,266 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::eval_cheby;
+use crate::traits::FloatSciConst;
+use num_traits::{cast, Float};
+
+/// Chebyshev coefficients for the hyperbolic sine and cosine integrals, computed with mpmath.
+///
+/// The tables approximate $xe^{-x}\mathrm{Shi}(x)$ and $xe^{-x}(\mathrm{Chi}(x) - \gamma - \ln x)$ in $1/x$ on $\[8, 18\]$ and $\[18, 88\]$, with the same intervals as [cephes].
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/shichi.c
+pub(crate) trait ShichiConsts: Sized {
+    const SHI_MID: [Self; 23];
+    const CHI_MID: [Self; 24];
+    const SHI_LARGE: [Self; 25];
+    const CHI_LARGE: [Self; 26];
+}
+
+macro_rules! impl_shichiconsts {
+    ($($T: ty)*) => ($(
+        impl ShichiConsts for $T {
+            const SHI_MID: [Self; 23] = [
+                -1.5220499299840323e-18,
+                1.8388923017339935e-17,
+                -9.554855322796549e-17,
+                2.0432610598087968e-16,
+                1.0989694907490533e-15,
+                -1.3131353434409254e-14,
+                5.93976226264314e-14,
+                -3.4719701049774844e-14,
+                -1.4005976461311708e-12,
+                9.490446262242231e-12,
+                -1.6159618114543533e-11,
+                -1.7789978443643027e-10,
+                1.3545546976724692e-09,
+                -1.032571217928194e-09,
+                -3.566996111149825e-08,
+                1.448188773842673e-07,
+                7.820182151840513e-07,
+                -5.39919118403805e-06,
+                -3.124582021689598e-05,
+                8.901367419507274e-05,
+                0.0020255847474384687,
+                0.029606444085563326,
+                0.5592387552362852,
+            ];
+            const CHI_MID: [Self; 24] = [
+                1.4957436850548162e-18,
+                -8.124353852258634e-18,
+                2.17586413290339e-17,
+                5.226243949240723e-17,
+                -9.488121105916902e-16,
+                5.355463116474649e-15,
+                -1.2100997011373281e-14,
+                -6.008651785534474e-14,
+                7.163396491560282e-13,
+                -2.934960726075997e-12,
+                -1.4035943813649147e-12,
+                8.763022886090548e-11,
+                -4.4009247621328215e-10,
+                -1.8799207564056955e-10,
+                1.3145815098947457e-08,
+                -4.755139309247653e-08,
+                -2.2177501880184887e-07,
+                1.9463553137327247e-06,
+                4.335058892573164e-06,
+                -6.133870010764943e-05,
+                -0.00031308547749299745,
+                0.000497164789823116,
+                0.026434749603137454,
+                0.5572307543834961,
+            ];
+            const SHI_LARGE: [Self; 25] = [
+                1.2191977273724642e-18,
+                -1.4738536119767914e-18,
+                -1.0531157415485094e-17,
+                2.624460955963552e-17,
+                8.820901356253682e-17,
+                -3.3845981187810305e-16,
+                -8.306080263669358e-16,
+                3.9339787543705e-15,
+                1.0176556596972905e-14,
+                -4.211281703076408e-14,
+                -1.6081820451980247e-13,
+                3.347149541759945e-13,
+                2.7260035212915307e-12,
+                1.6689495475283908e-12,
+                -3.492781410247309e-11,
+                -1.585806616664827e-10,
+                -1.7928943718335563e-10,
+                1.7628162914426453e-09,
+                1.690502288794213e-08,
+                1.2539177122848704e-07,
+                1.1622994706867733e-06,
+                1.610382601173763e-05,
+                0.000349810375601054,
+                0.012847806525964761,
+                0.5183286129439917,
+            ];
+            const CHI_LARGE: [Self; 26] = [
+                -9.740098103867921e-19,
+                1.0758157691309413e-18,
+                8.069134082551556e-18,
+                -2.0807416818014817e-17,
+                -5.981113296582723e-17,
+                2.6853395108594575e-16,
+                4.523139416989047e-16,
+                -3.1073491733529946e-15,
+                -4.4282320733253195e-15,
+                3.4963969541080694e-14,
+                6.634067317189116e-14,
+                -3.7190244809311924e-13,
+                -1.271354181323383e-12,
+                2.7485114193531538e-12,
+                2.3378184398545344e-11,
+                2.7143600637761244e-11,
+                -2.56600180000356e-10,
+                -1.6102137516380345e-09,
+                -4.725430648762717e-09,
+                -3.0009517802868167e-09,
+                7.793874743909149e-08,
+                1.069427655664015e-06,
+                1.595031648023132e-05,
+                0.000349592575153778,
+                0.012847538753006526,
+                0.5183284695896714,
+            ];
+        }
+    )*)
+}
+
+impl_shichiconsts! {f32 f64}
+
+/// Hyperbolic sine and cosine integrals implementation.
+///
+/// Taken from [cephes]. For $x \leq 8$, we sum the power series
+/// $$
+/// \mathrm{Shi}(x) = \sum_{k=0}^{\infty}\frac{x^{2k+1}}{(2k+1)(2k+1)!}, \quad \mathrm{Chi}(x) = \gamma + \ln x + \sum_{k=1}^{\infty}\frac{x^{2k}}{2k(2k)!}
+/// $$
+/// and up to $x = 88$ we use Chebyshev expansions. Beyond that, both functions share the asymptotic expansion
+/// $$
+/// \mathrm{Shi}(x) \sim \mathrm{Chi}(x) \sim \frac{e^x}{2x}\sum_{k=0}^{\infty}\frac{k!}{x^k}
+/// $$
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/shichi.c
+pub(crate) fn r_shichi<T>(x: T) -> (T, T)
+where
+    T: Float + FloatSciConst + ShichiConsts,
+{
+    if x.is_nan() {
+        return (x, x);
+    }
+    if x.is_zero() {
+        return (x, T::neg_infinity());
+    }
+    let sign = x.signum();
+    let x = x.abs();
+    if x.is_infinite() {
+        return (sign * x, x);
+    }
+
+    let two = T::one() + T::one();
+    let (shi, chi) = if x <= cast::<f64, T>(8.0).unwrap() {
+        let z = x * x;
+        let mut a = T::one();
+        let mut s = T::one();
+        let mut c = T::zero();
+        let mut k = two;
+        loop {
+            a = a * z / k;
+            c = c + a / k;
+            k = k + T::one();
+            a = a / k;
+            s = s + a / k;
+            k = k + T::one();
+            if (a / s).abs() <= T::epsilon() {
+                break;
+            }
+        }
+        (s * x, c)
+    } else if x < cast::<f64, T>(18.0).unwrap() {
+        let t = cast::<f64, T>(28.8).unwrap() / x - cast::<f64, T>(2.6).unwrap();
+        let k = x.exp() / x;
+        (
+            k * eval_cheby(t, &T::SHI_MID),
+            k * eval_cheby(t, &T::CHI_MID),
+        )
+    } else if x <= cast::<f64, T>(88.0).unwrap() {
+        let t = (cast::<f64, T>(3168.0).unwrap() / x - cast::<f64, T>(106.0).unwrap())
+            / cast::<f64, T>(70.0).unwrap();
+        let k = x.exp() / x;
+        (
+            k * eval_cheby(t, &T::SHI_LARGE),
+            k * eval_cheby(t, &T::CHI_LARGE),
+        )
+    } else {
+        let mut sum = T::one();
+        let mut term = T::one();
+        let mut k = T::one();
+        while term > T::epsilon() {
+            term = term * k / x;
+            sum = sum + term;
+            k = k + T::one();
+        }
+        // Split the exponential to avoid a premature overflow
+        let half_exp = (x / two).exp();
+        let value = half_exp * (half_exp / (two * x)) * sum;
+        return (sign * value, value);
+    };
+
+    (sign * shi, T::GAMMA() + x.ln() + chi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 3]; 14] = [
+        // x, Shi(x), Chi(x)
+        [1e-08, 1e-08, -17.843465079050834],
+        [0.1, 0.100055572225057, -1.7228683861943337],
+        [0.5, 0.5069967498196672, -0.05277684495649362],
+        [1.0, 1.0572508753757286, 0.8378669409802082],
+        [2.0, 2.5015674333549756, 2.4526669226469147],
+        [5.0, 20.093211825697228, 20.09206353010595],
+        [8.0, 220.18996860023054, 220.1899309346077],
+        [8.5, 337.1319115697291, 337.13188994860803],
+        [12.0, 7479.766333436319, 7479.7663329612105],
+        [18.0, 1938952.1652987222, 1938952.1652987213],
+        [30.0, 184486604703.6371, 184486604703.6371],
+        [88.0, 9.493446879912848e+35, 9.493446879912848e+35],
+        [100.0, 1.35777637242694e+41, 1.35777637242694e+41],
+        [500.0, 1.4064106989431473e+214, 1.4064106989431473e+214],
+    ];
+
+    #[test]
+    fn test_shichi() {
+        for [x, shi, chi] in KNOWN_VALUES {
+            let (s, c) = r_shichi(x);
+            assert_almost_eq!(s, shi, PRECISION * shi);
+            assert_almost_eq!(c, chi, PRECISION * chi.abs());
+
+            let (s, c) = r_shichi(-x);
+            assert_almost_eq!(s, -shi, PRECISION * shi);
+            assert_almost_eq!(c, chi, PRECISION * chi.abs());
+        }
+    }
+
+    #[test]
+    fn test_shichi_special_values() {
+        assert_eq!(r_shichi(0.0), (0.0, f64::NEG_INFINITY));
+        assert_eq!(r_shichi(f64::INFINITY), (f64::INFINITY, f64::INFINITY));
+        assert_eq!(
+            r_shichi(f64::NEG_INFINITY),
+            (f64::NEG_INFINITY, f64::INFINITY)
+        );
+        let (s, c) = r_shichi(f64::NAN);
+        assert!(s.is_nan() && c.is_nan());
+    }
+}
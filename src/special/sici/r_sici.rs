@@ -0,0 +1,261 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::eval_cheby;
+use crate::traits::FloatSciConst;
+use num_traits::{cast, Float};
+
+/// Chebyshev coefficients for the sine and cosine integrals, computed with mpmath.
+///
+/// `SI_SMALL` and `CI_SMALL` approximate $\mathrm{Si}(x)/x$ and $(\mathrm{Ci}(x) - \gamma - \ln x)/x^2$ in $x^2$ on $\[0, 16\]$. The remaining tables approximate the auxiliary functions $xf(x)$ and $x^2g(x)$ in $1/x$ on $\[4, 8\]$ and $\[8, \infty)$.
+pub(crate) trait SiciConsts: Sized {
+    const SI_SMALL: [Self; 12];
+    const CI_SMALL: [Self; 12];
+    const F_MID: [Self; 17];
+    const G_MID: [Self; 18];
+    const F_LARGE: [Self; 25];
+    const G_LARGE: [Self; 27];
+}
+
+macro_rules! impl_siciconsts {
+    ($($T: ty)*) => ($(
+        impl SiciConsts for $T {
+            const SI_SMALL: [Self; 12] = [
+                -1.2173098836850304e-17,
+                1.6669989586824331e-15,
+                -1.9083873430871455e-13,
+                1.7944072159973677e-11,
+                -1.3562997692540251e-09,
+                8.023421237057102e-08,
+                -3.5904327241606043e-06,
+                0.00011623653904970093,
+                -0.0025631631447933976,
+                0.03544140548666592,
+                -0.2776578526973602,
+                0.684217670090758,
+            ];
+            const CI_SMALL: [Self; 12] = [
+                4.914372667584291e-19,
+                -7.325887999017895e-17,
+                9.20266004392351e-15,
+                -9.58685931177066e-13,
+                8.124187461318157e-11,
+                -5.469739948753849e-09,
+                2.840545487734663e-07,
+                -1.09557257532162e-05,
+                0.00029860052841962137,
+                -0.005381896421135691,
+                0.058935748963644466,
+                -0.18537250875454844,
+            ];
+            const F_MID: [Self; 17] = [
+                -4.943012872398273e-18,
+                -4.768880915992585e-17,
+                -3.899159573980096e-16,
+                -2.500403492164723e-15,
+                -7.78416297370466e-15,
+                1.0265489449725927e-13,
+                2.6421871207333374e-12,
+                3.76557315442645e-11,
+                4.067229629362949e-10,
+                3.2067110490690106e-09,
+                8.819599915810802e-09,
+                -3.029827842244046e-07,
+                -8.081712187981541e-06,
+                -0.00012148407180044161,
+                -0.0008044878559027043,
+                0.028233801655034252,
+                0.9456948502821582,
+            ];
+            const G_MID: [Self; 18] = [
+                -4.783978081332424e-18,
+                -4.110501836650348e-17,
+                -3.0015881405711464e-16,
+                -1.6719967480292956e-15,
+                -3.2205106111693903e-15,
+                8.709644953648439e-14,
+                1.7590931155567007e-12,
+                2.2290305948073337e-11,
+                2.2081173025917405e-10,
+                1.6731671530710441e-09,
+                6.653043754223222e-09,
+                -6.842443675659774e-08,
+                -2.109508992266979e-06,
+                -3.134340385035312e-05,
+                -0.000295296634819374,
+                -0.00028233881571446156,
+                0.0655831928024807,
+                0.8604500319812468,
+            ];
+            const F_LARGE: [Self; 25] = [
+                -2.137832632701313e-18,
+                4.581093402994428e-18,
+                6.484017087208402e-19,
+                -5.876301571688247e-17,
+                3.09467959568031e-16,
+                -9.232608964588917e-16,
+                8.753803193919171e-16,
+                8.359152910755503e-15,
+                -6.087660948694281e-14,
+                2.1818295675388572e-13,
+                -2.458189876527913e-13,
+                -2.5030786798630747e-12,
+                2.0087704058552298e-11,
+                -7.185005694643667e-11,
+                -1.0344842023296264e-11,
+                1.9229929201401537e-09,
+                -1.2619194769335632e-08,
+                2.3579283414460503e-08,
+                3.2238160432852094e-07,
+                -3.5330532482758955e-06,
+                8.685854043010514e-06,
+                0.00021166311566645217,
+                -0.003010707843495904,
+                -0.013711001596596507,
+                0.9894988661115764,
+            ];
+            const G_LARGE: [Self; 27] = [
+                -2.4803139346589046e-18,
+                1.0717860644570815e-17,
+                -2.861506747486574e-17,
+                3.216102364487034e-17,
+                1.478583178113271e-16,
+                -1.1313115920769494e-15,
+                4.192255244514052e-15,
+                -8.393071452871192e-15,
+                -8.379541520684817e-15,
+                1.5696667630257683e-13,
+                -7.441892384033629e-13,
+                1.833588927354667e-12,
+                1.2008655071171972e-12,
+                -3.703788285045214e-11,
+                1.9406532575237083e-10,
+                -4.4717061296079394e-10,
+                -1.2794644446814196e-09,
+                1.785736117851045e-08,
+                -8.033144838438901e-08,
+                1.996845554594561e-08,
+                2.4181153863323553e-06,
+                -1.7168296081680412e-05,
+                1.2128761140268576e-05,
+                0.0008848387859351088,
+                -0.007723958513219698,
+                -0.038156669549908616,
+                0.9704205313366221,
+            ];
+        }
+    )*)
+}
+
+impl_siciconsts! {f32 f64}
+
+/// Sine and cosine integrals implementation.
+///
+/// Follows the structure of [cephes]. For $x \leq 4$, we use
+/// $$
+/// \mathrm{Si}(x) = xS(x^2), \quad \mathrm{Ci}(x) = \gamma + \ln x + x^2C(x^2)
+/// $$
+/// where $S$ and $C$ are Chebyshev expansions. Otherwise, we use the auxiliary functions $f$ and $g$ ([DLMF 6.2.17](https://dlmf.nist.gov/6.2#E17))
+/// $$
+/// \mathrm{Si}(x) = \frac{\pi}{2} - f(x)\cos x - g(x)\sin x, \quad \mathrm{Ci}(x) = f(x)\sin x - g(x)\cos x
+/// $$
+/// which are approximated by Chebyshev expansions in $1/x$.
+///
+/// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/sici.c
+pub(crate) fn r_sici<T>(x: T) -> (T, T)
+where
+    T: Float + FloatSciConst + SiciConsts,
+{
+    if x.is_nan() {
+        return (x, x);
+    }
+    if x.is_zero() {
+        return (x, T::neg_infinity());
+    }
+    let sign = x.signum();
+    let x = x.abs();
+    if x.is_infinite() {
+        return (sign * T::FRAC_PI_2(), T::zero());
+    }
+
+    let four = cast::<f64, T>(4.0).unwrap();
+    let eight = cast::<f64, T>(8.0).unwrap();
+    let sixteen = cast::<f64, T>(16.0).unwrap();
+
+    if x <= four {
+        let z = x * x;
+        let t = z / eight - T::one();
+        let si = x * eval_cheby(t, &T::SI_SMALL);
+        let ci = T::GAMMA() + x.ln() + z * eval_cheby(t, &T::CI_SMALL);
+        return (sign * si, ci);
+    }
+
+    let (f, g) = if x <= eight {
+        let t = cast::<f64, T>(3.0).unwrap() - sixteen / x;
+        (eval_cheby(t, &T::F_MID), eval_cheby(t, &T::G_MID))
+    } else {
+        let t = sixteen / x - T::one();
+        (eval_cheby(t, &T::F_LARGE), eval_cheby(t, &T::G_LARGE))
+    };
+    let f = f / x;
+    let g = g / x / x;
+
+    let (sin, cos) = x.sin_cos();
+    let si = T::FRAC_PI_2() - f * cos - g * sin;
+    let ci = f * sin - g * cos;
+    (sign * si, ci)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    // Values from mpmath (v 1.3.0)
+    const KNOWN_VALUES: [[f64; 3]; 15] = [
+        // x, Si(x), Ci(x)
+        [1e-08, 1e-08, -17.843465079050834],
+        [0.1, 0.09994446110827696, -1.7278683866572966],
+        [0.5, 0.4931074180430667, -0.1777840788066129],
+        [1.0, 0.946083070367183, 0.33740392290096816],
+        [2.0, 1.6054129768026948, 0.422980828774865],
+        [3.5, 1.833125398665997, -0.03212854851248111],
+        [4.0, 1.7582031389490531, -0.1409816978869304],
+        [5.0, 1.549931244944674, -0.19002974965664388],
+        [7.5, 1.5106815309433859, 0.11563320323793427],
+        [8.0, 1.5741868217069421, 0.12243388253200956],
+        [10.0, 1.6583475942188741, -0.04545643300445537],
+        [25.0, 1.5314825509999612, -0.006848597179702591],
+        [100.0, 1.5622254668890563, -0.005148825142610492],
+        [1000.0, 1.5702331219687713, 0.0008263155110906822],
+        [100000000.0, 1.5707963304287473, 9.316390307435767e-09],
+    ];
+
+    #[test]
+    fn test_sici() {
+        for [x, si, ci] in KNOWN_VALUES {
+            let (s, c) = r_sici(x);
+            assert_almost_eq!(s, si, PRECISION * si);
+            assert_almost_eq!(c, ci, PRECISION * ci.abs());
+
+            let (s, c) = r_sici(-x);
+            assert_almost_eq!(s, -si, PRECISION * si);
+            assert_almost_eq!(c, ci, PRECISION * ci.abs());
+        }
+    }
+
+    #[test]
+    fn test_sici_special_values() {
+        assert_eq!(r_sici(0.0), (0.0, f64::NEG_INFINITY));
+        assert_eq!(r_sici(f64::INFINITY), (std::f64::consts::FRAC_PI_2, 0.0));
+        assert_eq!(
+            r_sici(f64::NEG_INFINITY),
+            (-std::f64::consts::FRAC_PI_2, 0.0)
+        );
+        let (s, c) = r_sici(f64::NAN);
+        assert!(s.is_nan() && c.is_nan());
+    }
+}
@@ -0,0 +1,97 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::sici::real_sici_impl::*;
+
+/// Sine and cosine integrals, and their hyperbolic counterparts, for real-valued arguments.
+pub trait SiCi {
+    /// Sine and cosine integrals.
+    /// $$
+    /// \mathrm{Si}(x) = \int_0^x \frac{\sin t}{t}dt, \quad \mathrm{Ci}(x) = \gamma + \ln x + \int_0^x \frac{\cos t - 1}{t}dt
+    /// $$
+    /// where $\gamma$ is the [Euler-Mascheroni constant]. Returns the tuple $(\mathrm{Si}(x), \mathrm{Ci}(x))$. For $x < 0$, $\mathrm{Si}(x) = -\mathrm{Si}(-x)$ and we return the real part of $\mathrm{Ci}(x)$, which is $\mathrm{Ci}(-x)$. See the [DLMF] for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::SiCi;
+    /// let (si, ci) = 1.0_f64.sici();
+    /// assert!((si - 0.946083070367183).abs() < 1e-15);
+    /// assert!((ci - 0.33740392290096816).abs() < 1e-15);
+    /// let (si, ci) = f64::INFINITY.sici();
+    /// assert_eq!(si, std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(ci, 0.0);
+    /// ```
+    /// # Notes
+    /// The implementation follows the structure of [cephes]. For $x \leq 4$, we use
+    /// $$
+    /// \mathrm{Si}(x) = xS(x^2), \quad \mathrm{Ci}(x) = \gamma + \ln x + x^2C(x^2)
+    /// $$
+    /// where $S$ and $C$ are Chebyshev expansions. For larger arguments, we use the auxiliary functions
+    /// $$
+    /// \mathrm{Si}(x) = \frac{\pi}{2} - f(x)\cos x - g(x)\sin x, \quad \mathrm{Ci}(x) = f(x)\sin x - g(x)\cos x
+    /// $$
+    /// where $xf(x)$ and $x^2g(x)$ are approximated by Chebyshev expansions in $1/x$ on $\[4, 8\]$ and $\[8, \infty)$. The precision of $\mathrm{Ci}(x)$ is absolute close to its zeros.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Euler-Mascheroni constant]: crate::constants::f64::GAMMA
+    /// [DLMF]: https://dlmf.nist.gov/6.2
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/sici.c
+    fn sici(self) -> (Self, Self)
+    where
+        Self: Sized;
+
+    /// Hyperbolic sine and cosine integrals.
+    /// $$
+    /// \mathrm{Shi}(x) = \int_0^x \frac{\sinh t}{t}dt, \quad \mathrm{Chi}(x) = \gamma + \ln x + \int_0^x \frac{\cosh t - 1}{t}dt
+    /// $$
+    /// where $\gamma$ is the [Euler-Mascheroni constant]. Returns the tuple $(\mathrm{Shi}(x), \mathrm{Chi}(x))$. For $x < 0$, $\mathrm{Shi}(x) = -\mathrm{Shi}(-x)$ and we return the real part of $\mathrm{Chi}(x)$, which is $\mathrm{Chi}(-x)$. See the [DLMF] for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::SiCi;
+    /// let (shi, chi) = 1.0_f64.shichi();
+    /// assert!((shi - 1.0572508753757286).abs() < 1e-15);
+    /// assert!((chi - 0.8378669409802082).abs() < 1e-15);
+    /// let (shi, chi) = (-2.0_f64).shichi();
+    /// assert!((shi + 2.5015674333549756).abs() < 1e-15);
+    /// assert!((chi - 2.4526669226469147).abs() < 1e-15);
+    /// ```
+    /// # Notes
+    /// Taken from [cephes]. For $x \leq 8$, we sum the power series
+    /// $$
+    /// \mathrm{Shi}(x) = \sum_{k=0}^{\infty}\frac{x^{2k+1}}{(2k+1)(2k+1)!}, \quad \mathrm{Chi}(x) = \gamma + \ln x + \sum_{k=1}^{\infty}\frac{x^{2k}}{2k(2k)!}
+    /// $$
+    /// For $8 < x \leq 88$, $xe^{-x}\mathrm{Shi}(x)$ and $xe^{-x}(\mathrm{Chi}(x) - \gamma - \ln x)$ are approximated by Chebyshev expansions, and beyond that we use the asymptotic expansion
+    /// $$
+    /// \mathrm{Shi}(x) \sim \mathrm{Chi}(x) \sim \frac{e^x}{2x}\sum_{k=0}^{\infty}\frac{k!}{x^k}
+    /// $$
+    /// The precision of $\mathrm{Chi}(x)$ is absolute close to its zero.
+    ///
+    /// [comment]: <> (Reference hyperlinks)
+    /// [Euler-Mascheroni constant]: crate::constants::f64::GAMMA
+    /// [DLMF]: https://dlmf.nist.gov/6.2
+    /// [cephes]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/shichi.c
+    fn shichi(self) -> (Self, Self)
+    where
+        Self: Sized;
+}
+
+macro_rules! float_sici_impl {
+    ($($T: ty)*) => ($(
+        impl SiCi for $T {
+            #[inline(always)]
+            fn sici(self) -> (Self, Self) {
+                r_sici(self)
+            }
+
+            #[inline(always)]
+            fn shichi(self) -> (Self, Self) {
+                r_shichi(self)
+            }
+        }
+    )*)
+}
+
+float_sici_impl! {f32 f64}
@@ -95,15 +95,56 @@ where
     }
 }
 
+/// Computes $x 2^n$.
+///
+/// The power of two is applied in exact steps of at most $2^{64}$, such that the result only overflows or underflows if $x2^n$ does.
 pub(crate) fn ldexp<T>(num: T, exp: i64) -> T
 where
     T: Float,
 {
-    if exp >= 0 {
-        num * T::from(2u64.pow(exp as u32)).unwrap()
-    } else {
-        num / T::from(2u64.pow(-exp as u32)).unwrap()
+    const STEP: i64 = 64;
+    // Beyond this, the result overflows or underflows for any finite, nonzero x
+    const MAX_EXP: i64 = 4096;
+
+    let two = T::one() + T::one();
+    let step = two.powi(STEP as i32);
+    let mut exp = exp.clamp(-MAX_EXP, MAX_EXP);
+    let mut result = num;
+    while exp > STEP {
+        result = result * step;
+        exp -= STEP;
+    }
+    while exp < -STEP {
+        result = result / step;
+        exp += STEP;
+    }
+    result * two.powi(exp as i32)
+}
+
+/// Computes $\ln(1+x) - x$.
+///
+/// For small $|x|$ we sum the Taylor series directly to avoid the cancellation.
+pub(crate) fn log1pmx<T>(x: T) -> T
+where
+    T: Float,
+{
+    const MAX_TERMS: usize = 500;
+
+    if x.abs() >= T::from(0.5).unwrap() {
+        return x.ln_1p() - x;
+    }
+
+    let mut xfac = x;
+    let mut result = T::zero();
+    for n in 2..MAX_TERMS {
+        xfac = xfac * -x;
+        let term = xfac / T::from(n).unwrap();
+        result = result + term;
+        if term.abs() < T::epsilon() * result.abs() {
+            break;
+        }
     }
+    result
 }
 
 #[cfg(test)]
@@ -112,6 +153,21 @@ mod tests {
 
     const PRECISION: f64 = 1E-14;
 
+    #[test]
+    fn test_ldexp() {
+        assert_eq!(ldexp(0.75, 3), 6.0);
+        assert_eq!(ldexp(0.75_f32, -2), 0.1875);
+        assert_eq!(ldexp(1.0, -1074), 5e-324);
+        assert_eq!(ldexp(1.0, 1023), f64::MAX / (2.0 - f64::EPSILON));
+        assert_eq!(ldexp(1.5, -100), 1.5 * 2.0_f64.powi(-100));
+        assert_eq!(
+            ldexp(f64::MAX, -2000),
+            f64::MAX * 2.0_f64.powi(-1000) * 2.0_f64.powi(-1000)
+        );
+        assert_eq!(ldexp(1.0, 5000), f64::INFINITY);
+        assert_eq!(ldexp(1.0, -5000), 0.0);
+    }
+
     #[test]
     fn test_eval_poly() {
         assert_eq!(eval_poly(1.0, &[1.0, 1.0]), 2.0);
@@ -0,0 +1,314 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::zeta::r_zeta::{r_zeta, r_zetac, RealZetaConsts};
+use crate::special::zeta::zeta_util::EULER_MACLAURIN_TERMS;
+use crate::special::{Bernoulli, Gamma};
+use crate::traits::FloatSciConst;
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{cast, Float, FromPrimitive, Zero};
+
+pub(crate) trait ComplexZetaConsts: Sized {
+    /// Minimum number of terms summed directly before the Euler-Maclaurin remainder
+    const MIN_TERMS: Self;
+    /// Largest imaginary part for which the reflection formula is evaluated directly
+    const MAX_DIRECT_REFLECT: Self;
+}
+
+macro_rules! impl_complexzetaconsts {
+    ($($T: ty)*) => ($(
+        impl ComplexZetaConsts for $T {
+            const MIN_TERMS: Self = 10.0;
+            const MAX_DIRECT_REFLECT: Self = 100.0;
+        }
+)*)
+}
+
+impl_complexzetaconsts! {f32 f64}
+
+/// Euler-Maclaurin summation of the Riemann zeta function, where the sum starts at $k=$ `start`.
+/// $$
+/// \sum^{\infty}\_{k=\text{start}} \frac{1}{k^s} \approx \sum^{N-1}\_{k=\text{start}} \frac{1}{k^s} + \frac{N^{1-s}}{s-1} + \frac{N^{-s}}{2} + \sum^{M}\_{j=1} \frac{B_{2j}}{(2j)!} \frac{(s)\_{2j-1}}{N^{s+2j-1}}
+/// $$
+/// The number of directly summed terms $N$ grows with $|s|$ such that the remainder converges quickly. For large $\Re(s)$, the summation stops once the terms are negligible.
+fn zeta_euler_maclaurin<T>(s: Complex<T>, start: usize) -> Complex<T>
+where
+    T: Float + FloatSciConst + FromPrimitive + ComplexZetaConsts,
+{
+    let one = T::one();
+    let n = cast::<T, usize>(s.norm().max(T::MIN_TERMS).ceil()).unwrap();
+
+    let mut sum = Complex::<T>::zero();
+    for k in start..n {
+        let k = T::from_usize(k).unwrap();
+        let term = (-s * k.ln()).exp();
+        // For large Re(s), the remainder is bounded by k^{-s}(1 + k/(Re(s) - 1)), which includes terms that underflow
+        if s.re > one && term.norm() * (k / (s.re - one) + one) <= T::epsilon() * sum.norm() {
+            return sum;
+        }
+        sum = sum + term;
+    }
+
+    let n = T::from_usize(n).unwrap();
+    let rn = n.recip();
+    let n_s = (-s * n.ln()).exp();
+    sum = sum + n_s * n / (s - one) + n_s * T::from_f64(0.5).unwrap();
+
+    let b2n = EULER_MACLAURIN_TERMS.bernoulli_b2n::<T>();
+    let mut poch = s;
+    let mut factorial = one + one;
+    let mut n_pow = n_s * rn;
+    for (j, &b) in b2n.iter().enumerate().skip(1) {
+        let term = n_pow * poch * (b / factorial);
+        sum = sum + term;
+        if term.norm() < T::epsilon() * sum.norm() {
+            break;
+        }
+        let two_j = T::from_usize(2 * j).unwrap();
+        poch = poch * (s + two_j - one) * (s + two_j);
+        factorial = factorial * (two_j + one) * (two_j + one + one);
+        n_pow = n_pow * rn * rn;
+    }
+    sum
+}
+
+/// Stable evaluation of $\ln\sin(z)$ for large $|\Im(z)|$, up to multiples of $2\pi i$.
+fn ln_sin<T>(z: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatSciConst,
+{
+    if z.im < T::zero() {
+        return ln_sin(z.conj()).conj();
+    }
+    // sin(z) = e^{-iz} (1 - e^{2iz}) i/2
+    let i = Complex::<T>::i();
+    let half = T::from(0.5).unwrap();
+    -i * z
+        + (Complex::new(T::one(), T::zero()) - (i * z * (T::one() + T::one())).exp()).ln()
+        + Complex::new(half.ln(), T::FRAC_PI_2())
+}
+
+/// Reflection formula for the Riemann zeta function when $\Re(s) < 0$.
+/// $$
+/// \zeta(s) = 2^s\pi^{s-1}\sin\left(\frac{\pi s}{2}\right)\Gamma(1-s)\zeta(1-s)
+/// $$
+fn zeta_reflection<T>(s: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatSciConst + FromPrimitive + RealZetaConsts + ComplexZetaConsts + Gamma,
+    Complex<T>: Gamma,
+{
+    let one = Complex::new(T::one(), T::zero());
+    let two = T::one() + T::one();
+    let one_m_s = one - s;
+    let zeta = complex_zeta(one_m_s);
+
+    // Reduce the real part first since sin(pi s/2) is periodic
+    let reduced = Complex::new((s.re / two) % two, s.im / two) * T::PI();
+    if s.im.abs() < T::MAX_DIRECT_REFLECT && one_m_s.re < T::MAX_GAMMA {
+        let prefactor = (two * T::PI()).powc(s - one) * two * reduced.sin() * one_m_s.gamma();
+        return prefactor * zeta;
+    }
+    let ln_prefactor =
+        (s - one) * (two * T::PI()).ln() + two.ln() + ln_sin(reduced) + one_m_s.lngamma();
+    ln_prefactor.exp() * zeta
+}
+
+fn complex_zeta<T>(s: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatSciConst + FromPrimitive + RealZetaConsts + ComplexZetaConsts + Gamma,
+    Complex<T>: Gamma,
+{
+    if s.im.is_zero() {
+        return Complex::new(r_zeta(s.re), T::zero());
+    }
+    if s.is_nan() || s.is_infinite() {
+        return Complex::new(T::nan(), T::nan());
+    }
+    if s.re < T::zero() {
+        return zeta_reflection(s);
+    }
+    zeta_euler_maclaurin(s, 1)
+}
+
+/// Complex implementation of the Riemann zeta function.
+/// $$
+/// \zeta(s) = \sum^{\infty}\_{k=1} \frac{1}{k^s}
+/// $$
+/// For $\Re(s) \geq 0$, we use the Euler-Maclaurin summation formula. Otherwise, the reflection formula is used.
+pub(crate) fn c_zeta<T>(s: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatSciConst + FromPrimitive + RealZetaConsts + ComplexZetaConsts + Gamma,
+    Complex<T>: Gamma,
+{
+    complex_zeta(s)
+}
+
+/// Complex implementation of the Riemann zeta function minus one.
+pub(crate) fn c_zetac<T>(s: Complex<T>) -> Complex<T>
+where
+    T: Float + FloatSciConst + FromPrimitive + RealZetaConsts + ComplexZetaConsts + Gamma,
+    Complex<T>: Gamma,
+{
+    if s.im.is_zero() {
+        return Complex::new(r_zetac(s.re), T::zero());
+    }
+    if s.re >= T::zero() && !s.is_nan() && s.is_finite() {
+        // Avoid the cancellation for large Re(s)
+        return zeta_euler_maclaurin(s, 2);
+    }
+    complex_zeta(s) - T::one()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex64;
+
+    const PRECISION: f64 = 1.0e-14;
+
+    #[test]
+    fn test_c_zeta() {
+        const COMPLEX_KNOWN_VALUES: [[Complex64; 3]; 9] = [
+            // Values from mpmath (v 1.3.0), [s, zeta(s), zetac(s)]
+            [
+                Complex { re: 2.0, im: 1.0 },
+                Complex {
+                    re: 1.1503557032549026717,
+                    im: -0.43753086591960788112,
+                },
+                Complex {
+                    re: 0.15035570325490267174,
+                    im: -0.43753086591960788112,
+                },
+            ],
+            [
+                Complex { re: 0.5, im: 100.0 },
+                Complex {
+                    re: 2.6926198856813240905,
+                    im: -0.020386029602598161771,
+                },
+                Complex {
+                    re: 1.6926198856813240905,
+                    im: -0.020386029602598161771,
+                },
+            ],
+            [
+                Complex { re: -3.0, im: 4.0 },
+                Complex {
+                    re: -0.033730573388277570676,
+                    im: 0.27744992515570937453,
+                },
+                Complex {
+                    re: -1.0337305733882775707,
+                    im: 0.27744992515570937453,
+                },
+            ],
+            [
+                Complex { re: -20.5, im: 3.0 },
+                Complex {
+                    re: 2011.7042743440201249,
+                    im: -6579.7565382521518323,
+                },
+                Complex {
+                    re: 2010.7042743440201249,
+                    im: -6579.7565382521518323,
+                },
+            ],
+            [
+                Complex { re: 3.0, im: -50.0 },
+                Complex {
+                    re: 0.88575317457178229446,
+                    im: -0.048491476392560985596,
+                },
+                Complex {
+                    re: -0.11424682542821770554,
+                    im: -0.048491476392560985596,
+                },
+            ],
+            [
+                Complex { re: 0.1, im: -0.3 },
+                Complex {
+                    re: -0.49161884869848965773,
+                    im: 0.30919261314723957693,
+                },
+                Complex {
+                    re: -1.4916188486984896577,
+                    im: 0.30919261314723957693,
+                },
+            ],
+            [
+                Complex {
+                    re: -5.0,
+                    im: 150.0,
+                },
+                Complex {
+                    re: -4619221.3579364014938,
+                    im: 37095169.398781360813,
+                },
+                Complex {
+                    re: -4619222.3579364014938,
+                    im: 37095169.398781360813,
+                },
+            ],
+            [
+                Complex { re: 30.0, im: 2.0 },
+                Complex {
+                    re: 1.0000000001708547738,
+                    im: -9.1551986116397378574e-10,
+                },
+                Complex {
+                    re: 1.7085477383649804078e-10,
+                    im: -9.1551986116397378574e-10,
+                },
+            ],
+            [
+                Complex {
+                    re: 1.5,
+                    im: 1000.0,
+                },
+                Complex {
+                    re: 0.95554458130341148975,
+                    im: -0.09613241765159551067,
+                },
+                Complex {
+                    re: -0.044455418696588510249,
+                    im: -0.09613241765159551067,
+                },
+            ],
+        ];
+
+        for values in COMPLEX_KNOWN_VALUES {
+            // Rounding errors in the phases grow with |s|
+            let precision = PRECISION * values[0].norm().max(1.0);
+            assert_almost_eq!(
+                c_zeta(values[0]) / values[1],
+                Complex::new(1.0, 0.0),
+                precision
+            );
+            assert_almost_eq!(
+                c_zetac(values[0]) / values[2],
+                Complex::new(1.0, 0.0),
+                precision
+            );
+        }
+
+        // First non-trivial zero
+        assert!(c_zeta(Complex64::new(0.5, 14.134725141734693)).norm() < 1e-14);
+        assert_eq!(c_zeta(Complex64::new(-2.0, 0.0)), Complex::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_c_zeta_large_re() {
+        // Values from mpmath (v 1.3.0)
+        let s = Complex64::new(60.0, -40.0);
+        let zetac = Complex64::new(-7.4015047929094255081e-19, 4.5220974391970507223e-19);
+        assert_almost_eq!(c_zetac(s) / zetac, Complex::new(1.0, 0.0), 1e-13);
+
+        // The terms underflow, which must not prevent the summation from terminating
+        let s = Complex64::new(1e10, 3.0);
+        assert_eq!(c_zeta(s), Complex::new(1.0, 0.0));
+        assert_eq!(c_zetac(s), Complex::new(0.0, 0.0));
+    }
+}
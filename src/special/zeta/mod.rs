@@ -0,0 +1,21 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+//! Riemann and Hurwitz zeta functions.
+
+mod zeta_trait;
+pub(crate) mod zeta_util;
+
+pub use zeta_trait::*;
+
+mod c_zeta;
+mod r_zeta;
+
+mod real_zeta_impl {
+    pub(crate) use super::r_zeta::*;
+}
+mod complex_zeta_impl {
+    pub(crate) use super::c_zeta::*;
+}
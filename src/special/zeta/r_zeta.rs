@@ -0,0 +1,268 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::zeta::zeta_util::hurwitz_zeta_em;
+use crate::special::Gamma;
+use crate::traits::FloatSciConst;
+use num_traits::{Float, FloatConst, FromPrimitive};
+
+pub(crate) trait RealZetaConsts: Sized {
+    /// Largest argument for which the Gamma function does not overflow
+    const MAX_GAMMA: Self;
+    /// Argument above which $\zeta(s) - 1 = 2^{-s}$ to machine precision
+    const MIN_USE_POWER: Self;
+}
+
+macro_rules! impl_realzetaconsts {
+    ($($T: ty)*) => ($(
+        impl RealZetaConsts for $T {
+            const MAX_GAMMA: Self = 171.0;
+            const MIN_USE_POWER: Self = 128.0;
+        }
+)*)
+}
+
+impl_realzetaconsts! {f32 f64}
+
+/// Computes the prefactor of the reflection formula for the Riemann zeta function
+/// $$
+/// \zeta(s) = 2^s\pi^{s-1}\sin\left(\frac{\pi s}{2}\right)\Gamma(1-s)\zeta(1-s)
+/// $$
+/// for $s < 0$. When $\Gamma(1-s)$ overflows, the product is computed through logarithms.
+fn reflection_prefactor<T>(s: T) -> T
+where
+    T: Float + FloatSciConst + Gamma + RealZetaConsts,
+{
+    let two = T::one() + T::one();
+    // Reduce the argument first so that the trivial zeros are exact
+    let sin = (T::PI() * ((s / two) % two)).sin();
+    let one_m_s = T::one() - s;
+
+    if one_m_s < T::MAX_GAMMA {
+        return two * T::TAU().powf(s - T::one()) * sin * one_m_s.gamma();
+    }
+    two * sin * ((s - T::one()) * T::TAU().ln() + one_m_s.lgamma()).exp()
+}
+
+/// Riemann zeta function minus one for real-valued arguments.
+///
+/// For $s > 1$, we compute $\zeta(s) - 1 = \zeta(s, 2)$ directly to avoid cancellation for large $s$. Above $s = 128$, the remaining terms $3^{-s} + \ldots$ are negligible and $2^{-s}$ is returned.
+pub(crate) fn r_zetac<T>(s: T) -> T
+where
+    T: Float + FloatSciConst + FromPrimitive + Gamma + RealZetaConsts,
+{
+    if s.is_nan() || s == T::neg_infinity() {
+        return T::nan();
+    }
+    if s == T::infinity() {
+        return T::zero();
+    }
+    if s == T::one() {
+        return T::infinity();
+    }
+    let two = T::one() + T::one();
+    if s > T::MIN_USE_POWER {
+        return two.powf(-s);
+    }
+    if s > T::one() {
+        return hurwitz_zeta_em(s, two);
+    }
+    r_zeta(s) - T::one()
+}
+
+/// Riemann zeta function for real-valued arguments.
+/// $$
+/// \zeta(s) = \sum^{\infty}\_{k=1} \frac{1}{k^s}
+/// $$
+/// For $s < 0$, the reflection formula is used.
+pub(crate) fn r_zeta<T>(s: T) -> T
+where
+    T: Float + FloatSciConst + FromPrimitive + Gamma + RealZetaConsts,
+{
+    if s.is_nan() || s == T::neg_infinity() {
+        return T::nan();
+    }
+    if s == T::infinity() {
+        return T::one();
+    }
+    if s == T::one() {
+        return T::infinity();
+    }
+    if s > T::one() {
+        return T::one() + r_zetac(s);
+    }
+    if s >= T::zero() {
+        return hurwitz_zeta_em(s, T::one());
+    }
+
+    let two = T::one() + T::one();
+    if s % two == T::zero() {
+        // Trivial zeros
+        return T::zero();
+    }
+    reflection_prefactor(s) * r_zeta(T::one() - s)
+}
+
+/// Hurwitz zeta function for real-valued arguments.
+/// $$
+/// \zeta(s, a) = \sum^{\infty}\_{k=0} \frac{1}{(a+k)^s}
+/// $$
+/// Like the [cephes implementation], we return `NaN` for $s < 1$, as well as when $a < 0$ and $s$ is not an integer.
+///
+/// [cephes implementation]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/zeta.c
+pub(crate) fn r_hurwitz_zeta<T>(s: T, a: T) -> T
+where
+    T: Float + FloatConst + FromPrimitive,
+{
+    if s.is_nan() || a.is_nan() {
+        return T::nan();
+    }
+    if s == T::one() {
+        return T::infinity();
+    }
+    if s < T::one() {
+        return T::nan();
+    }
+    if a <= T::zero() {
+        if a == a.floor() {
+            return T::infinity();
+        }
+        if s != s.floor() {
+            // a^{-s} is not real-valued
+            return T::nan();
+        }
+    }
+    if a == T::infinity() {
+        return T::zero();
+    }
+    if s == T::infinity() {
+        return if a < T::one() {
+            T::infinity()
+        } else if a == T::one() {
+            T::one()
+        } else {
+            T::zero()
+        };
+    }
+
+    hurwitz_zeta_em(s, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRECISION: f64 = 1e-14;
+
+    #[test]
+    fn test_r_zeta() {
+        const KNOWN_VALUES: [[f64; 3]; 18] = [
+            // Values from mpmath (v 1.3.0), [s, zeta(s), zetac(s)]
+            [2.0, 1.6449340668482264365, 0.64493406684822643647],
+            [3.0, 1.2020569031595942854, 0.2020569031595942854],
+            [1.5, 2.6123753486854883433, 1.6123753486854883433],
+            [1.0001, 10000.57722294753897, 9999.5772229475389703],
+            [0.5, -1.4603545088095868129, -2.4603545088095868129],
+            [0.0, -0.5, -1.5],
+            [0.999, -999.42285715578790183, -1000.4228571557879018],
+            [0.25, -0.81327840526189165652, -1.8132784052618916565],
+            [-0.5, -0.20788622497735456602, -1.207886224977354566],
+            [-1.0, -0.083333333333333333333, -1.0833333333333333333],
+            [-3.0, 0.0083333333333333333333, -0.99166666666666666667],
+            [-7.5, 0.0032690395726002200217, -0.99673096042739977998],
+            [-15.3, 0.51934802611985639211, -0.48065197388014360789],
+            [
+                -150.5,
+                1.1811017459674170283e+143,
+                1.1811017459674170283e+143,
+            ],
+            [
+                -200.5,
+                -2.3200006633528991249e+215,
+                -2.3200006633528991249e+215,
+            ],
+            [10.0, 1.0009945751278180853, 0.00099457512781808533715],
+            [50.0, 1.0000000000000008882, 8.8817842109308161928e-16],
+            [100.0, 1.0, 7.8886090522101180541e-31],
+        ];
+
+        for values in KNOWN_VALUES {
+            assert_almost_eq!(r_zeta(values[0]) / values[1], 1.0, 1e-13);
+            assert_almost_eq!(r_zetac(values[0]) / values[2], 1.0, 1e-13);
+            if values[0].abs() < 30.0 {
+                // The zeta function is ill-conditioned near the pole, so compare with the f64 result
+                let expected = r_zeta(values[0] as f32 as f64);
+                assert_almost_eq!(r_zeta(values[0] as f32) / expected as f32, 1.0, 1e-5);
+            }
+        }
+
+        assert_eq!(r_zeta(-30.0), 0.0);
+        assert_eq!(r_zetac(-30.0), -1.0);
+        assert_eq!(r_zeta(1.0), f64::INFINITY);
+        assert_eq!(r_zeta(f64::INFINITY), 1.0);
+        assert_eq!(r_zetac(f64::INFINITY), 0.0);
+        assert!(r_zeta(f64::NEG_INFINITY).is_nan());
+        assert!(r_zeta(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_r_zeta_large_s() {
+        // Values from mpmath (v 1.3.0)
+        assert_almost_eq!(r_zetac(200.0) / 6.2230152778611417071e-61, 1.0, PRECISION);
+        assert_almost_eq!(r_zetac(1000.0) / 9.3326361850321887899e-302, 1.0, PRECISION);
+        assert_almost_eq!(
+            r_hurwitz_zeta(300.0, 2.5) / 4.1495155688809929585e-120,
+            1.0,
+            PRECISION
+        );
+        assert_almost_eq!(
+            r_hurwitz_zeta(500.0, 0.9) / 7.5638913231040064980e+22,
+            1.0,
+            PRECISION
+        );
+
+        // The terms underflow, which must not prevent the summation from terminating
+        assert_eq!(r_zeta(1e15), 1.0);
+        assert_eq!(r_zetac(1e15), 0.0);
+        assert_eq!(r_zeta(1e20), 1.0);
+        assert_eq!(r_zeta(1e10_f32), 1.0);
+        assert_eq!(r_hurwitz_zeta(1e12, 2.5), 0.0);
+        assert_eq!(r_hurwitz_zeta(1e12, 1.0), 1.0);
+        assert_eq!(r_hurwitz_zeta(1e12, 0.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_r_hurwitz_zeta() {
+        const KNOWN_VALUES: [[f64; 3]; 8] = [
+            // Values from mpmath (v 1.3.0)
+            [2.0, 0.5, 4.9348022005446793094],
+            [3.0, 2.5, 0.1181020258208637015],
+            [1.5, 10.0, 0.64866163194157042215],
+            [4.0, 0.01, 100000001.04184363715],
+            [3.0, -2.5, 0.054102025820863701502],
+            [10.0, 3.0, 0.000018012627818085337146],
+            [1.1, 1.0, 10.584448464950800951],
+            [7.0, 100.0, 1.7172499300183261871e-13],
+        ];
+
+        for values in KNOWN_VALUES {
+            assert_almost_eq!(
+                r_hurwitz_zeta(values[0], values[1]) / values[2],
+                1.0,
+                PRECISION
+            );
+            assert_almost_eq!(
+                r_hurwitz_zeta(values[0] as f32, values[1] as f32) / values[2] as f32,
+                1.0,
+                1e-5
+            );
+        }
+
+        assert_eq!(r_hurwitz_zeta(1.0, 2.0), f64::INFINITY);
+        assert_eq!(r_hurwitz_zeta(2.0, -3.0), f64::INFINITY);
+        assert!(r_hurwitz_zeta(0.5, 2.0).is_nan());
+        assert!(r_hurwitz_zeta(2.5, -1.5).is_nan());
+    }
+}
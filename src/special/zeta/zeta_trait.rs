@@ -0,0 +1,137 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+use crate::special::zeta::complex_zeta_impl::*;
+use crate::special::zeta::real_zeta_impl::*;
+
+use num_complex::Complex;
+
+/// Riemann zeta function for both real and complex-valued arguments.
+pub trait Zeta {
+    /// The Riemann zeta function.
+    /// $$
+    /// \zeta(s) = \sum^{\infty}\_{k=1} \frac{1}{k^s}
+    /// $$
+    /// where $\Re(s) > 1$. It is defined for the rest of the complex plane, except for the pole at $s=1$, through analytic continuation. See the [dlmf] or [wiki] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Zeta;
+    /// let pi = std::f64::consts::PI;
+    /// assert!((2.0_f64.zeta() - pi * pi / 6.0).abs() < 1e-15);
+    /// assert_eq!(0.0_f64.zeta(), -0.5);
+    /// assert_eq!((-2.0_f64).zeta(), 0.0); // Trivial zero
+    /// assert_eq!(1.0_f64.zeta(), f64::INFINITY);
+    /// ```
+    /// For complex-valued arguments:
+    /// ```
+    /// use sci_rs::special::Zeta;
+    /// use num_complex::Complex64;
+    /// let s = Complex64{re: 0.5, im: 14.134725141734693}; // First non-trivial zero
+    /// assert!(s.zeta().norm() < 1e-13);
+    /// ```
+    ///
+    /// # Notes
+    /// For real-valued $s > 1$, we compute $\zeta(s) = 1 + \zeta(s,2)$ where $\zeta(s,a)$ is the [Hurwitz zeta] function, which is evaluated following the [cephes implementation]. That is, the first terms are summed directly and the remainder is approximated with the Euler-Maclaurin summation formula
+    /// $$
+    /// \sum^{\infty}\_{k=N} \frac{1}{k^s} \approx \frac{N^{1-s}}{s-1} + \frac{N^{-s}}{2} + \sum^{M}\_{j=1} \frac{B_{2j}}{(2j)!} \frac{(s)\_{2j-1}}{N^{s+2j-1}}
+    /// $$
+    /// where $B_{2j}$ are the [Bernoulli] numbers and $(s)\_n$ is the rising factorial. For complex-valued arguments, the same formula is used with $N \geq |s|$. When $\Re(s) < 0$, we use the reflection formula
+    /// $$
+    /// \zeta(s) = 2^s\pi^{s-1}\sin\left(\frac{\pi s}{2}\right)\Gamma(1-s)\zeta(1-s)
+    /// $$
+    /// Since roughly $|s|$ terms are summed, each with a phase of $\Im(s)\ln k$, the relative accuracy degrades proportionally to $|\Im(s)|$.
+    ///
+    /// [dlmf]: https://dlmf.nist.gov/25.2
+    /// [wiki]: https://en.wikipedia.org/wiki/Riemann_zeta_function
+    /// [Hurwitz zeta]: crate::special::RealZeta::hurwitz_zeta()
+    /// [Bernoulli]: crate::special::Bernoulli::bernoulli_b2n()
+    /// [cephes implementation]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/zeta.c
+    fn zeta(self) -> Self;
+
+    /// The Riemann zeta function minus one.
+    /// $$
+    /// \zeta(s) - 1 = \sum^{\infty}\_{k=2} \frac{1}{k^s}
+    /// $$
+    /// This is more accurate than [zeta] for large $\Re(s)$, where $\zeta(s) \approx 1$.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::Zeta;
+    /// assert!((60.0_f64.zetac() - 8.673617380119934e-19).abs() < 1e-33); // 2^{-60}
+    /// assert_eq!(60.0_f64.zeta() - 1.0, 0.0);
+    /// ```
+    ///
+    /// [zeta]: crate::special::Zeta::zeta()
+    fn zetac(self) -> Self;
+}
+
+/// Hurwitz zeta function for real-valued arguments.
+pub trait RealZeta: Zeta {
+    /// The Hurwitz zeta function.
+    /// $$
+    /// \zeta(s, a) = \sum^{\infty}\_{k=0} \frac{1}{(k+a)^s}
+    /// $$
+    /// where $s > 1$. For $a=1$, this is the Riemann [zeta] function. See the [dlmf] page for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use sci_rs::special::{RealZeta, Zeta};
+    /// assert!((3.0_f64.hurwitz_zeta(1.0) - 3.0_f64.zeta()).abs() < 1e-15);
+    /// let pi = std::f64::consts::PI;
+    /// assert!((2.0_f64.hurwitz_zeta(0.5) - pi * pi / 2.0).abs() < 1e-14);
+    /// assert!(0.5_f64.hurwitz_zeta(1.0).is_nan());
+    /// ```
+    ///
+    /// # Notes
+    /// The implementation is based on the [cephes implementation] in SciPy (v 1.10.1), which sums the first few terms directly and then uses the Euler-Maclaurin summation formula. Like SciPy, we return `NaN` for $s < 1$, and for $a < 0$ when $s$ is not an integer. At the poles $a = 0, -1, -2, \ldots$ we return $+\infty$.
+    ///
+    /// [zeta]: crate::special::Zeta::zeta()
+    /// [dlmf]: https://dlmf.nist.gov/25.11
+    /// [cephes implementation]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/zeta.c
+    fn hurwitz_zeta(self, a: Self) -> Self;
+}
+
+macro_rules! float_zeta_impl {
+    ($($T: ty)*) => ($(
+        impl Zeta for $T {
+            #[inline(always)]
+            fn zeta(self) -> Self {
+                r_zeta(self)
+            }
+
+            #[inline(always)]
+            fn zetac(self) -> Self {
+                r_zetac(self)
+            }
+        }
+
+        impl RealZeta for $T {
+            #[inline(always)]
+            fn hurwitz_zeta(self, a: Self) -> Self {
+                r_hurwitz_zeta(self, a)
+            }
+        }
+    )*)
+}
+
+float_zeta_impl! {f32 f64}
+
+macro_rules! float_complexzeta_impl {
+    ($($T: ty)*) => ($(
+        impl Zeta for Complex<$T> {
+            #[inline(always)]
+            fn zeta(self) -> Self {
+                c_zeta(self)
+            }
+
+            #[inline(always)]
+            fn zetac(self) -> Self {
+                c_zetac(self)
+            }
+        }
+    )*)
+}
+
+float_complexzeta_impl! {f32 f64}
@@ -0,0 +1,98 @@
+//**********************************************************************
+// This file is part of sci-rs                                         *
+// Copyright 2023 Matthew R. Hennefarth                                *
+//**********************************************************************
+
+use crate::special::Bernoulli;
+use num_complex::ComplexFloat;
+use num_traits::{Float, FromPrimitive, One, ToPrimitive, Zero};
+use std::ops::{Add, Div, Mul};
+
+/// Number of Bernoulli numbers used in the Euler-Maclaurin summation formula.
+///
+/// Note that the Bernoulli numbers are computed with `u128`, which overflows for more than 15.
+pub(crate) const EULER_MACLAURIN_TERMS: u128 = 12;
+
+/// Computes $w^{-s}$, using integer powers for small integer $s$ and for $\Re(w) < 0$.
+///
+/// The error of the repeated multiplications in `powi` grows with $s$, such that `powf` is used otherwise.
+#[inline]
+fn pow_neg<T>(w: T, s: <T as ComplexFloat>::Real) -> T
+where
+    T: ComplexFloat,
+    <T as ComplexFloat>::Real: FromPrimitive,
+{
+    const MAX_POWI: i32 = 16;
+
+    if s == s.floor()
+        && (Float::abs(s) <= T::Real::from_i32(MAX_POWI).unwrap()
+            || (w.re() < T::Real::zero() && Float::abs(s) < T::Real::from_i32(i32::MAX).unwrap()))
+    {
+        w.powi(-s.to_i32().unwrap())
+    } else {
+        w.powf(-s)
+    }
+}
+
+/// Hurwitz zeta function for real $s$ through the Euler-Maclaurin summation formula.
+/// $$
+/// \zeta(s, a) = \sum^{\infty}\_{k=0} \frac{1}{(a+k)^s}
+/// $$
+/// The first terms are summed directly until $\Re(a+N) \geq 9$, or until they are negligible, which is the case for large $s$ since the terms decay like $(1 + k/a)^{-s}$. Otherwise, the remainder is approximated by
+/// $$
+/// \frac{w^{1-s}}{s-1} + \frac{w^{-s}}{2} + \sum^{\infty}\_{j=1} \frac{B_{2j}}{(2j)!} \frac{(s)\_{2j-1}}{w^{s+2j-1}}
+/// $$
+/// with $w = a+N$. Similar to the [cephes implementation], but $a$ may also be complex-valued. No checks on the domain are made, which is left to the caller.
+///
+/// [cephes implementation]: https://github.com/scipy/scipy/blob/main/scipy/special/cephes/zeta.c
+pub(crate) fn hurwitz_zeta_em<T>(s: <T as ComplexFloat>::Real, a: T) -> T
+where
+    T: ComplexFloat
+        + Add<<T as ComplexFloat>::Real, Output = T>
+        + Mul<<T as ComplexFloat>::Real, Output = T>
+        + Div<<T as ComplexFloat>::Real, Output = T>,
+    <T as ComplexFloat>::Real: FromPrimitive,
+{
+    const MIN_TERMS: usize = 9;
+
+    let one = T::Real::one();
+    let threshold = T::Real::from_usize(MIN_TERMS).unwrap();
+
+    let mut w = a;
+    let mut sum = T::zero();
+    let mut k = 0;
+    while k < MIN_TERMS || w.re() < threshold {
+        let term = pow_neg(w, s);
+        // For large s, the remainder w^{-s} + w^{1-s}/(s-1) is already negligible, which includes terms that underflow
+        if s > one
+            && w.re() > T::Real::zero()
+            && (term * (w / (s - one) + one)).abs() <= T::Real::epsilon() * sum.abs()
+        {
+            return sum;
+        }
+        sum = sum + term;
+        w = w + one;
+        k += 1;
+    }
+
+    let rw = w.recip();
+    let w_s = pow_neg(w, s);
+    sum = sum + w_s * w / (s - one) + w_s * T::Real::from_f64(0.5).unwrap();
+
+    let b2n = EULER_MACLAURIN_TERMS.bernoulli_b2n::<T::Real>();
+    let mut poch = s;
+    let mut factorial = one + one;
+    let mut w_pow = w_s * rw;
+    for (j, &b) in b2n.iter().enumerate().skip(1) {
+        let term = w_pow * (b * poch / factorial);
+        sum = sum + term;
+        if term.abs() < T::Real::epsilon() * sum.abs() {
+            break;
+        }
+        let two_j = T::Real::from_usize(2 * j).unwrap();
+        poch = poch * (s + two_j - one) * (s + two_j);
+        factorial = factorial * (two_j + one) * (two_j + one + one);
+        w_pow = w_pow * rw * rw;
+    }
+    sum
+}